@@ -0,0 +1,66 @@
+// Measures how fast note payloads travel between JS and the native module:
+// a note of each size is created and read back, and the throughput of both
+// directions is reported. As a baseline, copying a payload of the same size
+// from Rust into an `ArrayBuffer` is timed both with the single copy
+// responses use and byte by byte, as responses were copied before, and the
+// speedup of the former is reported. The baseline is only measured with the
+// module built against the fake core (`npm run build-mock`), the only one
+// which exports `benchmarkCopy`.
+//
+//   npm run bench -- [dataPath] [iterations]
+
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
+const core = require('../lib/index');
+const addon = require('../native/index.node');
+
+const SIZES_MB = [1, 4, 16];
+
+const dataPath = process.argv[2] || fs.mkdtempSync(path.join(os.tmpdir(), 'giganotes-bench-'));
+const iterations = Number(process.argv[3]) || 20;
+
+const megabytes = (bytes) => bytes / (1024 * 1024);
+
+// Runs `fn` `iterations` times and returns the mean duration in seconds.
+const time = async (fn) => {
+  const start = process.hrtime.bigint();
+  for (let i = 0; i < iterations; i++) await fn();
+  return Number(process.hrtime.bigint() - start) / 1e9 / iterations;
+};
+
+const run = async () => {
+  core.initData('', dataPath);
+  const folderId = core.getRootFolder().getFolderid();
+
+  console.log(`data path ${dataPath}, ${iterations} iterations`);
+  for (const size of SIZES_MB) {
+    const text = 'x'.repeat(size * 1024 * 1024);
+    const noteId = core.createNote('bench', text, folderId).getNoteid();
+    const bytes = Buffer.byteLength(text);
+
+    const write = await time(() => core.updateNoteAsync(noteId, folderId, 'bench', text));
+    const read = await time(() => core.getNoteByIdAsync(noteId));
+    const readSync = await time(() => core.getNoteById(noteId));
+
+    let line = `${String(size).padStart(3)} MB  ` +
+      `write ${(megabytes(bytes) / write).toFixed(1)} MB/s  ` +
+      `read ${(megabytes(bytes) / read).toFixed(1)} MB/s  ` +
+      `read (sync) ${(megabytes(bytes) / readSync).toFixed(1)} MB/s`;
+    if (typeof addon.benchmarkCopy === 'function') {
+      const copy = await time(() => addon.benchmarkCopy(bytes, false));
+      const baseline = await time(() => addon.benchmarkCopy(bytes, true));
+      line += `  copy ${(megabytes(bytes) / copy).toFixed(1)} MB/s  ` +
+        `byte by byte ${(megabytes(bytes) / baseline).toFixed(1)} MB/s  ` +
+        `(${(baseline / copy).toFixed(1)}x)`;
+    }
+    console.log(line);
+
+    core.removeNote(noteId);
+  }
+};
+
+run().catch((err) => {
+  console.error(err);
+  process.exit(1);
+});
@@ -1,143 +1,406 @@
 
 const addon = require('../native/index.node');
+const childProcess = require('child_process');
+const fs = require('fs');
+const net = require('net');
+const path = require('path');
 const messages = require('./messages_pb');
 const { EventEmitter } = require('events');
 
-// The `MyEventEmitter` class provides glue code to abstract the `poll`
-// interface provided by the Neon class. It may be constructed and used
-// as a normal `EventEmitter`, including use by multiple subscribers.
+// Version of the protocol in protos/messages.proto this file speaks. Must
+// match `PROTOCOL_VERSION` in native/src/version.rs.
+const PROTOCOL_VERSION = 1;
+
+// Returns the `messages.VersionInfo` of the loaded native module: its
+// version, the version of the core it was built against, the supported
+// protocol versions, the build profile and the commands and events it
+// supports.
+var getVersion = function() {
+  return messages.VersionInfo.deserializeBinary(addon.getVersion());
+}
+
+// Checks that the loaded native module supports `PROTOCOL_VERSION`. Throws an
+// `Error` with code `ERR_GIGANOTES_INCOMPATIBLE_VERSION` otherwise, since
+// mismatched builds would fail in confusing ways later on.
+var handshake = function() {
+  // Native modules built before the handshake was introduced don't export it.
+  if (typeof addon.handshake !== 'function') {
+    var err = new Error('The native module is too old for protocol version ' +
+      PROTOCOL_VERSION + ', rebuild the native module');
+    err.code = 'ERR_GIGANOTES_INCOMPATIBLE_VERSION';
+    throw err;
+  }
+
+  var request = new messages.Handshake();
+  request.setProtocolversion(PROTOCOL_VERSION);
+
+  var response = messages.HandshakeResponse.deserializeBinary(
+    addon.handshake(request.serializeBinary().buffer));
+  if (!response.getSuccess()) {
+    err = new Error(response.getError().getMessage());
+    err.code = 'ERR_GIGANOTES_INCOMPATIBLE_VERSION';
+    throw err;
+  }
+  return response.getVersion();
+}
+
+handshake();
+
+// Returns the metrics collected by the native module since it was loaded:
+// calls, errors, payload sizes and latencies per command, and counts of
+// published, delivered and dropped events. `format` is one of
+//   - 'message' (default): a `messages.Metrics`
+//   - 'json': the same as a plain object
+//   - 'prometheus': a string in the Prometheus text exposition format
+var getMetrics = function(format) {
+  if (format === 'prometheus') {
+    return addon.getMetrics('prometheus');
+  }
+  var metrics = messages.Metrics.deserializeBinary(addon.getMetrics());
+  return format === 'json' ? metrics.toObject() : metrics;
+}
+
+// Decodes the payload of a typed core event, e.g. the `SyncProgress` message
+// of a "syncProgress" event. Event names match the `payload` fields of
+// `CoreEvent`.
+const decodeCoreEvent = (event, data) => {
+  const coreEvent = messages.CoreEvent.deserializeBinary(data);
+  const getter = 'get' + event.charAt(0).toUpperCase() + event.slice(1);
+  return coreEvent[getter]();
+};
+
+// Emits an event received from the native module on `emitter`. Typed events
+// carry their decoded payload, anything else is passed on as is. The
+// sequence number of the event is passed as the last argument, and the
+// highest one is kept in `emitter.lastSeq`.
+const emitCoreEvent = (emitter, event, data, seq) => {
+  if (seq) emitter.lastSeq = Math.max(emitter.lastSeq || 0, seq);
+
+  if (event === 'coreEvent') emitter.emit(event, { data }, seq);
+  else emitter.emit(event, decodeCoreEvent(event, data), seq);
+};
+
+// Events of an emitter which don't come from the core.
+const LISTENER_EVENTS = new Set(['newListener', 'removeListener']);
+
+// Returns the callback of the native channel of `emitter`. The channel roots
+// its callback for as long as it is subscribed, so the callback only holds
+// a weak reference: holding the emitter would keep the emitter, and with it
+// the channel, from ever being garbage collected.
+const channelCallback = (emitter) => {
+  const ref = new WeakRef(emitter);
+  return (event, data, seq) => {
+    const target = ref.deref();
+    if (target) emitCoreEvent(target, event, data, seq);
+  };
+};
+
+// The `MyEventEmitter` class provides glue code to turn the callback of the
+// Neon class into events. It may be constructed and used as a normal
+// `EventEmitter`, including use by multiple subscribers.
+//
+// The emitter is subscribed to the core's events while it has listeners,
+// and keeps the process alive only then. An emitter which is garbage
+// collected is unsubscribed as well. Like `shutdown()`, calling
+// `removeAllListeners()` without an event name unsubscribes it for good.
+//
+// Events wait in a bounded queue until the main thread gets to them.
+// `options` may set its `capacity` (1024 events by default) and what
+// happens when it is full, `overflow`: "dropOldest" (the default),
+// "coalesce" (like "dropOldest", but repeated "noteChanged" events for the
+// same note are merged first) or "block" (hold up the core's events until
+// the queue has room). Dropped events are reported by an "eventsDropped"
+// event, after which everything shown from the core should be refreshed.
+//
+// Every event has a sequence number. Passing the `lastSeq` of a previous
+// emitter as `options.sinceSeq`, e.g. after a renderer reloaded, replays
+// the events it missed once the first listener is added. If they can't be
+// replayed any more, or there are more of them than fit in the queue, a
+// "resyncRequired" event is emitted instead, after which everything shown
+// from the core should be reloaded.
 class MyEventEmitter extends EventEmitter {
-  constructor() {
+  constructor(options) {
     super();
 
-    // Create an instance of the Neon class
-    const channel = new addon.RustChannel();
-
-    // Marks the emitter as shutdown to stop iteration of the `poll` loop
-    this.isShutdown = false;
-
-    // The `loop` method is called continuously to receive data from the Rust
-    // work thread.
-    const loop = () => {
-      // Stop the receiving loop and shutdown the work thead. However, since
-      // the `poll` method uses a blocking `recv`, this code will not execute
-      // until either the next event is sent on the channel or a receive
-      // timeout has occurred.
-      if (this.isShutdown) {
-        channel.shutdown();
-        return;
-      }
+    this.options = Object.assign({}, options);
+    this.lastSeq = this.options.sinceSeq;
+    this.channel = null;
+    this.closed = false;
+
+    this.on('newListener', (event) => {
+      if (!LISTENER_EVENTS.has(event)) this.subscribe();
+    });
+    this.on('removeListener', (event) => {
+      if (!LISTENER_EVENTS.has(event) && this.coreListenerCount() === 0) this.unsubscribe();
+    });
+  }
 
-      // Poll for data      
-      channel.poll((err, e) => {        
-        if (err) this.emit('error', err);
-        else if (e) {
-          const { event, ...data } = e;
-
-          // Emit the event
-          this.emit(event, data);
-        }
-        // Otherwise, timeout on poll, no data to emit
-
-        // Schedule the next iteration of the loop. This is performed with
-        // a `setImmediate` to yield to the event loop, to let JS code run
-        // and avoid a stack overflow.
-        setImmediate(loop);
-      });
-    };
+  // Create an instance of the Neon class. The Rust side pushes each event
+  // to the callback as soon as it arrives, so there is nothing to poll.
+  subscribe() {
+    if (this.channel || this.closed) return;
+
+    this.channel = new addon.RustChannel(channelCallback(this), this.options);
+    // Only the first subscription replays missed events.
+    delete this.options.sinceSeq;
+  }
+
+  unsubscribe() {
+    if (!this.channel) return;
+
+    this.channel.shutdown();
+    this.channel = null;
+  }
 
-    // Start the polling loop on next iteration of the JS event loop to prevent zalgo.
-    setImmediate(loop);
+  coreListenerCount() {
+    return this.eventNames()
+      .filter((event) => !LISTENER_EVENTS.has(event))
+      .reduce((count, event) => count + this.listenerCount(event), 0);
   }
 
-  // Mark the channel for shutdown
+  // Unsubscribe from core events for good, even if listeners are added
+  // later on.
   shutdown() {
-    this.isShutdown = true;
+    this.closed = true;
+    this.unsubscribe();
     return this;
   }
 }
 
-var initLogging = function() {
-  addon.initLogging();
+// Configures logging of the native module. All options are optional:
+// `level` ("off", "error", "warn", "info", "debug" or "trace"; "info" by
+// default in a release build of the native module, "debug" otherwise),
+// `filters` (module path to level, e.g. `{ "giganotes_core::sync": "trace" }`),
+// `console` (write to stdout), `dataPath` and `file` (log file, a relative
+// path inside the data directory; `file` requires `dataPath`),
+// `maxFileSize` (bytes before rotation) and `maxFiles` (rotated files to
+// keep), and `forwardLevel` (records up to this level are emitted as "log"
+// events on every MyEventEmitter, regardless of `level` and `filters`). May
+// be called again to reconfigure logging. Throws a `TypeError` for invalid
+// options and an `Error` if the log file can't be opened or another logger
+// was installed first.
+var initLogging = function(options) {
+  addon.initLogging(options);
+}
+
+// Stops the core from accepting new commands, waits for running ones to
+// finish, including their writes and synchronizations, and then stops the
+// threads delivering core events once they have delivered the pending ones.
+// giganotes-core offers no way to stop its own worker thread, so with the
+// real core that thread keeps running until the process exits; only the
+// fake core's is stopped. Commands fail with a `SHUT_DOWN` error until
+// `initData` is called again, which fails with a `BUSY` error while the
+// shutdown is still under way.
+var shutdown = function() {
+  return new Promise(function(resolve, reject) {
+    addon.shutdown(function(err) {
+      if (err) reject(err);
+      else resolve();
+    });
+  });
+}
+
+// Serves the core to other processes of the current user, e.g. editor
+// plugins, on a Unix domain socket `giganotes-server/giganotes.sock` in
+// `dir`, usually the data directory. Clients exchange length-prefixed
+// `messages.ServerFrame` and `messages.ServerMessage` frames with it, and
+// authenticate with the contents of `giganotes.token` in `dir`, which gets a
+// new token on every start. Returns `{ socketPath, tokenPath }`. Only
+// available if the native module was built with the `server` feature.
+var startServer = function(dir) {
+  if (typeof addon.startServer !== 'function') {
+    throw new Error('The native module was built without the server feature');
+  }
+  return addon.startServer(dir);
+}
+
+// Stops the server started in `dir`, closing its connections and removing
+// its socket and token file.
+var stopServer = function(dir) {
+  if (typeof addon.stopServer === 'function') addon.stopServer(dir);
+}
+
+// Runs a command on the native thread pool through `schedule`, one of the
+// callback style addon exports. Resolves with the response once the core has
+// finished processing it, decoded as `responseType` if given.
+//
+// `options` may set `timeoutMs` or a `deadline` (a `Date` or milliseconds
+// since the epoch), after which the command fails with a `TIMED_OUT` error.
+// The returned Promise has a `cancel()` method, which makes the command fail
+// with a `CANCELLED` error. Once the core has started a command, only reads
+// are given up on, which keep running in the background with their response
+// discarded; a write runs to the end and resolves with its response.
+var scheduleCommand = function(schedule, buffer, commandIndex, options, responseType) {
+  var token = new addon.CancellationToken();
+  var promise = new Promise(function(resolve, reject) {
+    schedule(buffer, commandIndex, function(err, result) {
+      if (err) reject(err);
+      else resolve(result);
+    }, token, timeoutMs(options));
+  });
+  if (responseType) promise = promise.then(responseType.deserializeBinary);
+
+  promise.cancel = function() {
+    token.cancel();
+  };
+  return promise;
+}
+
+// Time left until the earlier of `options.timeoutMs` and `options.deadline`,
+// `undefined` if neither is set.
+var timeoutMs = function(options) {
+  if (!options) return undefined;
+
+  var timeouts = [];
+  if (options.timeoutMs !== undefined) timeouts.push(options.timeoutMs);
+  if (options.deadline !== undefined) timeouts.push(Number(options.deadline) - Date.now());
+  return timeouts.length ? Math.max(0, Math.min.apply(null, timeouts)) : undefined;
+}
+
+// Runs `schedule(buffer, callback)` with a `Request` envelope and resolves
+// with the decoded `Response`.
+var scheduleRequest = function(schedule, request) {
+  return new Promise(function(resolve, reject) {
+    schedule(request.serializeBinary().buffer, function(err, result) {
+      if (err) reject(err);
+      else resolve(messages.Response.deserializeBinary(result));
+    });
+  });
+}
+
+// Runs the command set in a `messages.Request`, without the need to know its
+// command index or response type. Resolves with a `messages.Response`
+// carrying the same request id, whose `body` is set under the name of the
+// command.
+var sendRequest = function(request) {
+  return scheduleRequest(addon.handleRequest, request);
+}
+
+// Runs a list of `messages.Request`s in order in one native call. `options`
+// may set `stopOnError`, to skip the requests after the first failed one.
+// The sequence is not atomic: other commands may run in between its
+// requests, and the writes of the requests before a failed one are kept.
+// There is no all-or-nothing mode, since giganotes-core gives the bridge no
+// way to run several commands in one storage transaction. Resolves with the `messages.Response` of every request, in order.
+var sendRequests = function(requests, options) {
+  var sequence = new messages.RequestSequence();
+  sequence.setRequestsList(requests);
+  sequence.setStoponerror(Boolean(options && options.stopOnError));
+
+  return new Promise(function(resolve, reject) {
+    addon.handleRequests(sequence.serializeBinary().buffer, function(err, result) {
+      if (err) return reject(err);
+
+      var response = messages.RequestSequenceResponse.deserializeBinary(result);
+      if (response.hasError()) {
+        var error = new Error(response.getError().getMessage());
+        error.error = response.getError();
+        reject(error);
+      } else {
+        resolve(response.getResponsesList());
+      }
+    });
+  });
 }
 
-var initDataSerialized = function(apiPath, dataPath) {
+var encodeInitData = function(apiPath, dataPath) {
   var initCommand = new messages.InitData();
   initCommand.setApipath(apiPath);
   initCommand.setDatapath(dataPath);
-  var bytes = initCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 1);  
+  return initCommand.serializeBinary().buffer;
+}
+
+var initDataSerialized = function(apiPath, dataPath) {
+  return addon.handleCommand(encodeInitData(apiPath, dataPath), 1);
 }
 
 var initData = function(apiPath, dataPath) {  
   return messages.EmptyResultResponse.deserializeBinary(initDataSerialized(apiPath, dataPath));    
 }
 
-var createNoteSerialized = function(title, text, folderId) {
+var encodeCreateNote = function(title, text, folderId) {
   var createNoteCommand = new messages.CreateNote();
   createNoteCommand.setTitle(title);
   createNoteCommand.setText(text);    
   createNoteCommand.setFolderid(folderId);    
-  var bytes = createNoteCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 2);  
+  return createNoteCommand.serializeBinary().buffer;
+};
+
+var createNoteSerialized = function(title, text, folderId) {
+  return addon.handleCommand(encodeCreateNote(title, text, folderId), 2);
 };
 
 var createNote = function(title, text, folderId) {
     return messages.CreateNoteResponse.deserializeBinary(createNoteSerialized(title, text, folderId));    
 };
 
-var getNotesByFolderSerialized = function(folderId) {
+var encodeGetNotesByFolder = function(folderId) {
   var getNodesListCommand = new messages.GetNotesList();    
   getNodesListCommand.setFolderid(folderId);
-  var bytes = getNodesListCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 3);  
+  return getNodesListCommand.serializeBinary().buffer;
+}
+
+var getNotesByFolderSerialized = function(folderId) {
+  return addon.handleCommand(encodeGetNotesByFolder(folderId), 3);
 }
 
 var getNotesByFolder = function(folderId) {
   return messages.GetNotesListResponse.deserializeBinary(getNotesByFolderSerialized(folderId));  
 }
 
-var getNoteByIdSerialized = function(noteId) {
+var encodeGetNoteById = function(noteId) {
   var getNoteCommand = new messages.GetNoteById();    
   getNoteCommand.setNoteid(noteId);
-  var bytes = getNoteCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 5);  
+  return getNoteCommand.serializeBinary().buffer;
+}
+
+var getNoteByIdSerialized = function(noteId) {
+  return addon.handleCommand(encodeGetNoteById(noteId), 5);
 }
 
 var getNoteById = function(noteId) {
   return messages.GetNoteByIdResponse.deserializeBinary(getNoteByIdSerialized(noteId));  
 }
 
-var getFolderByIdSerialized = function(folderId) {
+var encodeGetFolderById = function(folderId) {
   var getFolderCommand = new messages.GetFolderById();    
   getFolderCommand.setFolderid(folderId);
-  var bytes = getFolderCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 6);
+  return getFolderCommand.serializeBinary().buffer;
+}
+
+var getFolderByIdSerialized = function(folderId) {
+  return addon.handleCommand(encodeGetFolderById(folderId), 6);
 }
 
 var getFolderById = function(folderId) {
   return messages.GetFolderByIdResponse.deserializeBinary(getFolderByIdSerialized(folderId));  
 }
 
-var makeLoginSerialized = function(email, password) {
+var encodeMakeLogin = function(email, password) {
   var createNoteCommand = new messages.Login();  
   createNoteCommand.setEmail(email);
   createNoteCommand.setPassword(password);
-  var bytes = createNoteCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 8);  
+  return createNoteCommand.serializeBinary().buffer;
+}
+
+var makeLoginSerialized = function(email, password) {
+  return addon.handleCommand(encodeMakeLogin(email, password), 8);
 }
 
 var makeLogin = function(email, password) {
   return messages.LoginResponse.deserializeBinary(makeLoginSerialized(email, password));    
 }
 
-var makeLoginSocialSerialized = function(email, provider, token) {
+var encodeMakeLoginSocial = function(email, provider, token) {
   var loginCommand = new messages.LoginSocial();  
   loginCommand.setEmail(email);
   loginCommand.setProvider(provider);
   loginCommand.setToken(token);
-  var bytes = loginCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 24);  
+  return loginCommand.serializeBinary().buffer;
+}
+
+var makeLoginSocialSerialized = function(email, provider, token) {
+  return addon.handleCommand(encodeMakeLoginSocial(email, provider, token), 24);
 }
 
 var makeLoginSocial = function(email, provider, token) {
@@ -148,12 +411,15 @@ var register = function(email, password) {
   return messages.LoginResponse.deserializeBinary(registerSerialized(email, password));    
 }
 
-var registerSerialized = function(email, password) {
+var encodeRegister = function(email, password) {
   var registerCommand = new messages.Login();  
   registerCommand.setEmail(email);
   registerCommand.setPassword(password);
-  var bytes = registerCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 19);  
+  return registerCommand.serializeBinary().buffer;
+}
+
+var registerSerialized = function(email, password) {
+  return addon.handleCommand(encodeRegister(email, password), 19);
 }
 
 var getLastLoginDataSerialized = function() {
@@ -168,12 +434,15 @@ var getAllFoldersSerialized = function() {
   return addon.handleCommand(new Uint8Array([]).buffer, 11);  
 }
 
-var getAllNotesSerialized = function(offset, limit) {
+var encodeGetAllNotes = function(offset, limit) {
   var getAllNotesCommand = new messages.GetAllNotes();  
   getAllNotesCommand.setOffset(offset);
   getAllNotesCommand.setLimit(limit);
-  var bytes = getAllNotesCommand.serializeBinary();  
-  return addon.handleCommand(bytes.buffer, 12);  
+  return getAllNotesCommand.serializeBinary().buffer;
+}
+
+var getAllNotesSerialized = function(offset, limit) {
+  return addon.handleCommand(encodeGetAllNotes(offset, limit), 12);
 }
 
 var getLastLoginData = function() {  
@@ -192,26 +461,32 @@ var getAllNotes = function(offset, limit) {
   return messages.GetNotesListResponse.deserializeBinary(getAllNotesSerialized(offset, limit));  
 }
 
-var createFolderSerialized = function(title, parentId) {
+var encodeCreateFolder = function(title, parentId) {
   var createFolderCommand = new messages.CreateFolder();
   createFolderCommand.setTitle(title);
   createFolderCommand.setParentid(parentId);
-  var bytes = createFolderCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 13);  
+  return createFolderCommand.serializeBinary().buffer;
+};
+
+var createFolderSerialized = function(title, parentId) {
+  return addon.handleCommand(encodeCreateFolder(title, parentId), 13);
 };
 
 var createFolder = function(title, parentId) {
   return messages.CreateFolderResponse.deserializeBinary(createFolderSerialized(title, parentId));    
 };
 
-var updateNoteSerialized = function(id, folderId, title, text) {  
+var encodeUpdateNote = function(id, folderId, title, text) {
   var updateCommand = new messages.UpdateNote();  
   updateCommand.setId(id);
   updateCommand.setFolderid(folderId);
   updateCommand.setTitle(title);
   updateCommand.setText(text);
-  var bytes = updateCommand.serializeBinary();  
-  return addon.handleCommand(bytes.buffer, 14);  
+  return updateCommand.serializeBinary().buffer;
+};
+
+var updateNoteSerialized = function(id, folderId, title, text) {
+  return addon.handleCommand(encodeUpdateNote(id, folderId, title, text), 14);
 };
 
 var updateNote = function(id, folderId, title, text) {
@@ -219,74 +494,104 @@ var updateNote = function(id, folderId, title, text) {
 };
 
 
-var updateFolderSerialized = function(id, parentId, title, level) {
+var encodeUpdateFolder = function(id, parentId, title, level) {
   var updateCommand = new messages.UpdateFolder();  
   updateCommand.setId(id);
   updateCommand.setParentid(parentId);
   updateCommand.setTitle(title);
   updateCommand.setLevel(level);
-  var bytes = updateCommand.serializeBinary();  
-  return addon.handleCommand(bytes.buffer, 15);  
+  return updateCommand.serializeBinary().buffer;
+};
+
+var updateFolderSerialized = function(id, parentId, title, level) {
+  return addon.handleCommand(encodeUpdateFolder(id, parentId, title, level), 15);
 };
 
 var updateFolder = function(id, parentId, title, level) {
   return messages.EmptyResultResponse.deserializeBinary(updateFolderSerialized(id, parentId, title, level));    
 }
 
-var syncronize = function() {  
-  addon.handleAsyncCommand(new Uint8Array([]).buffer, 7);  
+var synchronizeSerialized = function(options) {
+  return scheduleCommand(addon.handleAsyncCommand, new Uint8Array([]).buffer, 7, options);
 }
 
-var removeNoteSerialized = function(id) {  
+// Synchronizes the local store with the server. With a core reporting its
+// progress as `syncFinished` and `syncFailed` events, like the fake core,
+// resolves once the synchronization has finished, with a failed response
+// carrying the error of the `syncFailed` event if it didn't succeed, or a
+// `TIMED_OUT` one after five minutes. giganotes-core doesn't report them, so
+// with it this resolves as soon as the core has started the
+// synchronization.
+var syncronize = function(options) {
+  return scheduleCommand(addon.handleAsyncCommand, new Uint8Array([]).buffer, 7, options,
+    messages.EmptyResultResponse);
+}
+
+var encodeRemoveNote = function(id) {
   var command = new messages.RemoveNote();  
   command.setNoteid(id);
-  var bytes = command.serializeBinary();  
-  return addon.handleCommand(bytes.buffer, 16);  
+  return command.serializeBinary().buffer;
+};
+
+var removeNoteSerialized = function(id) {
+  return addon.handleCommand(encodeRemoveNote(id), 16);
 };
 
 var removeNote = function(id) {
   return messages.EmptyResultResponse.deserializeBinary(removeNoteSerialized(id));    
 };
 
-var removeFolderSerialized = function(id) {  
+var encodeRemoveFolder = function(id) {
   var command = new messages.RemoveFolder();  
   command.setFolderid(id);
-  var bytes = command.serializeBinary();  
-  return addon.handleCommand(bytes.buffer, 17);  
+  return command.serializeBinary().buffer;
+};
+
+var removeFolderSerialized = function(id) {
+  return addon.handleCommand(encodeRemoveFolder(id), 17);
 };
 
 var removeFolder = function(id) {
   return messages.EmptyResultResponse.deserializeBinary(removeFolderSerialized(id));    
 };
 
-var searchNotesSerialized = function(query, folderId) {
+var encodeSearchNotes = function(query, folderId) {
   var searchNotesCommand = new messages.SearchNotes();    
   searchNotesCommand.setQuery(query);
   searchNotesCommand.setFolderid(folderId)
-  var bytes = searchNotesCommand.serializeBinary();
-  return addon.handleCommand(bytes.buffer, 18);  
+  return searchNotesCommand.serializeBinary().buffer;
+}
+
+var searchNotesSerialized = function(query, folderId) {
+  return addon.handleCommand(encodeSearchNotes(query, folderId), 18);
 }
 
 var searchNotes = function(query) {
   return messages.GetNotesListResponse.deserializeBinary(searchNotesSerialized(query));  
 }
 
-var addToFavoritesSerialized = function(id) {  
+var encodeAddToFavorites = function(id) {
   var command = new messages.AddToFavorites();  
   command.setNoteid(id);
-  var bytes = command.serializeBinary();  
-  return addon.handleCommand(bytes.buffer, 20);  
+  return command.serializeBinary().buffer;
+};
+
+var addToFavoritesSerialized = function(id) {
+  return addon.handleCommand(encodeAddToFavorites(id), 20);
 };
 
 var addToFavorites = function(id) {
   return messages.EmptyResultResponse.deserializeBinary(addToFavoritesSerialized(id));    
 };
 
-var removeFromFavoritesSerialized = function(id) {  
+var encodeRemoveFromFavorites = function(id) {
   var command = new messages.AddToFavorites();  
   command.setNoteid(id);
-  var bytes = command.serializeBinary();  
-  return addon.handleCommand(bytes.buffer, 21);  
+  return command.serializeBinary().buffer;
+};
+
+var removeFromFavoritesSerialized = function(id) {
+  return addon.handleCommand(encodeRemoveFromFavorites(id), 21);
 };
 
 var removeFromFavorites = function(id) {
@@ -309,6 +614,317 @@ var makeLogout = function() {
   return messages.EmptyResultResponse.deserializeBinary(makeLogoutSerialized());    
 }
 
+// Counterparts of the functions above which run the command on the native
+// thread pool instead of the main thread. Each returns a `Promise` resolving
+// with the decoded response, which can be cancelled. The trailing `options`
+// may set a timeout, see `scheduleCommand`.
+var initDataAsync = function(apiPath, dataPath, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeInitData(apiPath, dataPath), 1, options, messages.EmptyResultResponse);
+}
+
+var createNoteAsync = function(title, text, folderId, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeCreateNote(title, text, folderId), 2, options, messages.CreateNoteResponse);
+}
+
+var getNotesByFolderAsync = function(folderId, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeGetNotesByFolder(folderId), 3, options, messages.GetNotesListResponse);
+}
+
+var getNoteByIdAsync = function(noteId, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeGetNoteById(noteId), 5, options, messages.GetNoteByIdResponse);
+}
+
+var getFolderByIdAsync = function(folderId, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeGetFolderById(folderId), 6, options, messages.GetFolderByIdResponse);
+}
+
+var makeLoginAsync = function(email, password, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeMakeLogin(email, password), 8, options, messages.LoginResponse);
+}
+
+var getLastLoginDataAsync = function(options) {
+  return scheduleCommand(addon.handleCommandAsync, new Uint8Array([]).buffer, 9, options, messages.GetLastLoginDataResponse);
+}
+
+var getRootFolderAsync = function(options) {
+  return scheduleCommand(addon.handleCommandAsync, new Uint8Array([]).buffer, 10, options, messages.GetRootFolderResponse);
+}
+
+var getAllFoldersAsync = function(options) {
+  return scheduleCommand(addon.handleCommandAsync, new Uint8Array([]).buffer, 11, options, messages.GetFoldersListResponse);
+}
+
+var getAllNotesAsync = function(offset, limit, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeGetAllNotes(offset, limit), 12, options, messages.GetNotesListResponse);
+}
+
+var createFolderAsync = function(title, parentId, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeCreateFolder(title, parentId), 13, options, messages.CreateFolderResponse);
+}
+
+var updateNoteAsync = function(id, folderId, title, text, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeUpdateNote(id, folderId, title, text), 14, options, messages.EmptyResultResponse);
+}
+
+var updateFolderAsync = function(id, parentId, title, level, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeUpdateFolder(id, parentId, title, level), 15, options, messages.EmptyResultResponse);
+}
+
+var removeNoteAsync = function(id, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeRemoveNote(id), 16, options, messages.EmptyResultResponse);
+}
+
+var removeFolderAsync = function(id, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeRemoveFolder(id), 17, options, messages.EmptyResultResponse);
+}
+
+var searchNotesAsync = function(query, folderId, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeSearchNotes(query, folderId), 18, options, messages.GetNotesListResponse);
+}
+
+var registerAsync = function(email, password, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeRegister(email, password), 19, options, messages.LoginResponse);
+}
+
+var addToFavoritesAsync = function(id, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeAddToFavorites(id), 20, options, messages.EmptyResultResponse);
+}
+
+var removeFromFavoritesAsync = function(id, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeRemoveFromFavorites(id), 21, options, messages.EmptyResultResponse);
+}
+
+var getFavoritesAsync = function(options) {
+  return scheduleCommand(addon.handleCommandAsync, new Uint8Array([]).buffer, 22, options, messages.GetNotesListResponse);
+}
+
+var makeLogoutAsync = function(options) {
+  return scheduleCommand(addon.handleCommandAsync, new Uint8Array([]).buffer, 23, options, messages.EmptyResultResponse);
+}
+
+var makeLoginSocialAsync = function(email, provider, token, options) {
+  return scheduleCommand(addon.handleCommandAsync, encodeMakeLoginSocial(email, provider, token), 24, options, messages.LoginResponse);
+}
+
+// A core with its own data path, API path, session and events, so that
+// several accounts or databases can be open side by side. giganotes-core
+// keeps all of these in globals, so each instance runs its core in a child
+// process of its own (lib/worker.js) and talks to it over the socket server
+// of the child, see `startServer`. The native module must have been built
+// with the `server` feature.
+//
+// Commands return Promises which reject if the child process has gone away
+// or rejected the command, e.g. for an unknown index. They can't be
+// cancelled and take no timeouts. The core's events are emitted like on a
+// `MyEventEmitter`; `options.sinceSeq` replays the events missed since
+// another instance's `lastSeq` for the same data path. An instance keeps the
+// process alive until `shutdown` is called.
+class GiganotesCore extends EventEmitter {
+  constructor(apiPath, dataPath, options) {
+    super();
+
+    if (typeof addon.startServer !== 'function') {
+      throw new Error('The native module was built without the server feature');
+    }
+
+    this.dataPath = dataPath;
+    this.lastSeq = options && options.sinceSeq;
+    this.buffer = Buffer.alloc(0);
+    this.pending = new Map();
+    this.nextRequestId = 1;
+
+    this.child = childProcess.fork(path.join(__dirname, 'worker.js'), [apiPath, dataPath]);
+    this.exited = new Promise((resolve) => this.child.on('exit', resolve));
+    this.ready = new Promise((resolve, reject) => {
+      this.child.once('message', (started) => {
+        if (started.error) reject(new Error(started.error));
+        else resolve(this.connect(started));
+      });
+      this.exited.then(() => reject(new Error('The core process exited')));
+    });
+    // Commands report a failed start, this only keeps it from being unhandled.
+    this.ready.catch(() => this.child.connected && this.child.disconnect());
+  }
+
+  // Connects to the child's server, authenticates and subscribes to the
+  // core's events.
+  connect(paths) {
+    this.socket = net.connect(paths.socketPath);
+    this.socket.on('data', (data) => this.receive(data));
+    this.socket.on('error', () => {});
+    this.socket.on('close', () => {
+      this.closed = true;
+      this.pending.forEach((callbacks) => callbacks.reject(new Error('The core process exited')));
+      this.pending.clear();
+    });
+
+    var auth = new messages.ServerAuth();
+    auth.setToken(fs.readFileSync(paths.tokenPath, 'utf8'));
+    var subscribe = new messages.ServerSubscribe();
+    subscribe.setSinceseq(this.lastSeq || 0);
+
+    return this.send((frame) => frame.setAuth(auth))
+      .then(() => this.send((frame) => frame.setSubscribe(subscribe)));
+  }
+
+  // Splits the length-prefixed `ServerMessage`s off the data read so far.
+  receive(data) {
+    this.buffer = Buffer.concat([this.buffer, data]);
+    while (this.buffer.length >= 4) {
+      var length = this.buffer.readUInt32BE(0);
+      if (this.buffer.length < 4 + length) break;
+
+      var message = messages.ServerMessage.deserializeBinary(this.buffer.slice(4, 4 + length));
+      this.buffer = this.buffer.slice(4 + length);
+      if (message.hasEvent()) {
+        var event = message.getEvent();
+        emitCoreEvent(this, event.getName(), event.getData_asU8(), event.getSeq());
+      } else {
+        this.settle(message.getReply());
+      }
+    }
+  }
+
+  settle(reply) {
+    var callbacks = this.pending.get(reply.getRequestid());
+    if (!callbacks) return;
+
+    this.pending.delete(reply.getRequestid());
+    if (reply.hasError()) {
+      var error = new Error(reply.getError().getMessage());
+      error.error = reply.getError();
+      callbacks.reject(error);
+    } else {
+      callbacks.resolve(reply.getResponse_asU8());
+    }
+  }
+
+  // Sends a `ServerFrame` whose body is set by `setBody` and resolves with
+  // the response of its reply.
+  send(setBody) {
+    if (this.closed) return Promise.reject(new Error('The core process exited'));
+
+    var frame = new messages.ServerFrame();
+    frame.setRequestid(this.nextRequestId++);
+    setBody(frame);
+
+    var body = Buffer.from(frame.serializeBinary());
+    var length = Buffer.alloc(4);
+    length.writeUInt32BE(body.length);
+    this.socket.write(Buffer.concat([length, body]));
+    return new Promise((resolve, reject) => {
+      this.pending.set(frame.getRequestid(), { resolve, reject });
+    });
+  }
+
+  // Closes the connection to the child process and stops it once it has
+  // finished the commands it is running. Resolves once it has exited.
+  shutdown() {
+    if (this.socket) this.socket.end();
+    if (this.child.connected) this.child.disconnect();
+    return this.exited.then(() => undefined);
+  }
+
+  // Runs the command with the same index and encoded request as
+  // `handleCommand` and resolves with its response decoded as `responseType`.
+  runCommand(buffer, commandIndex, responseType) {
+    return this.ready.then(() => {
+      var command = new messages.ServerCommand();
+      command.setIndex(commandIndex);
+      command.setData(new Uint8Array(buffer));
+      return this.send((frame) => frame.setCommand(command));
+    }).then(responseType.deserializeBinary);
+  }
+
+  createNote(title, text, folderId) {
+    return this.runCommand(encodeCreateNote(title, text, folderId), 2, messages.CreateNoteResponse);
+  }
+
+  getNotesByFolder(folderId) {
+    return this.runCommand(encodeGetNotesByFolder(folderId), 3, messages.GetNotesListResponse);
+  }
+
+  getNoteById(noteId) {
+    return this.runCommand(encodeGetNoteById(noteId), 5, messages.GetNoteByIdResponse);
+  }
+
+  getFolderById(folderId) {
+    return this.runCommand(encodeGetFolderById(folderId), 6, messages.GetFolderByIdResponse);
+  }
+
+  synchronize() {
+    return this.runCommand(new Uint8Array([]).buffer, 7, messages.EmptyResultResponse);
+  }
+
+  makeLogin(email, password) {
+    return this.runCommand(encodeMakeLogin(email, password), 8, messages.LoginResponse);
+  }
+
+  getLastLoginData() {
+    return this.runCommand(new Uint8Array([]).buffer, 9, messages.GetLastLoginDataResponse);
+  }
+
+  getRootFolder() {
+    return this.runCommand(new Uint8Array([]).buffer, 10, messages.GetRootFolderResponse);
+  }
+
+  getAllFolders() {
+    return this.runCommand(new Uint8Array([]).buffer, 11, messages.GetFoldersListResponse);
+  }
+
+  getAllNotes(offset, limit) {
+    return this.runCommand(encodeGetAllNotes(offset, limit), 12, messages.GetNotesListResponse);
+  }
+
+  createFolder(title, parentId) {
+    return this.runCommand(encodeCreateFolder(title, parentId), 13, messages.CreateFolderResponse);
+  }
+
+  updateNote(id, folderId, title, text) {
+    return this.runCommand(encodeUpdateNote(id, folderId, title, text), 14, messages.EmptyResultResponse);
+  }
+
+  updateFolder(id, parentId, title, level) {
+    return this.runCommand(encodeUpdateFolder(id, parentId, title, level), 15, messages.EmptyResultResponse);
+  }
+
+  removeNote(id) {
+    return this.runCommand(encodeRemoveNote(id), 16, messages.EmptyResultResponse);
+  }
+
+  removeFolder(id) {
+    return this.runCommand(encodeRemoveFolder(id), 17, messages.EmptyResultResponse);
+  }
+
+  searchNotes(query, folderId) {
+    return this.runCommand(encodeSearchNotes(query, folderId), 18, messages.GetNotesListResponse);
+  }
+
+  register(email, password) {
+    return this.runCommand(encodeRegister(email, password), 19, messages.LoginResponse);
+  }
+
+  addToFavorites(id) {
+    return this.runCommand(encodeAddToFavorites(id), 20, messages.EmptyResultResponse);
+  }
+
+  removeFromFavorites(id) {
+    return this.runCommand(encodeRemoveFromFavorites(id), 21, messages.EmptyResultResponse);
+  }
+
+  getFavorites() {
+    return this.runCommand(new Uint8Array([]).buffer, 22, messages.GetNotesListResponse);
+  }
+
+  makeLogout() {
+    return this.runCommand(new Uint8Array([]).buffer, 23, messages.EmptyResultResponse);
+  }
+
+  makeLoginSocial(email, provider, token) {
+    return this.runCommand(encodeMakeLoginSocial(email, provider, token), 24, messages.LoginResponse);
+  }
+}
+
 module.exports.removeNoteSerialized = removeNoteSerialized;
 module.exports.removeFolderSerialized = removeFolderSerialized;
 module.exports.updateNoteSerialized = updateNoteSerialized;
@@ -332,6 +948,7 @@ module.exports.addToFavoritesSerialized = addToFavoritesSerialized;
 module.exports.removeFromFavoritesSerialized = removeFromFavoritesSerialized;
 module.exports.getFavoritesSerialized = getFavoritesSerialized;
 module.exports.makeLogoutSerialized = makeLogoutSerialized;
+module.exports.synchronizeSerialized = synchronizeSerialized;
 
 module.exports.synchronize = syncronize;
 module.exports.getNoteById = getNoteById;
@@ -351,6 +968,14 @@ module.exports.makeLogin = makeLogin;
 module.exports.makeLoginSocial = makeLoginSocial;
 module.exports.register = register;
 module.exports.initLogging = initLogging;
+module.exports.PROTOCOL_VERSION = PROTOCOL_VERSION;
+module.exports.getVersion = getVersion;
+module.exports.getMetrics = getMetrics;
+module.exports.sendRequest = sendRequest;
+module.exports.sendRequests = sendRequests;
+module.exports.shutdown = shutdown;
+module.exports.startServer = startServer;
+module.exports.stopServer = stopServer;
 module.exports.initData = initData;
 module.exports.createNote = createNote;
 module.exports.searchNotes = searchNotes;
@@ -359,3 +984,26 @@ module.exports.removeFromFavorites = removeFromFavorites;
 module.exports.getFavorites = getFavorites;
 module.exports.makeLogout = makeLogout;
 module.exports.MyEventEmitter = MyEventEmitter;
+module.exports.GiganotesCore = GiganotesCore;
+module.exports.initDataAsync = initDataAsync;
+module.exports.createNoteAsync = createNoteAsync;
+module.exports.getNotesByFolderAsync = getNotesByFolderAsync;
+module.exports.getNoteByIdAsync = getNoteByIdAsync;
+module.exports.getFolderByIdAsync = getFolderByIdAsync;
+module.exports.makeLoginAsync = makeLoginAsync;
+module.exports.getLastLoginDataAsync = getLastLoginDataAsync;
+module.exports.getRootFolderAsync = getRootFolderAsync;
+module.exports.getAllFoldersAsync = getAllFoldersAsync;
+module.exports.getAllNotesAsync = getAllNotesAsync;
+module.exports.createFolderAsync = createFolderAsync;
+module.exports.updateNoteAsync = updateNoteAsync;
+module.exports.updateFolderAsync = updateFolderAsync;
+module.exports.removeNoteAsync = removeNoteAsync;
+module.exports.removeFolderAsync = removeFolderAsync;
+module.exports.searchNotesAsync = searchNotesAsync;
+module.exports.registerAsync = registerAsync;
+module.exports.addToFavoritesAsync = addToFavoritesAsync;
+module.exports.removeFromFavoritesAsync = removeFromFavoritesAsync;
+module.exports.getFavoritesAsync = getFavoritesAsync;
+module.exports.makeLogoutAsync = makeLogoutAsync;
+module.exports.makeLoginSocialAsync = makeLoginSocialAsync;
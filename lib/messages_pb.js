@@ -12,34 +12,82 @@ var goog = jspb;
 var global = Function('return this')();
 
 goog.exportSymbol('proto.gigamessages.AddToFavorites', null, global);
+goog.exportSymbol('proto.gigamessages.AuthExpired', null, global);
+goog.exportSymbol('proto.gigamessages.ChangeKind', null, global);
+goog.exportSymbol('proto.gigamessages.CommandInfo', null, global);
+goog.exportSymbol('proto.gigamessages.CommandMetrics', null, global);
+goog.exportSymbol('proto.gigamessages.CoreEvent', null, global);
+goog.exportSymbol('proto.gigamessages.CoreEvent.PayloadCase', null, global);
 goog.exportSymbol('proto.gigamessages.CreateFolder', null, global);
 goog.exportSymbol('proto.gigamessages.CreateFolderResponse', null, global);
 goog.exportSymbol('proto.gigamessages.CreateNote', null, global);
 goog.exportSymbol('proto.gigamessages.CreateNoteResponse', null, global);
 goog.exportSymbol('proto.gigamessages.EmptyResultResponse', null, global);
+goog.exportSymbol('proto.gigamessages.Error', null, global);
+goog.exportSymbol('proto.gigamessages.ErrorCode', null, global);
+goog.exportSymbol('proto.gigamessages.EventMetrics', null, global);
+goog.exportSymbol('proto.gigamessages.EventsDropped', null, global);
 goog.exportSymbol('proto.gigamessages.Folder', null, global);
+goog.exportSymbol('proto.gigamessages.FolderChanged', null, global);
+goog.exportSymbol('proto.gigamessages.GetAllFolders', null, global);
 goog.exportSymbol('proto.gigamessages.GetAllNotes', null, global);
+goog.exportSymbol('proto.gigamessages.GetFavorites', null, global);
 goog.exportSymbol('proto.gigamessages.GetFolderById', null, global);
 goog.exportSymbol('proto.gigamessages.GetFolderByIdResponse', null, global);
 goog.exportSymbol('proto.gigamessages.GetFoldersListResponse', null, global);
+goog.exportSymbol('proto.gigamessages.GetLastLoginData', null, global);
 goog.exportSymbol('proto.gigamessages.GetLastLoginDataResponse', null, global);
 goog.exportSymbol('proto.gigamessages.GetNoteById', null, global);
 goog.exportSymbol('proto.gigamessages.GetNoteByIdResponse', null, global);
 goog.exportSymbol('proto.gigamessages.GetNotesList', null, global);
 goog.exportSymbol('proto.gigamessages.GetNotesListResponse', null, global);
+goog.exportSymbol('proto.gigamessages.GetRootFolder', null, global);
 goog.exportSymbol('proto.gigamessages.GetRootFolderResponse', null, global);
+goog.exportSymbol('proto.gigamessages.Handshake', null, global);
+goog.exportSymbol('proto.gigamessages.HandshakeResponse', null, global);
+goog.exportSymbol('proto.gigamessages.Histogram', null, global);
+goog.exportSymbol('proto.gigamessages.HistogramBucket', null, global);
 goog.exportSymbol('proto.gigamessages.InitData', null, global);
+goog.exportSymbol('proto.gigamessages.LogLevel', null, global);
+goog.exportSymbol('proto.gigamessages.LogRecord', null, global);
 goog.exportSymbol('proto.gigamessages.Login', null, global);
 goog.exportSymbol('proto.gigamessages.LoginResponse', null, global);
 goog.exportSymbol('proto.gigamessages.LoginSocial', null, global);
+goog.exportSymbol('proto.gigamessages.Logout', null, global);
+goog.exportSymbol('proto.gigamessages.Metrics', null, global);
+goog.exportSymbol('proto.gigamessages.NoteChanged', null, global);
 goog.exportSymbol('proto.gigamessages.NoteShortInfo', null, global);
 goog.exportSymbol('proto.gigamessages.RemoveFolder', null, global);
 goog.exportSymbol('proto.gigamessages.RemoveFromFavorites', null, global);
 goog.exportSymbol('proto.gigamessages.RemoveNote', null, global);
+goog.exportSymbol('proto.gigamessages.Request', null, global);
+goog.exportSymbol('proto.gigamessages.Request.CommandCase', null, global);
+goog.exportSymbol('proto.gigamessages.RequestSequence', null, global);
+goog.exportSymbol('proto.gigamessages.RequestSequenceResponse', null, global);
+goog.exportSymbol('proto.gigamessages.Response', null, global);
+goog.exportSymbol('proto.gigamessages.Response.BodyCase', null, global);
+goog.exportSymbol('proto.gigamessages.ResponseStatus', null, global);
+goog.exportSymbol('proto.gigamessages.ResyncRequired', null, global);
 goog.exportSymbol('proto.gigamessages.SearchNotes', null, global);
+goog.exportSymbol('proto.gigamessages.ServerAuth', null, global);
+goog.exportSymbol('proto.gigamessages.ServerCommand', null, global);
+goog.exportSymbol('proto.gigamessages.ServerEvent', null, global);
+goog.exportSymbol('proto.gigamessages.ServerFrame', null, global);
+goog.exportSymbol('proto.gigamessages.ServerFrame.BodyCase', null, global);
+goog.exportSymbol('proto.gigamessages.ServerMessage', null, global);
+goog.exportSymbol('proto.gigamessages.ServerMessage.BodyCase', null, global);
+goog.exportSymbol('proto.gigamessages.ServerReply', null, global);
+goog.exportSymbol('proto.gigamessages.ServerSubscribe', null, global);
+goog.exportSymbol('proto.gigamessages.ServerUnsubscribe', null, global);
 goog.exportSymbol('proto.gigamessages.SetToken', null, global);
+goog.exportSymbol('proto.gigamessages.SyncFailed', null, global);
+goog.exportSymbol('proto.gigamessages.SyncFinished', null, global);
+goog.exportSymbol('proto.gigamessages.SyncProgress', null, global);
+goog.exportSymbol('proto.gigamessages.SyncStarted', null, global);
+goog.exportSymbol('proto.gigamessages.Synchronize', null, global);
 goog.exportSymbol('proto.gigamessages.UpdateFolder', null, global);
 goog.exportSymbol('proto.gigamessages.UpdateNote', null, global);
+goog.exportSymbol('proto.gigamessages.VersionInfo', null, global);
 
 /**
  * Generated by JsPbCodeGenerator.
@@ -51,12 +99,12 @@ goog.exportSymbol('proto.gigamessages.UpdateNote', null, global);
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.InitData = function(opt_data) {
+proto.gigamessages.Error = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.InitData, jspb.Message);
+goog.inherits(proto.gigamessages.Error, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.InitData.displayName = 'proto.gigamessages.InitData';
+  proto.gigamessages.Error.displayName = 'proto.gigamessages.Error';
 }
 
 
@@ -71,8 +119,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.InitData.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.InitData.toObject(opt_includeInstance, this);
+proto.gigamessages.Error.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Error.toObject(opt_includeInstance, this);
 };
 
 
@@ -81,14 +129,17 @@ proto.gigamessages.InitData.prototype.toObject = function(opt_includeInstance) {
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.InitData} msg The msg instance to transform.
+ * @param {!proto.gigamessages.Error} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.InitData.toObject = function(includeInstance, msg) {
+proto.gigamessages.Error.toObject = function(includeInstance, msg) {
   var f, obj = {
-    datapath: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    apipath: jspb.Message.getFieldWithDefault(msg, 2, "")
+    code: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    message: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    field: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    retryable: jspb.Message.getFieldWithDefault(msg, 4, false),
+    corecode: jspb.Message.getFieldWithDefault(msg, 5, 0)
   };
 
   if (includeInstance) {
@@ -102,23 +153,23 @@ proto.gigamessages.InitData.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.InitData}
+ * @return {!proto.gigamessages.Error}
  */
-proto.gigamessages.InitData.deserializeBinary = function(bytes) {
+proto.gigamessages.Error.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.InitData;
-  return proto.gigamessages.InitData.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.Error;
+  return proto.gigamessages.Error.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.InitData} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.Error} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.InitData}
+ * @return {!proto.gigamessages.Error}
  */
-proto.gigamessages.InitData.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.Error.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -126,12 +177,24 @@ proto.gigamessages.InitData.deserializeBinaryFromReader = function(msg, reader)
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setDatapath(value);
+      var value = /** @type {!proto.gigamessages.ErrorCode} */ (reader.readEnum());
+      msg.setCode(value);
       break;
     case 2:
       var value = /** @type {string} */ (reader.readString());
-      msg.setApipath(value);
+      msg.setMessage(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setField(value);
+      break;
+    case 4:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setRetryable(value);
+      break;
+    case 5:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setCorecode(value);
       break;
     default:
       reader.skipField();
@@ -146,9 +209,9 @@ proto.gigamessages.InitData.deserializeBinaryFromReader = function(msg, reader)
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.InitData.prototype.serializeBinary = function() {
+proto.gigamessages.Error.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.InitData.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.Error.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -156,59 +219,127 @@ proto.gigamessages.InitData.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.InitData} message
+ * @param {!proto.gigamessages.Error} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.InitData.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.Error.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getDatapath();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getCode();
+  if (f !== 0.0) {
+    writer.writeEnum(
       1,
       f
     );
   }
-  f = message.getApipath();
+  f = message.getMessage();
   if (f.length > 0) {
     writer.writeString(
       2,
       f
     );
   }
+  f = message.getField();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getRetryable();
+  if (f) {
+    writer.writeBool(
+      4,
+      f
+    );
+  }
+  f = message.getCorecode();
+  if (f !== 0) {
+    writer.writeInt32(
+      5,
+      f
+    );
+  }
 };
 
 
 /**
- * optional string dataPath = 1;
- * @return {string}
+ * optional ErrorCode code = 1;
+ * @return {!proto.gigamessages.ErrorCode}
  */
-proto.gigamessages.InitData.prototype.getDatapath = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.Error.prototype.getCode = function() {
+  return /** @type {!proto.gigamessages.ErrorCode} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.InitData.prototype.setDatapath = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/** @param {!proto.gigamessages.ErrorCode} value */
+proto.gigamessages.Error.prototype.setCode = function(value) {
+  jspb.Message.setProto3EnumField(this, 1, value);
 };
 
 
 /**
- * optional string apiPath = 2;
+ * optional string message = 2;
  * @return {string}
  */
-proto.gigamessages.InitData.prototype.getApipath = function() {
+proto.gigamessages.Error.prototype.getMessage = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.InitData.prototype.setApipath = function(value) {
+proto.gigamessages.Error.prototype.setMessage = function(value) {
   jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
+/**
+ * optional string field = 3;
+ * @return {string}
+ */
+proto.gigamessages.Error.prototype.getField = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.Error.prototype.setField = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional bool retryable = 4;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.Error.prototype.getRetryable = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 4, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.Error.prototype.setRetryable = function(value) {
+  jspb.Message.setProto3BooleanField(this, 4, value);
+};
+
+
+/**
+ * optional int32 coreCode = 5;
+ * @return {number}
+ */
+proto.gigamessages.Error.prototype.getCorecode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Error.prototype.setCorecode = function(value) {
+  jspb.Message.setProto3IntField(this, 5, value);
+};
+
+
 
 /**
  * Generated by JsPbCodeGenerator.
@@ -220,12 +351,12 @@ proto.gigamessages.InitData.prototype.setApipath = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.Login = function(opt_data) {
+proto.gigamessages.ResponseStatus = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.Login, jspb.Message);
+goog.inherits(proto.gigamessages.ResponseStatus, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.Login.displayName = 'proto.gigamessages.Login';
+  proto.gigamessages.ResponseStatus.displayName = 'proto.gigamessages.ResponseStatus';
 }
 
 
@@ -240,8 +371,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.Login.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.Login.toObject(opt_includeInstance, this);
+proto.gigamessages.ResponseStatus.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ResponseStatus.toObject(opt_includeInstance, this);
 };
 
 
@@ -250,14 +381,15 @@ proto.gigamessages.Login.prototype.toObject = function(opt_includeInstance) {
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.Login} msg The msg instance to transform.
+ * @param {!proto.gigamessages.ResponseStatus} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.Login.toObject = function(includeInstance, msg) {
+proto.gigamessages.ResponseStatus.toObject = function(includeInstance, msg) {
   var f, obj = {
-    email: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    password: jspb.Message.getFieldWithDefault(msg, 2, "")
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -271,23 +403,23 @@ proto.gigamessages.Login.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.Login}
+ * @return {!proto.gigamessages.ResponseStatus}
  */
-proto.gigamessages.Login.deserializeBinary = function(bytes) {
+proto.gigamessages.ResponseStatus.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.Login;
-  return proto.gigamessages.Login.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.ResponseStatus;
+  return proto.gigamessages.ResponseStatus.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.Login} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.ResponseStatus} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.Login}
+ * @return {!proto.gigamessages.ResponseStatus}
  */
-proto.gigamessages.Login.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.ResponseStatus.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -295,12 +427,17 @@ proto.gigamessages.Login.deserializeBinaryFromReader = function(msg, reader) {
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setEmail(value);
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
       break;
     case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setPassword(value);
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
       break;
     default:
       reader.skipField();
@@ -315,9 +452,9 @@ proto.gigamessages.Login.deserializeBinaryFromReader = function(msg, reader) {
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.Login.prototype.serializeBinary = function() {
+proto.gigamessages.ResponseStatus.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.Login.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.ResponseStatus.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -325,56 +462,96 @@ proto.gigamessages.Login.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.Login} message
+ * @param {!proto.gigamessages.ResponseStatus} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.Login.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.ResponseStatus.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getEmail();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
       1,
       f
     );
   }
-  f = message.getPassword();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
       2,
       f
     );
   }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
 };
 
 
 /**
- * optional string email = 1;
- * @return {string}
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
  */
-proto.gigamessages.Login.prototype.getEmail = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.ResponseStatus.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.Login.prototype.setEmail = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/** @param {boolean} value */
+proto.gigamessages.ResponseStatus.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
 };
 
 
 /**
- * optional string password = 2;
- * @return {string}
+ * optional int32 errorCode = 2;
+ * @return {number}
  */
-proto.gigamessages.Login.prototype.getPassword = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+proto.gigamessages.ResponseStatus.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.Login.prototype.setPassword = function(value) {
-  jspb.Message.setProto3StringField(this, 2, value);
+/** @param {number} value */
+proto.gigamessages.ResponseStatus.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.ResponseStatus.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.ResponseStatus.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.ResponseStatus.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.ResponseStatus.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
 };
 
 
@@ -389,12 +566,12 @@ proto.gigamessages.Login.prototype.setPassword = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.LoginSocial = function(opt_data) {
+proto.gigamessages.InitData = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.LoginSocial, jspb.Message);
+goog.inherits(proto.gigamessages.InitData, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.LoginSocial.displayName = 'proto.gigamessages.LoginSocial';
+  proto.gigamessages.InitData.displayName = 'proto.gigamessages.InitData';
 }
 
 
@@ -409,8 +586,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.LoginSocial.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.LoginSocial.toObject(opt_includeInstance, this);
+proto.gigamessages.InitData.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.InitData.toObject(opt_includeInstance, this);
 };
 
 
@@ -419,15 +596,14 @@ proto.gigamessages.LoginSocial.prototype.toObject = function(opt_includeInstance
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.LoginSocial} msg The msg instance to transform.
+ * @param {!proto.gigamessages.InitData} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.LoginSocial.toObject = function(includeInstance, msg) {
+proto.gigamessages.InitData.toObject = function(includeInstance, msg) {
   var f, obj = {
-    email: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    provider: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    token: jspb.Message.getFieldWithDefault(msg, 3, "")
+    datapath: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    apipath: jspb.Message.getFieldWithDefault(msg, 2, "")
   };
 
   if (includeInstance) {
@@ -441,23 +617,23 @@ proto.gigamessages.LoginSocial.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.LoginSocial}
+ * @return {!proto.gigamessages.InitData}
  */
-proto.gigamessages.LoginSocial.deserializeBinary = function(bytes) {
+proto.gigamessages.InitData.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.LoginSocial;
-  return proto.gigamessages.LoginSocial.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.InitData;
+  return proto.gigamessages.InitData.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.LoginSocial} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.InitData} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.LoginSocial}
+ * @return {!proto.gigamessages.InitData}
  */
-proto.gigamessages.LoginSocial.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.InitData.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -466,15 +642,11 @@ proto.gigamessages.LoginSocial.deserializeBinaryFromReader = function(msg, reade
     switch (field) {
     case 1:
       var value = /** @type {string} */ (reader.readString());
-      msg.setEmail(value);
+      msg.setDatapath(value);
       break;
     case 2:
       var value = /** @type {string} */ (reader.readString());
-      msg.setProvider(value);
-      break;
-    case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setToken(value);
+      msg.setApipath(value);
       break;
     default:
       reader.skipField();
@@ -489,9 +661,9 @@ proto.gigamessages.LoginSocial.deserializeBinaryFromReader = function(msg, reade
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.LoginSocial.prototype.serializeBinary = function() {
+proto.gigamessages.InitData.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.LoginSocial.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.InitData.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -499,81 +671,59 @@ proto.gigamessages.LoginSocial.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.LoginSocial} message
+ * @param {!proto.gigamessages.InitData} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.LoginSocial.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.InitData.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getEmail();
+  f = message.getDatapath();
   if (f.length > 0) {
     writer.writeString(
       1,
       f
     );
   }
-  f = message.getProvider();
+  f = message.getApipath();
   if (f.length > 0) {
     writer.writeString(
       2,
       f
     );
   }
-  f = message.getToken();
-  if (f.length > 0) {
-    writer.writeString(
-      3,
-      f
-    );
-  }
 };
 
 
 /**
- * optional string email = 1;
+ * optional string dataPath = 1;
  * @return {string}
  */
-proto.gigamessages.LoginSocial.prototype.getEmail = function() {
+proto.gigamessages.InitData.prototype.getDatapath = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.LoginSocial.prototype.setEmail = function(value) {
+proto.gigamessages.InitData.prototype.setDatapath = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
 /**
- * optional string provider = 2;
+ * optional string apiPath = 2;
  * @return {string}
  */
-proto.gigamessages.LoginSocial.prototype.getProvider = function() {
+proto.gigamessages.InitData.prototype.getApipath = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.LoginSocial.prototype.setProvider = function(value) {
+proto.gigamessages.InitData.prototype.setApipath = function(value) {
   jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
-/**
- * optional string token = 3;
- * @return {string}
- */
-proto.gigamessages.LoginSocial.prototype.getToken = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
-};
-
-
-/** @param {string} value */
-proto.gigamessages.LoginSocial.prototype.setToken = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
-};
-
-
 
 /**
  * Generated by JsPbCodeGenerator.
@@ -585,12 +735,12 @@ proto.gigamessages.LoginSocial.prototype.setToken = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.LoginResponse = function(opt_data) {
+proto.gigamessages.Login = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.LoginResponse, jspb.Message);
+goog.inherits(proto.gigamessages.Login, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.LoginResponse.displayName = 'proto.gigamessages.LoginResponse';
+  proto.gigamessages.Login.displayName = 'proto.gigamessages.Login';
 }
 
 
@@ -605,8 +755,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.LoginResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.LoginResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.Login.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Login.toObject(opt_includeInstance, this);
 };
 
 
@@ -615,16 +765,14 @@ proto.gigamessages.LoginResponse.prototype.toObject = function(opt_includeInstan
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.LoginResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.Login} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.LoginResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.Login.toObject = function(includeInstance, msg) {
   var f, obj = {
-    success: jspb.Message.getFieldWithDefault(msg, 1, false),
-    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    token: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    userid: jspb.Message.getFieldWithDefault(msg, 4, 0)
+    email: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    password: jspb.Message.getFieldWithDefault(msg, 2, "")
   };
 
   if (includeInstance) {
@@ -638,23 +786,23 @@ proto.gigamessages.LoginResponse.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.LoginResponse}
+ * @return {!proto.gigamessages.Login}
  */
-proto.gigamessages.LoginResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.Login.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.LoginResponse;
-  return proto.gigamessages.LoginResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.Login;
+  return proto.gigamessages.Login.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.LoginResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.Login} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.LoginResponse}
+ * @return {!proto.gigamessages.Login}
  */
-proto.gigamessages.LoginResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.Login.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -662,20 +810,12 @@ proto.gigamessages.LoginResponse.deserializeBinaryFromReader = function(msg, rea
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setSuccess(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setEmail(value);
       break;
     case 2:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setErrorcode(value);
-      break;
-    case 3:
       var value = /** @type {string} */ (reader.readString());
-      msg.setToken(value);
-      break;
-    case 4:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setUserid(value);
+      msg.setPassword(value);
       break;
     default:
       reader.skipField();
@@ -690,9 +830,9 @@ proto.gigamessages.LoginResponse.deserializeBinaryFromReader = function(msg, rea
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.LoginResponse.prototype.serializeBinary = function() {
+proto.gigamessages.Login.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.LoginResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.Login.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -700,37 +840,23 @@ proto.gigamessages.LoginResponse.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.LoginResponse} message
+ * @param {!proto.gigamessages.Login} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.LoginResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.Login.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSuccess();
-  if (f) {
-    writer.writeBool(
+  f = message.getEmail();
+  if (f.length > 0) {
+    writer.writeString(
       1,
       f
     );
   }
-  f = message.getErrorcode();
-  if (f !== 0) {
-    writer.writeInt32(
-      2,
-      f
-    );
-  }
-  f = message.getToken();
+  f = message.getPassword();
   if (f.length > 0) {
     writer.writeString(
-      3,
-      f
-    );
-  }
-  f = message.getUserid();
-  if (f !== 0) {
-    writer.writeInt32(
-      4,
+      2,
       f
     );
   }
@@ -738,64 +864,32 @@ proto.gigamessages.LoginResponse.serializeBinaryToWriter = function(message, wri
 
 
 /**
- * optional bool success = 1;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
- * @return {boolean}
- */
-proto.gigamessages.LoginResponse.prototype.getSuccess = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
-};
-
-
-/** @param {boolean} value */
-proto.gigamessages.LoginResponse.prototype.setSuccess = function(value) {
-  jspb.Message.setProto3BooleanField(this, 1, value);
-};
-
-
-/**
- * optional int32 errorCode = 2;
- * @return {number}
- */
-proto.gigamessages.LoginResponse.prototype.getErrorcode = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
-};
-
-
-/** @param {number} value */
-proto.gigamessages.LoginResponse.prototype.setErrorcode = function(value) {
-  jspb.Message.setProto3IntField(this, 2, value);
-};
-
-
-/**
- * optional string token = 3;
+ * optional string email = 1;
  * @return {string}
  */
-proto.gigamessages.LoginResponse.prototype.getToken = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+proto.gigamessages.Login.prototype.getEmail = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.LoginResponse.prototype.setToken = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
+proto.gigamessages.Login.prototype.setEmail = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
 /**
- * optional int32 userId = 4;
- * @return {number}
+ * optional string password = 2;
+ * @return {string}
  */
-proto.gigamessages.LoginResponse.prototype.getUserid = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+proto.gigamessages.Login.prototype.getPassword = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.LoginResponse.prototype.setUserid = function(value) {
-  jspb.Message.setProto3IntField(this, 4, value);
+/** @param {string} value */
+proto.gigamessages.Login.prototype.setPassword = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
@@ -810,12 +904,12 @@ proto.gigamessages.LoginResponse.prototype.setUserid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.CreateNote = function(opt_data) {
+proto.gigamessages.LoginSocial = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.CreateNote, jspb.Message);
+goog.inherits(proto.gigamessages.LoginSocial, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.CreateNote.displayName = 'proto.gigamessages.CreateNote';
+  proto.gigamessages.LoginSocial.displayName = 'proto.gigamessages.LoginSocial';
 }
 
 
@@ -830,8 +924,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.CreateNote.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.CreateNote.toObject(opt_includeInstance, this);
+proto.gigamessages.LoginSocial.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.LoginSocial.toObject(opt_includeInstance, this);
 };
 
 
@@ -840,15 +934,15 @@ proto.gigamessages.CreateNote.prototype.toObject = function(opt_includeInstance)
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.CreateNote} msg The msg instance to transform.
+ * @param {!proto.gigamessages.LoginSocial} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateNote.toObject = function(includeInstance, msg) {
+proto.gigamessages.LoginSocial.toObject = function(includeInstance, msg) {
   var f, obj = {
-    title: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    text: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    folderid: jspb.Message.getFieldWithDefault(msg, 3, "")
+    email: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    provider: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    token: jspb.Message.getFieldWithDefault(msg, 3, "")
   };
 
   if (includeInstance) {
@@ -862,23 +956,23 @@ proto.gigamessages.CreateNote.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.CreateNote}
+ * @return {!proto.gigamessages.LoginSocial}
  */
-proto.gigamessages.CreateNote.deserializeBinary = function(bytes) {
+proto.gigamessages.LoginSocial.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.CreateNote;
-  return proto.gigamessages.CreateNote.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.LoginSocial;
+  return proto.gigamessages.LoginSocial.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.CreateNote} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.LoginSocial} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.CreateNote}
+ * @return {!proto.gigamessages.LoginSocial}
  */
-proto.gigamessages.CreateNote.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.LoginSocial.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -887,15 +981,15 @@ proto.gigamessages.CreateNote.deserializeBinaryFromReader = function(msg, reader
     switch (field) {
     case 1:
       var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
+      msg.setEmail(value);
       break;
     case 2:
       var value = /** @type {string} */ (reader.readString());
-      msg.setText(value);
+      msg.setProvider(value);
       break;
     case 3:
       var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
+      msg.setToken(value);
       break;
     default:
       reader.skipField();
@@ -910,9 +1004,9 @@ proto.gigamessages.CreateNote.deserializeBinaryFromReader = function(msg, reader
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.CreateNote.prototype.serializeBinary = function() {
+proto.gigamessages.LoginSocial.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.CreateNote.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.LoginSocial.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -920,27 +1014,27 @@ proto.gigamessages.CreateNote.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.CreateNote} message
+ * @param {!proto.gigamessages.LoginSocial} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateNote.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.LoginSocial.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getTitle();
+  f = message.getEmail();
   if (f.length > 0) {
     writer.writeString(
       1,
       f
     );
   }
-  f = message.getText();
+  f = message.getProvider();
   if (f.length > 0) {
     writer.writeString(
       2,
       f
     );
   }
-  f = message.getFolderid();
+  f = message.getToken();
   if (f.length > 0) {
     writer.writeString(
       3,
@@ -951,46 +1045,46 @@ proto.gigamessages.CreateNote.serializeBinaryToWriter = function(message, writer
 
 
 /**
- * optional string title = 1;
+ * optional string email = 1;
  * @return {string}
  */
-proto.gigamessages.CreateNote.prototype.getTitle = function() {
+proto.gigamessages.LoginSocial.prototype.getEmail = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.CreateNote.prototype.setTitle = function(value) {
+proto.gigamessages.LoginSocial.prototype.setEmail = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
 /**
- * optional string text = 2;
+ * optional string provider = 2;
  * @return {string}
  */
-proto.gigamessages.CreateNote.prototype.getText = function() {
+proto.gigamessages.LoginSocial.prototype.getProvider = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.CreateNote.prototype.setText = function(value) {
+proto.gigamessages.LoginSocial.prototype.setProvider = function(value) {
   jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
 /**
- * optional string folderId = 3;
+ * optional string token = 3;
  * @return {string}
  */
-proto.gigamessages.CreateNote.prototype.getFolderid = function() {
+proto.gigamessages.LoginSocial.prototype.getToken = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.CreateNote.prototype.setFolderid = function(value) {
+proto.gigamessages.LoginSocial.prototype.setToken = function(value) {
   jspb.Message.setProto3StringField(this, 3, value);
 };
 
@@ -1006,12 +1100,12 @@ proto.gigamessages.CreateNote.prototype.setFolderid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.CreateFolder = function(opt_data) {
+proto.gigamessages.LoginResponse = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.CreateFolder, jspb.Message);
+goog.inherits(proto.gigamessages.LoginResponse, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.CreateFolder.displayName = 'proto.gigamessages.CreateFolder';
+  proto.gigamessages.LoginResponse.displayName = 'proto.gigamessages.LoginResponse';
 }
 
 
@@ -1026,8 +1120,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.CreateFolder.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.CreateFolder.toObject(opt_includeInstance, this);
+proto.gigamessages.LoginResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.LoginResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -1036,14 +1130,17 @@ proto.gigamessages.CreateFolder.prototype.toObject = function(opt_includeInstanc
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.CreateFolder} msg The msg instance to transform.
+ * @param {!proto.gigamessages.LoginResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateFolder.toObject = function(includeInstance, msg) {
+proto.gigamessages.LoginResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    parentid: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    title: jspb.Message.getFieldWithDefault(msg, 2, "")
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    token: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    userid: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -1057,23 +1154,23 @@ proto.gigamessages.CreateFolder.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.CreateFolder}
+ * @return {!proto.gigamessages.LoginResponse}
  */
-proto.gigamessages.CreateFolder.deserializeBinary = function(bytes) {
+proto.gigamessages.LoginResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.CreateFolder;
-  return proto.gigamessages.CreateFolder.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.LoginResponse;
+  return proto.gigamessages.LoginResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.CreateFolder} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.LoginResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.CreateFolder}
+ * @return {!proto.gigamessages.LoginResponse}
  */
-proto.gigamessages.CreateFolder.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.LoginResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -1081,12 +1178,25 @@ proto.gigamessages.CreateFolder.deserializeBinaryFromReader = function(msg, read
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setParentid(value);
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
       break;
     case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 3:
       var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
+      msg.setToken(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setUserid(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
       break;
     default:
       reader.skipField();
@@ -1101,9 +1211,9 @@ proto.gigamessages.CreateFolder.deserializeBinaryFromReader = function(msg, read
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.CreateFolder.prototype.serializeBinary = function() {
+proto.gigamessages.LoginResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.CreateFolder.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.LoginResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -1111,56 +1221,140 @@ proto.gigamessages.CreateFolder.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.CreateFolder} message
+ * @param {!proto.gigamessages.LoginResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateFolder.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.LoginResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getParentid();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
       1,
       f
     );
   }
-  f = message.getTitle();
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+  f = message.getToken();
   if (f.length > 0) {
     writer.writeString(
-      2,
+      3,
+      f
+    );
+  }
+  f = message.getUserid();
+  if (f !== 0) {
+    writer.writeInt32(
+      4,
       f
     );
   }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
 };
 
 
 /**
- * optional string parentId = 1;
- * @return {string}
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
  */
-proto.gigamessages.CreateFolder.prototype.getParentid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.LoginResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.CreateFolder.prototype.setParentid = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/** @param {boolean} value */
+proto.gigamessages.LoginResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
 };
 
 
 /**
- * optional string title = 2;
+ * optional int32 errorCode = 2;
+ * @return {number}
+ */
+proto.gigamessages.LoginResponse.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.LoginResponse.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional string token = 3;
  * @return {string}
  */
-proto.gigamessages.CreateFolder.prototype.getTitle = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+proto.gigamessages.LoginResponse.prototype.getToken = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.CreateFolder.prototype.setTitle = function(value) {
-  jspb.Message.setProto3StringField(this, 2, value);
+proto.gigamessages.LoginResponse.prototype.setToken = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional int32 userId = 4;
+ * @return {number}
+ */
+proto.gigamessages.LoginResponse.prototype.getUserid = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.LoginResponse.prototype.setUserid = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.LoginResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.LoginResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.LoginResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.LoginResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
 };
 
 
@@ -1175,12 +1369,12 @@ proto.gigamessages.CreateFolder.prototype.setTitle = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.CreateFolderResponse = function(opt_data) {
+proto.gigamessages.CreateNote = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.CreateFolderResponse, jspb.Message);
+goog.inherits(proto.gigamessages.CreateNote, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.CreateFolderResponse.displayName = 'proto.gigamessages.CreateFolderResponse';
+  proto.gigamessages.CreateNote.displayName = 'proto.gigamessages.CreateNote';
 }
 
 
@@ -1195,8 +1389,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.CreateFolderResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.CreateFolderResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.CreateNote.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.CreateNote.toObject(opt_includeInstance, this);
 };
 
 
@@ -1205,14 +1399,14 @@ proto.gigamessages.CreateFolderResponse.prototype.toObject = function(opt_includ
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.CreateFolderResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.CreateNote} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateFolderResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.CreateNote.toObject = function(includeInstance, msg) {
   var f, obj = {
-    success: jspb.Message.getFieldWithDefault(msg, 1, false),
-    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    title: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    text: jspb.Message.getFieldWithDefault(msg, 2, ""),
     folderid: jspb.Message.getFieldWithDefault(msg, 3, "")
   };
 
@@ -1227,23 +1421,23 @@ proto.gigamessages.CreateFolderResponse.toObject = function(includeInstance, msg
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.CreateFolderResponse}
+ * @return {!proto.gigamessages.CreateNote}
  */
-proto.gigamessages.CreateFolderResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.CreateNote.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.CreateFolderResponse;
-  return proto.gigamessages.CreateFolderResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.CreateNote;
+  return proto.gigamessages.CreateNote.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.CreateFolderResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.CreateNote} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.CreateFolderResponse}
+ * @return {!proto.gigamessages.CreateNote}
  */
-proto.gigamessages.CreateFolderResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.CreateNote.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -1251,12 +1445,12 @@ proto.gigamessages.CreateFolderResponse.deserializeBinaryFromReader = function(m
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setSuccess(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTitle(value);
       break;
     case 2:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setErrorcode(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setText(value);
       break;
     case 3:
       var value = /** @type {string} */ (reader.readString());
@@ -1275,9 +1469,9 @@ proto.gigamessages.CreateFolderResponse.deserializeBinaryFromReader = function(m
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.CreateFolderResponse.prototype.serializeBinary = function() {
+proto.gigamessages.CreateNote.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.CreateFolderResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.CreateNote.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -1285,22 +1479,22 @@ proto.gigamessages.CreateFolderResponse.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.CreateFolderResponse} message
+ * @param {!proto.gigamessages.CreateNote} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateFolderResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.CreateNote.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSuccess();
-  if (f) {
-    writer.writeBool(
+  f = message.getTitle();
+  if (f.length > 0) {
+    writer.writeString(
       1,
       f
     );
   }
-  f = message.getErrorcode();
-  if (f !== 0) {
-    writer.writeInt32(
+  f = message.getText();
+  if (f.length > 0) {
+    writer.writeString(
       2,
       f
     );
@@ -1316,34 +1510,32 @@ proto.gigamessages.CreateFolderResponse.serializeBinaryToWriter = function(messa
 
 
 /**
- * optional bool success = 1;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
- * @return {boolean}
+ * optional string title = 1;
+ * @return {string}
  */
-proto.gigamessages.CreateFolderResponse.prototype.getSuccess = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+proto.gigamessages.CreateNote.prototype.getTitle = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
-/** @param {boolean} value */
-proto.gigamessages.CreateFolderResponse.prototype.setSuccess = function(value) {
-  jspb.Message.setProto3BooleanField(this, 1, value);
+/** @param {string} value */
+proto.gigamessages.CreateNote.prototype.setTitle = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
 /**
- * optional int32 errorCode = 2;
- * @return {number}
+ * optional string text = 2;
+ * @return {string}
  */
-proto.gigamessages.CreateFolderResponse.prototype.getErrorcode = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+proto.gigamessages.CreateNote.prototype.getText = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.CreateFolderResponse.prototype.setErrorcode = function(value) {
-  jspb.Message.setProto3IntField(this, 2, value);
+/** @param {string} value */
+proto.gigamessages.CreateNote.prototype.setText = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
@@ -1351,13 +1543,13 @@ proto.gigamessages.CreateFolderResponse.prototype.setErrorcode = function(value)
  * optional string folderId = 3;
  * @return {string}
  */
-proto.gigamessages.CreateFolderResponse.prototype.getFolderid = function() {
+proto.gigamessages.CreateNote.prototype.getFolderid = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.CreateFolderResponse.prototype.setFolderid = function(value) {
+proto.gigamessages.CreateNote.prototype.setFolderid = function(value) {
   jspb.Message.setProto3StringField(this, 3, value);
 };
 
@@ -1373,12 +1565,12 @@ proto.gigamessages.CreateFolderResponse.prototype.setFolderid = function(value)
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.CreateNoteResponse = function(opt_data) {
+proto.gigamessages.CreateFolder = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.CreateNoteResponse, jspb.Message);
+goog.inherits(proto.gigamessages.CreateFolder, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.CreateNoteResponse.displayName = 'proto.gigamessages.CreateNoteResponse';
+  proto.gigamessages.CreateFolder.displayName = 'proto.gigamessages.CreateFolder';
 }
 
 
@@ -1393,8 +1585,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.CreateNoteResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.CreateNoteResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.CreateFolder.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.CreateFolder.toObject(opt_includeInstance, this);
 };
 
 
@@ -1403,15 +1595,14 @@ proto.gigamessages.CreateNoteResponse.prototype.toObject = function(opt_includeI
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.CreateNoteResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.CreateFolder} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateNoteResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.CreateFolder.toObject = function(includeInstance, msg) {
   var f, obj = {
-    success: jspb.Message.getFieldWithDefault(msg, 1, false),
-    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    noteid: jspb.Message.getFieldWithDefault(msg, 3, "")
+    parentid: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    title: jspb.Message.getFieldWithDefault(msg, 2, "")
   };
 
   if (includeInstance) {
@@ -1425,23 +1616,23 @@ proto.gigamessages.CreateNoteResponse.toObject = function(includeInstance, msg)
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.CreateNoteResponse}
+ * @return {!proto.gigamessages.CreateFolder}
  */
-proto.gigamessages.CreateNoteResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.CreateFolder.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.CreateNoteResponse;
-  return proto.gigamessages.CreateNoteResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.CreateFolder;
+  return proto.gigamessages.CreateFolder.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.CreateNoteResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.CreateFolder} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.CreateNoteResponse}
+ * @return {!proto.gigamessages.CreateFolder}
  */
-proto.gigamessages.CreateNoteResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.CreateFolder.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -1449,16 +1640,12 @@ proto.gigamessages.CreateNoteResponse.deserializeBinaryFromReader = function(msg
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setSuccess(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setParentid(value);
       break;
     case 2:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setErrorcode(value);
-      break;
-    case 3:
       var value = /** @type {string} */ (reader.readString());
-      msg.setNoteid(value);
+      msg.setTitle(value);
       break;
     default:
       reader.skipField();
@@ -1473,9 +1660,9 @@ proto.gigamessages.CreateNoteResponse.deserializeBinaryFromReader = function(msg
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.CreateNoteResponse.prototype.serializeBinary = function() {
+proto.gigamessages.CreateFolder.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.CreateNoteResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.CreateFolder.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -1483,30 +1670,23 @@ proto.gigamessages.CreateNoteResponse.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.CreateNoteResponse} message
+ * @param {!proto.gigamessages.CreateFolder} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.CreateNoteResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.CreateFolder.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSuccess();
-  if (f) {
-    writer.writeBool(
+  f = message.getParentid();
+  if (f.length > 0) {
+    writer.writeString(
       1,
       f
     );
   }
-  f = message.getErrorcode();
-  if (f !== 0) {
-    writer.writeInt32(
-      2,
-      f
-    );
-  }
-  f = message.getNoteid();
+  f = message.getTitle();
   if (f.length > 0) {
     writer.writeString(
-      3,
+      2,
       f
     );
   }
@@ -1514,49 +1694,32 @@ proto.gigamessages.CreateNoteResponse.serializeBinaryToWriter = function(message
 
 
 /**
- * optional bool success = 1;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
- * @return {boolean}
- */
-proto.gigamessages.CreateNoteResponse.prototype.getSuccess = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
-};
-
-
-/** @param {boolean} value */
-proto.gigamessages.CreateNoteResponse.prototype.setSuccess = function(value) {
-  jspb.Message.setProto3BooleanField(this, 1, value);
-};
-
-
-/**
- * optional int32 errorCode = 2;
- * @return {number}
+ * optional string parentId = 1;
+ * @return {string}
  */
-proto.gigamessages.CreateNoteResponse.prototype.getErrorcode = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+proto.gigamessages.CreateFolder.prototype.getParentid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.CreateNoteResponse.prototype.setErrorcode = function(value) {
-  jspb.Message.setProto3IntField(this, 2, value);
+/** @param {string} value */
+proto.gigamessages.CreateFolder.prototype.setParentid = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
 /**
- * optional string noteId = 3;
+ * optional string title = 2;
  * @return {string}
  */
-proto.gigamessages.CreateNoteResponse.prototype.getNoteid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+proto.gigamessages.CreateFolder.prototype.getTitle = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.CreateNoteResponse.prototype.setNoteid = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
+proto.gigamessages.CreateFolder.prototype.setTitle = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
@@ -1571,12 +1734,12 @@ proto.gigamessages.CreateNoteResponse.prototype.setNoteid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetNotesList = function(opt_data) {
+proto.gigamessages.CreateFolderResponse = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.GetNotesList, jspb.Message);
+goog.inherits(proto.gigamessages.CreateFolderResponse, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetNotesList.displayName = 'proto.gigamessages.GetNotesList';
+  proto.gigamessages.CreateFolderResponse.displayName = 'proto.gigamessages.CreateFolderResponse';
 }
 
 
@@ -1591,8 +1754,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetNotesList.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetNotesList.toObject(opt_includeInstance, this);
+proto.gigamessages.CreateFolderResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.CreateFolderResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -1601,13 +1764,16 @@ proto.gigamessages.GetNotesList.prototype.toObject = function(opt_includeInstanc
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetNotesList} msg The msg instance to transform.
+ * @param {!proto.gigamessages.CreateFolderResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNotesList.toObject = function(includeInstance, msg) {
+proto.gigamessages.CreateFolderResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    folderid: jspb.Message.getFieldWithDefault(msg, 1, "")
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    folderid: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -1621,23 +1787,23 @@ proto.gigamessages.GetNotesList.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetNotesList}
+ * @return {!proto.gigamessages.CreateFolderResponse}
  */
-proto.gigamessages.GetNotesList.deserializeBinary = function(bytes) {
+proto.gigamessages.CreateFolderResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetNotesList;
-  return proto.gigamessages.GetNotesList.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.CreateFolderResponse;
+  return proto.gigamessages.CreateFolderResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetNotesList} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.CreateFolderResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetNotesList}
+ * @return {!proto.gigamessages.CreateFolderResponse}
  */
-proto.gigamessages.GetNotesList.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.CreateFolderResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -1645,9 +1811,22 @@ proto.gigamessages.GetNotesList.deserializeBinaryFromReader = function(msg, read
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 3:
       var value = /** @type {string} */ (reader.readString());
       msg.setFolderid(value);
       break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
     default:
       reader.skipField();
       break;
@@ -1661,9 +1840,9 @@ proto.gigamessages.GetNotesList.deserializeBinaryFromReader = function(msg, read
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetNotesList.prototype.serializeBinary = function() {
+proto.gigamessages.CreateFolderResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetNotesList.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.CreateFolderResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -1671,34 +1850,118 @@ proto.gigamessages.GetNotesList.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetNotesList} message
+ * @param {!proto.gigamessages.CreateFolderResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNotesList.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.CreateFolderResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
+      1,
+      f
+    );
+  }
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
   f = message.getFolderid();
   if (f.length > 0) {
     writer.writeString(
-      1,
+      3,
       f
     );
   }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
 };
 
 
 /**
- * optional string folderId = 1;
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.CreateFolderResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.CreateFolderResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
+};
+
+
+/**
+ * optional int32 errorCode = 2;
+ * @return {number}
+ */
+proto.gigamessages.CreateFolderResponse.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.CreateFolderResponse.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional string folderId = 3;
  * @return {string}
  */
-proto.gigamessages.GetNotesList.prototype.getFolderid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.CreateFolderResponse.prototype.getFolderid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.GetNotesList.prototype.setFolderid = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+proto.gigamessages.CreateFolderResponse.prototype.setFolderid = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.CreateFolderResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.CreateFolderResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.CreateFolderResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CreateFolderResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
 };
 
 
@@ -1713,12 +1976,12 @@ proto.gigamessages.GetNotesList.prototype.setFolderid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetAllNotes = function(opt_data) {
+proto.gigamessages.CreateNoteResponse = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.GetAllNotes, jspb.Message);
+goog.inherits(proto.gigamessages.CreateNoteResponse, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetAllNotes.displayName = 'proto.gigamessages.GetAllNotes';
+  proto.gigamessages.CreateNoteResponse.displayName = 'proto.gigamessages.CreateNoteResponse';
 }
 
 
@@ -1733,8 +1996,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetAllNotes.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetAllNotes.toObject(opt_includeInstance, this);
+proto.gigamessages.CreateNoteResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.CreateNoteResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -1743,14 +2006,16 @@ proto.gigamessages.GetAllNotes.prototype.toObject = function(opt_includeInstance
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetAllNotes} msg The msg instance to transform.
+ * @param {!proto.gigamessages.CreateNoteResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetAllNotes.toObject = function(includeInstance, msg) {
+proto.gigamessages.CreateNoteResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    offset: jspb.Message.getFieldWithDefault(msg, 1, 0),
-    limit: jspb.Message.getFieldWithDefault(msg, 2, 0)
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    noteid: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -1764,23 +2029,23 @@ proto.gigamessages.GetAllNotes.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetAllNotes}
+ * @return {!proto.gigamessages.CreateNoteResponse}
  */
-proto.gigamessages.GetAllNotes.deserializeBinary = function(bytes) {
+proto.gigamessages.CreateNoteResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetAllNotes;
-  return proto.gigamessages.GetAllNotes.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.CreateNoteResponse;
+  return proto.gigamessages.CreateNoteResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetAllNotes} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.CreateNoteResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetAllNotes}
+ * @return {!proto.gigamessages.CreateNoteResponse}
  */
-proto.gigamessages.GetAllNotes.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.CreateNoteResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -1788,12 +2053,21 @@ proto.gigamessages.GetAllNotes.deserializeBinaryFromReader = function(msg, reade
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setOffset(value);
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
       break;
     case 2:
       var value = /** @type {number} */ (reader.readInt32());
-      msg.setLimit(value);
+      msg.setErrorcode(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setNoteid(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
       break;
     default:
       reader.skipField();
@@ -1808,9 +2082,9 @@ proto.gigamessages.GetAllNotes.deserializeBinaryFromReader = function(msg, reade
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetAllNotes.prototype.serializeBinary = function() {
+proto.gigamessages.CreateNoteResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetAllNotes.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.CreateNoteResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -1818,59 +2092,121 @@ proto.gigamessages.GetAllNotes.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetAllNotes} message
+ * @param {!proto.gigamessages.CreateNoteResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetAllNotes.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.CreateNoteResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getOffset();
-  if (f !== 0) {
-    writer.writeInt32(
-      1,
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
+      1,
       f
     );
   }
-  f = message.getLimit();
+  f = message.getErrorcode();
   if (f !== 0) {
     writer.writeInt32(
       2,
       f
     );
   }
+  f = message.getNoteid();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
 };
 
 
 /**
- * optional int32 offset = 1;
- * @return {number}
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
  */
-proto.gigamessages.GetAllNotes.prototype.getOffset = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+proto.gigamessages.CreateNoteResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.GetAllNotes.prototype.setOffset = function(value) {
-  jspb.Message.setProto3IntField(this, 1, value);
+/** @param {boolean} value */
+proto.gigamessages.CreateNoteResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
 };
 
 
 /**
- * optional int32 limit = 2;
+ * optional int32 errorCode = 2;
  * @return {number}
  */
-proto.gigamessages.GetAllNotes.prototype.getLimit = function() {
+proto.gigamessages.CreateNoteResponse.prototype.getErrorcode = function() {
   return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /** @param {number} value */
-proto.gigamessages.GetAllNotes.prototype.setLimit = function(value) {
+proto.gigamessages.CreateNoteResponse.prototype.setErrorcode = function(value) {
   jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
+/**
+ * optional string noteId = 3;
+ * @return {string}
+ */
+proto.gigamessages.CreateNoteResponse.prototype.getNoteid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.CreateNoteResponse.prototype.setNoteid = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.CreateNoteResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.CreateNoteResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.CreateNoteResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CreateNoteResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
 
 /**
  * Generated by JsPbCodeGenerator.
@@ -1882,12 +2218,12 @@ proto.gigamessages.GetAllNotes.prototype.setLimit = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.SetToken = function(opt_data) {
+proto.gigamessages.GetNotesList = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.SetToken, jspb.Message);
+goog.inherits(proto.gigamessages.GetNotesList, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.SetToken.displayName = 'proto.gigamessages.SetToken';
+  proto.gigamessages.GetNotesList.displayName = 'proto.gigamessages.GetNotesList';
 }
 
 
@@ -1902,8 +2238,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.SetToken.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.SetToken.toObject(opt_includeInstance, this);
+proto.gigamessages.GetNotesList.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetNotesList.toObject(opt_includeInstance, this);
 };
 
 
@@ -1912,13 +2248,13 @@ proto.gigamessages.SetToken.prototype.toObject = function(opt_includeInstance) {
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.SetToken} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetNotesList} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.SetToken.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetNotesList.toObject = function(includeInstance, msg) {
   var f, obj = {
-    token: jspb.Message.getFieldWithDefault(msg, 1, "")
+    folderid: jspb.Message.getFieldWithDefault(msg, 1, "")
   };
 
   if (includeInstance) {
@@ -1932,23 +2268,23 @@ proto.gigamessages.SetToken.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.SetToken}
+ * @return {!proto.gigamessages.GetNotesList}
  */
-proto.gigamessages.SetToken.deserializeBinary = function(bytes) {
+proto.gigamessages.GetNotesList.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.SetToken;
-  return proto.gigamessages.SetToken.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetNotesList;
+  return proto.gigamessages.GetNotesList.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.SetToken} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetNotesList} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.SetToken}
+ * @return {!proto.gigamessages.GetNotesList}
  */
-proto.gigamessages.SetToken.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetNotesList.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -1957,7 +2293,7 @@ proto.gigamessages.SetToken.deserializeBinaryFromReader = function(msg, reader)
     switch (field) {
     case 1:
       var value = /** @type {string} */ (reader.readString());
-      msg.setToken(value);
+      msg.setFolderid(value);
       break;
     default:
       reader.skipField();
@@ -1972,9 +2308,9 @@ proto.gigamessages.SetToken.deserializeBinaryFromReader = function(msg, reader)
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.SetToken.prototype.serializeBinary = function() {
+proto.gigamessages.GetNotesList.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.SetToken.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.GetNotesList.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -1982,13 +2318,13 @@ proto.gigamessages.SetToken.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.SetToken} message
+ * @param {!proto.gigamessages.GetNotesList} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.SetToken.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.GetNotesList.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getToken();
+  f = message.getFolderid();
   if (f.length > 0) {
     writer.writeString(
       1,
@@ -1999,16 +2335,16 @@ proto.gigamessages.SetToken.serializeBinaryToWriter = function(message, writer)
 
 
 /**
- * optional string token = 1;
+ * optional string folderId = 1;
  * @return {string}
  */
-proto.gigamessages.SetToken.prototype.getToken = function() {
+proto.gigamessages.GetNotesList.prototype.getFolderid = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.SetToken.prototype.setToken = function(value) {
+proto.gigamessages.GetNotesList.prototype.setFolderid = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
@@ -2024,12 +2360,12 @@ proto.gigamessages.SetToken.prototype.setToken = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.NoteShortInfo = function(opt_data) {
+proto.gigamessages.GetAllNotes = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.NoteShortInfo, jspb.Message);
+goog.inherits(proto.gigamessages.GetAllNotes, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.NoteShortInfo.displayName = 'proto.gigamessages.NoteShortInfo';
+  proto.gigamessages.GetAllNotes.displayName = 'proto.gigamessages.GetAllNotes';
 }
 
 
@@ -2044,8 +2380,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.NoteShortInfo.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.NoteShortInfo.toObject(opt_includeInstance, this);
+proto.gigamessages.GetAllNotes.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetAllNotes.toObject(opt_includeInstance, this);
 };
 
 
@@ -2054,17 +2390,14 @@ proto.gigamessages.NoteShortInfo.prototype.toObject = function(opt_includeInstan
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.NoteShortInfo} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetAllNotes} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.NoteShortInfo.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetAllNotes.toObject = function(includeInstance, msg) {
   var f, obj = {
-    id: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    folderid: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    title: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    createdat: jspb.Message.getFieldWithDefault(msg, 4, 0),
-    updatedat: jspb.Message.getFieldWithDefault(msg, 5, 0)
+    offset: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    limit: jspb.Message.getFieldWithDefault(msg, 2, 0)
   };
 
   if (includeInstance) {
@@ -2078,23 +2411,23 @@ proto.gigamessages.NoteShortInfo.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.NoteShortInfo}
+ * @return {!proto.gigamessages.GetAllNotes}
  */
-proto.gigamessages.NoteShortInfo.deserializeBinary = function(bytes) {
+proto.gigamessages.GetAllNotes.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.NoteShortInfo;
-  return proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetAllNotes;
+  return proto.gigamessages.GetAllNotes.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.NoteShortInfo} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetAllNotes} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.NoteShortInfo}
+ * @return {!proto.gigamessages.GetAllNotes}
  */
-proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetAllNotes.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -2102,24 +2435,12 @@ proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader = function(msg, rea
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setId(value);
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setOffset(value);
       break;
     case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
-      break;
-    case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
-      break;
-    case 4:
-      var value = /** @type {number} */ (reader.readInt64());
-      msg.setCreatedat(value);
-      break;
-    case 5:
-      var value = /** @type {number} */ (reader.readInt64());
-      msg.setUpdatedat(value);
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setLimit(value);
       break;
     default:
       reader.skipField();
@@ -2134,9 +2455,9 @@ proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader = function(msg, rea
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.NoteShortInfo.prototype.serializeBinary = function() {
+proto.gigamessages.GetAllNotes.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.NoteShortInfo.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.GetAllNotes.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -2144,44 +2465,23 @@ proto.gigamessages.NoteShortInfo.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.NoteShortInfo} message
+ * @param {!proto.gigamessages.GetAllNotes} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.NoteShortInfo.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.GetAllNotes.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getId();
-  if (f.length > 0) {
-    writer.writeString(
-      1,
-      f
-    );
-  }
-  f = message.getFolderid();
-  if (f.length > 0) {
-    writer.writeString(
-      2,
-      f
-    );
-  }
-  f = message.getTitle();
-  if (f.length > 0) {
-    writer.writeString(
-      3,
-      f
-    );
-  }
-  f = message.getCreatedat();
+  f = message.getOffset();
   if (f !== 0) {
-    writer.writeInt64(
-      4,
+    writer.writeInt32(
+      1,
       f
     );
   }
-  f = message.getUpdatedat();
+  f = message.getLimit();
   if (f !== 0) {
-    writer.writeInt64(
-      5,
+    writer.writeInt32(
+      2,
       f
     );
   }
@@ -2189,77 +2489,32 @@ proto.gigamessages.NoteShortInfo.serializeBinaryToWriter = function(message, wri
 
 
 /**
- * optional string id = 1;
- * @return {string}
+ * optional int32 offset = 1;
+ * @return {number}
  */
-proto.gigamessages.NoteShortInfo.prototype.getId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.GetAllNotes.prototype.getOffset = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.NoteShortInfo.prototype.setId = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/** @param {number} value */
+proto.gigamessages.GetAllNotes.prototype.setOffset = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
 /**
- * optional string folderId = 2;
- * @return {string}
+ * optional int32 limit = 2;
+ * @return {number}
  */
-proto.gigamessages.NoteShortInfo.prototype.getFolderid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
-};
-
-
-/** @param {string} value */
-proto.gigamessages.NoteShortInfo.prototype.setFolderid = function(value) {
-  jspb.Message.setProto3StringField(this, 2, value);
+proto.gigamessages.GetAllNotes.prototype.getLimit = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
-/**
- * optional string title = 3;
- * @return {string}
- */
-proto.gigamessages.NoteShortInfo.prototype.getTitle = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
-};
-
-
-/** @param {string} value */
-proto.gigamessages.NoteShortInfo.prototype.setTitle = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
-};
-
-
-/**
- * optional int64 createdAt = 4;
- * @return {number}
- */
-proto.gigamessages.NoteShortInfo.prototype.getCreatedat = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
-};
-
-
-/** @param {number} value */
-proto.gigamessages.NoteShortInfo.prototype.setCreatedat = function(value) {
-  jspb.Message.setProto3IntField(this, 4, value);
-};
-
-
-/**
- * optional int64 updatedAt = 5;
- * @return {number}
- */
-proto.gigamessages.NoteShortInfo.prototype.getUpdatedat = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
-};
-
-
-/** @param {number} value */
-proto.gigamessages.NoteShortInfo.prototype.setUpdatedat = function(value) {
-  jspb.Message.setProto3IntField(this, 5, value);
+/** @param {number} value */
+proto.gigamessages.GetAllNotes.prototype.setLimit = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
@@ -2274,20 +2529,13 @@ proto.gigamessages.NoteShortInfo.prototype.setUpdatedat = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetNotesListResponse = function(opt_data) {
-  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.GetNotesListResponse.repeatedFields_, null);
+proto.gigamessages.SetToken = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.GetNotesListResponse, jspb.Message);
+goog.inherits(proto.gigamessages.SetToken, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetNotesListResponse.displayName = 'proto.gigamessages.GetNotesListResponse';
+  proto.gigamessages.SetToken.displayName = 'proto.gigamessages.SetToken';
 }
-/**
- * List of repeated fields within this message type.
- * @private {!Array<number>}
- * @const
- */
-proto.gigamessages.GetNotesListResponse.repeatedFields_ = [3];
-
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -2301,8 +2549,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetNotesListResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetNotesListResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.SetToken.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.SetToken.toObject(opt_includeInstance, this);
 };
 
 
@@ -2311,16 +2559,13 @@ proto.gigamessages.GetNotesListResponse.prototype.toObject = function(opt_includ
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetNotesListResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.SetToken} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNotesListResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.SetToken.toObject = function(includeInstance, msg) {
   var f, obj = {
-    success: jspb.Message.getFieldWithDefault(msg, 1, false),
-    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    notesList: jspb.Message.toObjectList(msg.getNotesList(),
-    proto.gigamessages.NoteShortInfo.toObject, includeInstance)
+    token: jspb.Message.getFieldWithDefault(msg, 1, "")
   };
 
   if (includeInstance) {
@@ -2334,23 +2579,23 @@ proto.gigamessages.GetNotesListResponse.toObject = function(includeInstance, msg
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetNotesListResponse}
+ * @return {!proto.gigamessages.SetToken}
  */
-proto.gigamessages.GetNotesListResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.SetToken.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetNotesListResponse;
-  return proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.SetToken;
+  return proto.gigamessages.SetToken.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetNotesListResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.SetToken} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetNotesListResponse}
+ * @return {!proto.gigamessages.SetToken}
  */
-proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.SetToken.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -2358,17 +2603,8 @@ proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader = function(m
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setSuccess(value);
-      break;
-    case 2:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setErrorcode(value);
-      break;
-    case 3:
-      var value = new proto.gigamessages.NoteShortInfo;
-      reader.readMessage(value,proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader);
-      msg.addNotes(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setToken(value);
       break;
     default:
       reader.skipField();
@@ -2383,9 +2619,9 @@ proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader = function(m
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetNotesListResponse.prototype.serializeBinary = function() {
+proto.gigamessages.SetToken.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.SetToken.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -2393,97 +2629,34 @@ proto.gigamessages.GetNotesListResponse.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetNotesListResponse} message
+ * @param {!proto.gigamessages.SetToken} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.SetToken.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSuccess();
-  if (f) {
-    writer.writeBool(
+  f = message.getToken();
+  if (f.length > 0) {
+    writer.writeString(
       1,
       f
     );
   }
-  f = message.getErrorcode();
-  if (f !== 0) {
-    writer.writeInt32(
-      2,
-      f
-    );
-  }
-  f = message.getNotesList();
-  if (f.length > 0) {
-    writer.writeRepeatedMessage(
-      3,
-      f,
-      proto.gigamessages.NoteShortInfo.serializeBinaryToWriter
-    );
-  }
-};
-
-
-/**
- * optional bool success = 1;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
- * @return {boolean}
- */
-proto.gigamessages.GetNotesListResponse.prototype.getSuccess = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
-};
-
-
-/** @param {boolean} value */
-proto.gigamessages.GetNotesListResponse.prototype.setSuccess = function(value) {
-  jspb.Message.setProto3BooleanField(this, 1, value);
-};
-
-
-/**
- * optional int32 errorCode = 2;
- * @return {number}
- */
-proto.gigamessages.GetNotesListResponse.prototype.getErrorcode = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
-};
-
-
-/** @param {number} value */
-proto.gigamessages.GetNotesListResponse.prototype.setErrorcode = function(value) {
-  jspb.Message.setProto3IntField(this, 2, value);
-};
-
-
-/**
- * repeated NoteShortInfo notes = 3;
- * @return {!Array<!proto.gigamessages.NoteShortInfo>}
- */
-proto.gigamessages.GetNotesListResponse.prototype.getNotesList = function() {
-  return /** @type{!Array<!proto.gigamessages.NoteShortInfo>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.NoteShortInfo, 3));
-};
-
-
-/** @param {!Array<!proto.gigamessages.NoteShortInfo>} value */
-proto.gigamessages.GetNotesListResponse.prototype.setNotesList = function(value) {
-  jspb.Message.setRepeatedWrapperField(this, 3, value);
 };
 
 
 /**
- * @param {!proto.gigamessages.NoteShortInfo=} opt_value
- * @param {number=} opt_index
- * @return {!proto.gigamessages.NoteShortInfo}
+ * optional string token = 1;
+ * @return {string}
  */
-proto.gigamessages.GetNotesListResponse.prototype.addNotes = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 3, opt_value, proto.gigamessages.NoteShortInfo, opt_index);
+proto.gigamessages.SetToken.prototype.getToken = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
-proto.gigamessages.GetNotesListResponse.prototype.clearNotesList = function() {
-  this.setNotesList([]);
+/** @param {string} value */
+proto.gigamessages.SetToken.prototype.setToken = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
@@ -2498,12 +2671,12 @@ proto.gigamessages.GetNotesListResponse.prototype.clearNotesList = function() {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.Folder = function(opt_data) {
+proto.gigamessages.NoteShortInfo = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.Folder, jspb.Message);
+goog.inherits(proto.gigamessages.NoteShortInfo, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.Folder.displayName = 'proto.gigamessages.Folder';
+  proto.gigamessages.NoteShortInfo.displayName = 'proto.gigamessages.NoteShortInfo';
 }
 
 
@@ -2518,8 +2691,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.Folder.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.Folder.toObject(opt_includeInstance, this);
+proto.gigamessages.NoteShortInfo.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.NoteShortInfo.toObject(opt_includeInstance, this);
 };
 
 
@@ -2528,18 +2701,17 @@ proto.gigamessages.Folder.prototype.toObject = function(opt_includeInstance) {
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.Folder} msg The msg instance to transform.
+ * @param {!proto.gigamessages.NoteShortInfo} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.Folder.toObject = function(includeInstance, msg) {
+proto.gigamessages.NoteShortInfo.toObject = function(includeInstance, msg) {
   var f, obj = {
     id: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    parentid: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    folderid: jspb.Message.getFieldWithDefault(msg, 2, ""),
     title: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    level: jspb.Message.getFieldWithDefault(msg, 4, 0),
-    createdat: jspb.Message.getFieldWithDefault(msg, 5, 0),
-    updatedat: jspb.Message.getFieldWithDefault(msg, 6, 0)
+    createdat: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    updatedat: jspb.Message.getFieldWithDefault(msg, 5, 0)
   };
 
   if (includeInstance) {
@@ -2553,23 +2725,23 @@ proto.gigamessages.Folder.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.Folder}
+ * @return {!proto.gigamessages.NoteShortInfo}
  */
-proto.gigamessages.Folder.deserializeBinary = function(bytes) {
+proto.gigamessages.NoteShortInfo.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.Folder;
-  return proto.gigamessages.Folder.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.NoteShortInfo;
+  return proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.Folder} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.NoteShortInfo} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.Folder}
+ * @return {!proto.gigamessages.NoteShortInfo}
  */
-proto.gigamessages.Folder.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -2582,21 +2754,17 @@ proto.gigamessages.Folder.deserializeBinaryFromReader = function(msg, reader) {
       break;
     case 2:
       var value = /** @type {string} */ (reader.readString());
-      msg.setParentid(value);
+      msg.setFolderid(value);
       break;
     case 3:
       var value = /** @type {string} */ (reader.readString());
       msg.setTitle(value);
       break;
     case 4:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setLevel(value);
-      break;
-    case 5:
       var value = /** @type {number} */ (reader.readInt64());
       msg.setCreatedat(value);
       break;
-    case 6:
+    case 5:
       var value = /** @type {number} */ (reader.readInt64());
       msg.setUpdatedat(value);
       break;
@@ -2613,9 +2781,9 @@ proto.gigamessages.Folder.deserializeBinaryFromReader = function(msg, reader) {
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.Folder.prototype.serializeBinary = function() {
+proto.gigamessages.NoteShortInfo.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.Folder.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.NoteShortInfo.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -2623,11 +2791,11 @@ proto.gigamessages.Folder.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.Folder} message
+ * @param {!proto.gigamessages.NoteShortInfo} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.Folder.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.NoteShortInfo.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
   f = message.getId();
   if (f.length > 0) {
@@ -2636,7 +2804,7 @@ proto.gigamessages.Folder.serializeBinaryToWriter = function(message, writer) {
       f
     );
   }
-  f = message.getParentid();
+  f = message.getFolderid();
   if (f.length > 0) {
     writer.writeString(
       2,
@@ -2650,24 +2818,17 @@ proto.gigamessages.Folder.serializeBinaryToWriter = function(message, writer) {
       f
     );
   }
-  f = message.getLevel();
-  if (f !== 0) {
-    writer.writeInt32(
-      4,
-      f
-    );
-  }
   f = message.getCreatedat();
   if (f !== 0) {
     writer.writeInt64(
-      5,
+      4,
       f
     );
   }
   f = message.getUpdatedat();
   if (f !== 0) {
     writer.writeInt64(
-      6,
+      5,
       f
     );
   }
@@ -2678,28 +2839,28 @@ proto.gigamessages.Folder.serializeBinaryToWriter = function(message, writer) {
  * optional string id = 1;
  * @return {string}
  */
-proto.gigamessages.Folder.prototype.getId = function() {
+proto.gigamessages.NoteShortInfo.prototype.getId = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.Folder.prototype.setId = function(value) {
+proto.gigamessages.NoteShortInfo.prototype.setId = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
 /**
- * optional string parentId = 2;
+ * optional string folderId = 2;
  * @return {string}
  */
-proto.gigamessages.Folder.prototype.getParentid = function() {
+proto.gigamessages.NoteShortInfo.prototype.getFolderid = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.Folder.prototype.setParentid = function(value) {
+proto.gigamessages.NoteShortInfo.prototype.setFolderid = function(value) {
   jspb.Message.setProto3StringField(this, 2, value);
 };
 
@@ -2708,62 +2869,47 @@ proto.gigamessages.Folder.prototype.setParentid = function(value) {
  * optional string title = 3;
  * @return {string}
  */
-proto.gigamessages.Folder.prototype.getTitle = function() {
+proto.gigamessages.NoteShortInfo.prototype.getTitle = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.Folder.prototype.setTitle = function(value) {
+proto.gigamessages.NoteShortInfo.prototype.setTitle = function(value) {
   jspb.Message.setProto3StringField(this, 3, value);
 };
 
 
 /**
- * optional int32 level = 4;
+ * optional int64 createdAt = 4;
  * @return {number}
  */
-proto.gigamessages.Folder.prototype.getLevel = function() {
+proto.gigamessages.NoteShortInfo.prototype.getCreatedat = function() {
   return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
 };
 
 
 /** @param {number} value */
-proto.gigamessages.Folder.prototype.setLevel = function(value) {
+proto.gigamessages.NoteShortInfo.prototype.setCreatedat = function(value) {
   jspb.Message.setProto3IntField(this, 4, value);
 };
 
 
 /**
- * optional int64 createdAt = 5;
+ * optional int64 updatedAt = 5;
  * @return {number}
  */
-proto.gigamessages.Folder.prototype.getCreatedat = function() {
+proto.gigamessages.NoteShortInfo.prototype.getUpdatedat = function() {
   return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
 };
 
 
 /** @param {number} value */
-proto.gigamessages.Folder.prototype.setCreatedat = function(value) {
+proto.gigamessages.NoteShortInfo.prototype.setUpdatedat = function(value) {
   jspb.Message.setProto3IntField(this, 5, value);
 };
 
 
-/**
- * optional int64 updatedAt = 6;
- * @return {number}
- */
-proto.gigamessages.Folder.prototype.getUpdatedat = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
-};
-
-
-/** @param {number} value */
-proto.gigamessages.Folder.prototype.setUpdatedat = function(value) {
-  jspb.Message.setProto3IntField(this, 6, value);
-};
-
-
 
 /**
  * Generated by JsPbCodeGenerator.
@@ -2775,19 +2921,19 @@ proto.gigamessages.Folder.prototype.setUpdatedat = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetFoldersListResponse = function(opt_data) {
-  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.GetFoldersListResponse.repeatedFields_, null);
+proto.gigamessages.GetNotesListResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.GetNotesListResponse.repeatedFields_, null);
 };
-goog.inherits(proto.gigamessages.GetFoldersListResponse, jspb.Message);
+goog.inherits(proto.gigamessages.GetNotesListResponse, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetFoldersListResponse.displayName = 'proto.gigamessages.GetFoldersListResponse';
+  proto.gigamessages.GetNotesListResponse.displayName = 'proto.gigamessages.GetNotesListResponse';
 }
 /**
  * List of repeated fields within this message type.
  * @private {!Array<number>}
  * @const
  */
-proto.gigamessages.GetFoldersListResponse.repeatedFields_ = [3];
+proto.gigamessages.GetNotesListResponse.repeatedFields_ = [3];
 
 
 
@@ -2802,8 +2948,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetFoldersListResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetFoldersListResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.GetNotesListResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetNotesListResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -2812,16 +2958,17 @@ proto.gigamessages.GetFoldersListResponse.prototype.toObject = function(opt_incl
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetFoldersListResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetNotesListResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetFoldersListResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetNotesListResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
     success: jspb.Message.getFieldWithDefault(msg, 1, false),
     errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    foldersList: jspb.Message.toObjectList(msg.getFoldersList(),
-    proto.gigamessages.Folder.toObject, includeInstance)
+    notesList: jspb.Message.toObjectList(msg.getNotesList(),
+    proto.gigamessages.NoteShortInfo.toObject, includeInstance),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -2835,23 +2982,23 @@ proto.gigamessages.GetFoldersListResponse.toObject = function(includeInstance, m
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetFoldersListResponse}
+ * @return {!proto.gigamessages.GetNotesListResponse}
  */
-proto.gigamessages.GetFoldersListResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.GetNotesListResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetFoldersListResponse;
-  return proto.gigamessages.GetFoldersListResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetNotesListResponse;
+  return proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetFoldersListResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetNotesListResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetFoldersListResponse}
+ * @return {!proto.gigamessages.GetNotesListResponse}
  */
-proto.gigamessages.GetFoldersListResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -2867,9 +3014,14 @@ proto.gigamessages.GetFoldersListResponse.deserializeBinaryFromReader = function
       msg.setErrorcode(value);
       break;
     case 3:
-      var value = new proto.gigamessages.Folder;
-      reader.readMessage(value,proto.gigamessages.Folder.deserializeBinaryFromReader);
-      msg.addFolders(value);
+      var value = new proto.gigamessages.NoteShortInfo;
+      reader.readMessage(value,proto.gigamessages.NoteShortInfo.deserializeBinaryFromReader);
+      msg.addNotes(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
       break;
     default:
       reader.skipField();
@@ -2884,9 +3036,9 @@ proto.gigamessages.GetFoldersListResponse.deserializeBinaryFromReader = function
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetFoldersListResponse.prototype.serializeBinary = function() {
+proto.gigamessages.GetNotesListResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetFoldersListResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -2894,11 +3046,11 @@ proto.gigamessages.GetFoldersListResponse.prototype.serializeBinary = function()
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetFoldersListResponse} message
+ * @param {!proto.gigamessages.GetNotesListResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetFoldersListResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
   f = message.getSuccess();
   if (f) {
@@ -2914,12 +3066,20 @@ proto.gigamessages.GetFoldersListResponse.serializeBinaryToWriter = function(mes
       f
     );
   }
-  f = message.getFoldersList();
+  f = message.getNotesList();
   if (f.length > 0) {
     writer.writeRepeatedMessage(
       3,
       f,
-      proto.gigamessages.Folder.serializeBinaryToWriter
+      proto.gigamessages.NoteShortInfo.serializeBinaryToWriter
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
     );
   }
 };
@@ -2931,13 +3091,13 @@ proto.gigamessages.GetFoldersListResponse.serializeBinaryToWriter = function(mes
  * You should avoid comparisons like {@code val === true/false} in those cases.
  * @return {boolean}
  */
-proto.gigamessages.GetFoldersListResponse.prototype.getSuccess = function() {
+proto.gigamessages.GetNotesListResponse.prototype.getSuccess = function() {
   return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
 };
 
 
 /** @param {boolean} value */
-proto.gigamessages.GetFoldersListResponse.prototype.setSuccess = function(value) {
+proto.gigamessages.GetNotesListResponse.prototype.setSuccess = function(value) {
   jspb.Message.setProto3BooleanField(this, 1, value);
 };
 
@@ -2946,45 +3106,75 @@ proto.gigamessages.GetFoldersListResponse.prototype.setSuccess = function(value)
  * optional int32 errorCode = 2;
  * @return {number}
  */
-proto.gigamessages.GetFoldersListResponse.prototype.getErrorcode = function() {
+proto.gigamessages.GetNotesListResponse.prototype.getErrorcode = function() {
   return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /** @param {number} value */
-proto.gigamessages.GetFoldersListResponse.prototype.setErrorcode = function(value) {
+proto.gigamessages.GetNotesListResponse.prototype.setErrorcode = function(value) {
   jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
 /**
- * repeated Folder folders = 3;
- * @return {!Array<!proto.gigamessages.Folder>}
+ * repeated NoteShortInfo notes = 3;
+ * @return {!Array<!proto.gigamessages.NoteShortInfo>}
  */
-proto.gigamessages.GetFoldersListResponse.prototype.getFoldersList = function() {
-  return /** @type{!Array<!proto.gigamessages.Folder>} */ (
-    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.Folder, 3));
+proto.gigamessages.GetNotesListResponse.prototype.getNotesList = function() {
+  return /** @type{!Array<!proto.gigamessages.NoteShortInfo>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.NoteShortInfo, 3));
 };
 
 
-/** @param {!Array<!proto.gigamessages.Folder>} value */
-proto.gigamessages.GetFoldersListResponse.prototype.setFoldersList = function(value) {
+/** @param {!Array<!proto.gigamessages.NoteShortInfo>} value */
+proto.gigamessages.GetNotesListResponse.prototype.setNotesList = function(value) {
   jspb.Message.setRepeatedWrapperField(this, 3, value);
 };
 
 
 /**
- * @param {!proto.gigamessages.Folder=} opt_value
+ * @param {!proto.gigamessages.NoteShortInfo=} opt_value
  * @param {number=} opt_index
- * @return {!proto.gigamessages.Folder}
+ * @return {!proto.gigamessages.NoteShortInfo}
  */
-proto.gigamessages.GetFoldersListResponse.prototype.addFolders = function(opt_value, opt_index) {
-  return jspb.Message.addToRepeatedWrapperField(this, 3, opt_value, proto.gigamessages.Folder, opt_index);
+proto.gigamessages.GetNotesListResponse.prototype.addNotes = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 3, opt_value, proto.gigamessages.NoteShortInfo, opt_index);
 };
 
 
-proto.gigamessages.GetFoldersListResponse.prototype.clearFoldersList = function() {
-  this.setFoldersList([]);
+proto.gigamessages.GetNotesListResponse.prototype.clearNotesList = function() {
+  this.setNotesList([]);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.GetNotesListResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.GetNotesListResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.GetNotesListResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.GetNotesListResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
 };
 
 
@@ -2999,12 +3189,12 @@ proto.gigamessages.GetFoldersListResponse.prototype.clearFoldersList = function(
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetNoteById = function(opt_data) {
+proto.gigamessages.Folder = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.GetNoteById, jspb.Message);
+goog.inherits(proto.gigamessages.Folder, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetNoteById.displayName = 'proto.gigamessages.GetNoteById';
+  proto.gigamessages.Folder.displayName = 'proto.gigamessages.Folder';
 }
 
 
@@ -3019,8 +3209,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetNoteById.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetNoteById.toObject(opt_includeInstance, this);
+proto.gigamessages.Folder.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Folder.toObject(opt_includeInstance, this);
 };
 
 
@@ -3029,13 +3219,18 @@ proto.gigamessages.GetNoteById.prototype.toObject = function(opt_includeInstance
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetNoteById} msg The msg instance to transform.
+ * @param {!proto.gigamessages.Folder} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNoteById.toObject = function(includeInstance, msg) {
+proto.gigamessages.Folder.toObject = function(includeInstance, msg) {
   var f, obj = {
-    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
+    id: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    parentid: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    title: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    level: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    createdat: jspb.Message.getFieldWithDefault(msg, 5, 0),
+    updatedat: jspb.Message.getFieldWithDefault(msg, 6, 0)
   };
 
   if (includeInstance) {
@@ -3049,23 +3244,23 @@ proto.gigamessages.GetNoteById.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetNoteById}
+ * @return {!proto.gigamessages.Folder}
  */
-proto.gigamessages.GetNoteById.deserializeBinary = function(bytes) {
+proto.gigamessages.Folder.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetNoteById;
-  return proto.gigamessages.GetNoteById.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.Folder;
+  return proto.gigamessages.Folder.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetNoteById} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.Folder} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetNoteById}
+ * @return {!proto.gigamessages.Folder}
  */
-proto.gigamessages.GetNoteById.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.Folder.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -3074,7 +3269,27 @@ proto.gigamessages.GetNoteById.deserializeBinaryFromReader = function(msg, reade
     switch (field) {
     case 1:
       var value = /** @type {string} */ (reader.readString());
-      msg.setNoteid(value);
+      msg.setId(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setParentid(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTitle(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setLevel(value);
+      break;
+    case 5:
+      var value = /** @type {number} */ (reader.readInt64());
+      msg.setCreatedat(value);
+      break;
+    case 6:
+      var value = /** @type {number} */ (reader.readInt64());
+      msg.setUpdatedat(value);
       break;
     default:
       reader.skipField();
@@ -3089,9 +3304,9 @@ proto.gigamessages.GetNoteById.deserializeBinaryFromReader = function(msg, reade
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetNoteById.prototype.serializeBinary = function() {
+proto.gigamessages.Folder.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetNoteById.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.Folder.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -3099,55 +3314,172 @@ proto.gigamessages.GetNoteById.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetNoteById} message
+ * @param {!proto.gigamessages.Folder} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNoteById.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.Folder.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getNoteid();
+  f = message.getId();
   if (f.length > 0) {
     writer.writeString(
       1,
       f
     );
   }
+  f = message.getParentid();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getTitle();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getLevel();
+  if (f !== 0) {
+    writer.writeInt32(
+      4,
+      f
+    );
+  }
+  f = message.getCreatedat();
+  if (f !== 0) {
+    writer.writeInt64(
+      5,
+      f
+    );
+  }
+  f = message.getUpdatedat();
+  if (f !== 0) {
+    writer.writeInt64(
+      6,
+      f
+    );
+  }
 };
 
 
 /**
- * optional string noteId = 1;
+ * optional string id = 1;
  * @return {string}
  */
-proto.gigamessages.GetNoteById.prototype.getNoteid = function() {
+proto.gigamessages.Folder.prototype.getId = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.GetNoteById.prototype.setNoteid = function(value) {
+proto.gigamessages.Folder.prototype.setId = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
-
 /**
- * Generated by JsPbCodeGenerator.
- * @param {Array=} opt_data Optional initial data array, typically from a
- * server response, or constructed directly in Javascript. The array is used
- * in place and becomes part of the constructed object. It is not cloned.
- * If no data is provided, the constructed object will be empty, but still
- * valid.
- * @extends {jspb.Message}
- * @constructor
+ * optional string parentId = 2;
+ * @return {string}
  */
-proto.gigamessages.GetNoteByIdResponse = function(opt_data) {
-  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+proto.gigamessages.Folder.prototype.getParentid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
-goog.inherits(proto.gigamessages.GetNoteByIdResponse, jspb.Message);
+
+
+/** @param {string} value */
+proto.gigamessages.Folder.prototype.setParentid = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional string title = 3;
+ * @return {string}
+ */
+proto.gigamessages.Folder.prototype.getTitle = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.Folder.prototype.setTitle = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional int32 level = 4;
+ * @return {number}
+ */
+proto.gigamessages.Folder.prototype.getLevel = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Folder.prototype.setLevel = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional int64 createdAt = 5;
+ * @return {number}
+ */
+proto.gigamessages.Folder.prototype.getCreatedat = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Folder.prototype.setCreatedat = function(value) {
+  jspb.Message.setProto3IntField(this, 5, value);
+};
+
+
+/**
+ * optional int64 updatedAt = 6;
+ * @return {number}
+ */
+proto.gigamessages.Folder.prototype.getUpdatedat = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Folder.prototype.setUpdatedat = function(value) {
+  jspb.Message.setProto3IntField(this, 6, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.GetFoldersListResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.GetFoldersListResponse.repeatedFields_, null);
+};
+goog.inherits(proto.gigamessages.GetFoldersListResponse, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetNoteByIdResponse.displayName = 'proto.gigamessages.GetNoteByIdResponse';
+  proto.gigamessages.GetFoldersListResponse.displayName = 'proto.gigamessages.GetFoldersListResponse';
 }
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.gigamessages.GetFoldersListResponse.repeatedFields_ = [3];
+
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -3161,8 +3493,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetNoteByIdResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetNoteByIdResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.GetFoldersListResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetFoldersListResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -3171,18 +3503,17 @@ proto.gigamessages.GetNoteByIdResponse.prototype.toObject = function(opt_include
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetNoteByIdResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetFoldersListResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNoteByIdResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetFoldersListResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
     success: jspb.Message.getFieldWithDefault(msg, 1, false),
     errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    id: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    folderid: jspb.Message.getFieldWithDefault(msg, 4, ""),
-    title: jspb.Message.getFieldWithDefault(msg, 5, ""),
-    text: jspb.Message.getFieldWithDefault(msg, 6, "")
+    foldersList: jspb.Message.toObjectList(msg.getFoldersList(),
+    proto.gigamessages.Folder.toObject, includeInstance),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -3196,23 +3527,23 @@ proto.gigamessages.GetNoteByIdResponse.toObject = function(includeInstance, msg)
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetNoteByIdResponse}
+ * @return {!proto.gigamessages.GetFoldersListResponse}
  */
-proto.gigamessages.GetNoteByIdResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.GetFoldersListResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetNoteByIdResponse;
-  return proto.gigamessages.GetNoteByIdResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetFoldersListResponse;
+  return proto.gigamessages.GetFoldersListResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetNoteByIdResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetFoldersListResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetNoteByIdResponse}
+ * @return {!proto.gigamessages.GetFoldersListResponse}
  */
-proto.gigamessages.GetNoteByIdResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetFoldersListResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -3228,20 +3559,14 @@ proto.gigamessages.GetNoteByIdResponse.deserializeBinaryFromReader = function(ms
       msg.setErrorcode(value);
       break;
     case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setId(value);
-      break;
-    case 4:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
-      break;
-    case 5:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
+      var value = new proto.gigamessages.Folder;
+      reader.readMessage(value,proto.gigamessages.Folder.deserializeBinaryFromReader);
+      msg.addFolders(value);
       break;
-    case 6:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setText(value);
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
       break;
     default:
       reader.skipField();
@@ -3256,9 +3581,9 @@ proto.gigamessages.GetNoteByIdResponse.deserializeBinaryFromReader = function(ms
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetNoteByIdResponse.prototype.serializeBinary = function() {
+proto.gigamessages.GetFoldersListResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetNoteByIdResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.GetFoldersListResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -3266,11 +3591,11 @@ proto.gigamessages.GetNoteByIdResponse.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetNoteByIdResponse} message
+ * @param {!proto.gigamessages.GetFoldersListResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetNoteByIdResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.GetFoldersListResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
   f = message.getSuccess();
   if (f) {
@@ -3286,32 +3611,20 @@ proto.gigamessages.GetNoteByIdResponse.serializeBinaryToWriter = function(messag
       f
     );
   }
-  f = message.getId();
+  f = message.getFoldersList();
   if (f.length > 0) {
-    writer.writeString(
+    writer.writeRepeatedMessage(
       3,
-      f
-    );
-  }
-  f = message.getFolderid();
-  if (f.length > 0) {
-    writer.writeString(
-      4,
-      f
-    );
-  }
-  f = message.getTitle();
-  if (f.length > 0) {
-    writer.writeString(
-      5,
-      f
+      f,
+      proto.gigamessages.Folder.serializeBinaryToWriter
     );
   }
-  f = message.getText();
-  if (f.length > 0) {
-    writer.writeString(
-      6,
-      f
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
     );
   }
 };
@@ -3323,13 +3636,13 @@ proto.gigamessages.GetNoteByIdResponse.serializeBinaryToWriter = function(messag
  * You should avoid comparisons like {@code val === true/false} in those cases.
  * @return {boolean}
  */
-proto.gigamessages.GetNoteByIdResponse.prototype.getSuccess = function() {
+proto.gigamessages.GetFoldersListResponse.prototype.getSuccess = function() {
   return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
 };
 
 
 /** @param {boolean} value */
-proto.gigamessages.GetNoteByIdResponse.prototype.setSuccess = function(value) {
+proto.gigamessages.GetFoldersListResponse.prototype.setSuccess = function(value) {
   jspb.Message.setProto3BooleanField(this, 1, value);
 };
 
@@ -3338,74 +3651,75 @@ proto.gigamessages.GetNoteByIdResponse.prototype.setSuccess = function(value) {
  * optional int32 errorCode = 2;
  * @return {number}
  */
-proto.gigamessages.GetNoteByIdResponse.prototype.getErrorcode = function() {
+proto.gigamessages.GetFoldersListResponse.prototype.getErrorcode = function() {
   return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /** @param {number} value */
-proto.gigamessages.GetNoteByIdResponse.prototype.setErrorcode = function(value) {
+proto.gigamessages.GetFoldersListResponse.prototype.setErrorcode = function(value) {
   jspb.Message.setProto3IntField(this, 2, value);
 };
 
 
 /**
- * optional string id = 3;
- * @return {string}
+ * repeated Folder folders = 3;
+ * @return {!Array<!proto.gigamessages.Folder>}
  */
-proto.gigamessages.GetNoteByIdResponse.prototype.getId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+proto.gigamessages.GetFoldersListResponse.prototype.getFoldersList = function() {
+  return /** @type{!Array<!proto.gigamessages.Folder>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.Folder, 3));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.GetNoteByIdResponse.prototype.setId = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
+/** @param {!Array<!proto.gigamessages.Folder>} value */
+proto.gigamessages.GetFoldersListResponse.prototype.setFoldersList = function(value) {
+  jspb.Message.setRepeatedWrapperField(this, 3, value);
 };
 
 
 /**
- * optional string folderId = 4;
- * @return {string}
+ * @param {!proto.gigamessages.Folder=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.gigamessages.Folder}
  */
-proto.gigamessages.GetNoteByIdResponse.prototype.getFolderid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+proto.gigamessages.GetFoldersListResponse.prototype.addFolders = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 3, opt_value, proto.gigamessages.Folder, opt_index);
 };
 
 
-/** @param {string} value */
-proto.gigamessages.GetNoteByIdResponse.prototype.setFolderid = function(value) {
-  jspb.Message.setProto3StringField(this, 4, value);
+proto.gigamessages.GetFoldersListResponse.prototype.clearFoldersList = function() {
+  this.setFoldersList([]);
 };
 
 
 /**
- * optional string title = 5;
- * @return {string}
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
  */
-proto.gigamessages.GetNoteByIdResponse.prototype.getTitle = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+proto.gigamessages.GetFoldersListResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.GetNoteByIdResponse.prototype.setTitle = function(value) {
-  jspb.Message.setProto3StringField(this, 5, value);
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.GetFoldersListResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
 };
 
 
-/**
- * optional string text = 6;
- * @return {string}
- */
-proto.gigamessages.GetNoteByIdResponse.prototype.getText = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 6, ""));
+proto.gigamessages.GetFoldersListResponse.prototype.clearError = function() {
+  this.setError(undefined);
 };
 
 
-/** @param {string} value */
-proto.gigamessages.GetNoteByIdResponse.prototype.setText = function(value) {
-  jspb.Message.setProto3StringField(this, 6, value);
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.GetFoldersListResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
 };
 
 
@@ -3420,12 +3734,12 @@ proto.gigamessages.GetNoteByIdResponse.prototype.setText = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetFolderById = function(opt_data) {
+proto.gigamessages.GetNoteById = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.GetFolderById, jspb.Message);
+goog.inherits(proto.gigamessages.GetNoteById, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetFolderById.displayName = 'proto.gigamessages.GetFolderById';
+  proto.gigamessages.GetNoteById.displayName = 'proto.gigamessages.GetNoteById';
 }
 
 
@@ -3440,8 +3754,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetFolderById.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetFolderById.toObject(opt_includeInstance, this);
+proto.gigamessages.GetNoteById.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetNoteById.toObject(opt_includeInstance, this);
 };
 
 
@@ -3450,13 +3764,13 @@ proto.gigamessages.GetFolderById.prototype.toObject = function(opt_includeInstan
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetFolderById} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetNoteById} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetFolderById.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetNoteById.toObject = function(includeInstance, msg) {
   var f, obj = {
-    folderid: jspb.Message.getFieldWithDefault(msg, 1, "")
+    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
   };
 
   if (includeInstance) {
@@ -3470,23 +3784,23 @@ proto.gigamessages.GetFolderById.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetFolderById}
+ * @return {!proto.gigamessages.GetNoteById}
  */
-proto.gigamessages.GetFolderById.deserializeBinary = function(bytes) {
+proto.gigamessages.GetNoteById.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetFolderById;
-  return proto.gigamessages.GetFolderById.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetNoteById;
+  return proto.gigamessages.GetNoteById.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetFolderById} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetNoteById} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetFolderById}
+ * @return {!proto.gigamessages.GetNoteById}
  */
-proto.gigamessages.GetFolderById.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetNoteById.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -3495,7 +3809,7 @@ proto.gigamessages.GetFolderById.deserializeBinaryFromReader = function(msg, rea
     switch (field) {
     case 1:
       var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
+      msg.setNoteid(value);
       break;
     default:
       reader.skipField();
@@ -3510,9 +3824,9 @@ proto.gigamessages.GetFolderById.deserializeBinaryFromReader = function(msg, rea
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetFolderById.prototype.serializeBinary = function() {
+proto.gigamessages.GetNoteById.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetFolderById.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.GetNoteById.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -3520,13 +3834,13 @@ proto.gigamessages.GetFolderById.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetFolderById} message
+ * @param {!proto.gigamessages.GetNoteById} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetFolderById.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.GetNoteById.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getFolderid();
+  f = message.getNoteid();
   if (f.length > 0) {
     writer.writeString(
       1,
@@ -3537,16 +3851,16 @@ proto.gigamessages.GetFolderById.serializeBinaryToWriter = function(message, wri
 
 
 /**
- * optional string folderId = 1;
+ * optional string noteId = 1;
  * @return {string}
  */
-proto.gigamessages.GetFolderById.prototype.getFolderid = function() {
+proto.gigamessages.GetNoteById.prototype.getNoteid = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.GetFolderById.prototype.setFolderid = function(value) {
+proto.gigamessages.GetNoteById.prototype.setNoteid = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
@@ -3562,12 +3876,12 @@ proto.gigamessages.GetFolderById.prototype.setFolderid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetFolderByIdResponse = function(opt_data) {
+proto.gigamessages.GetNoteByIdResponse = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.GetFolderByIdResponse, jspb.Message);
+goog.inherits(proto.gigamessages.GetNoteByIdResponse, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetFolderByIdResponse.displayName = 'proto.gigamessages.GetFolderByIdResponse';
+  proto.gigamessages.GetNoteByIdResponse.displayName = 'proto.gigamessages.GetNoteByIdResponse';
 }
 
 
@@ -3582,8 +3896,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetFolderByIdResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.GetNoteByIdResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetNoteByIdResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -3592,20 +3906,19 @@ proto.gigamessages.GetFolderByIdResponse.prototype.toObject = function(opt_inclu
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetFolderByIdResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetNoteByIdResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetFolderByIdResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetNoteByIdResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
     success: jspb.Message.getFieldWithDefault(msg, 1, false),
     errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
     id: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    title: jspb.Message.getFieldWithDefault(msg, 4, ""),
-    parentid: jspb.Message.getFieldWithDefault(msg, 5, ""),
-    level: jspb.Message.getFieldWithDefault(msg, 6, 0),
-    createdat: jspb.Message.getFieldWithDefault(msg, 7, 0),
-    updatedat: jspb.Message.getFieldWithDefault(msg, 8, 0)
+    folderid: jspb.Message.getFieldWithDefault(msg, 4, ""),
+    title: jspb.Message.getFieldWithDefault(msg, 5, ""),
+    text: jspb.Message.getFieldWithDefault(msg, 6, ""),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -3619,23 +3932,23 @@ proto.gigamessages.GetFolderByIdResponse.toObject = function(includeInstance, ms
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetFolderByIdResponse}
+ * @return {!proto.gigamessages.GetNoteByIdResponse}
  */
-proto.gigamessages.GetFolderByIdResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.GetNoteByIdResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetFolderByIdResponse;
-  return proto.gigamessages.GetFolderByIdResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetNoteByIdResponse;
+  return proto.gigamessages.GetNoteByIdResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetFolderByIdResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetNoteByIdResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetFolderByIdResponse}
+ * @return {!proto.gigamessages.GetNoteByIdResponse}
  */
-proto.gigamessages.GetFolderByIdResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetNoteByIdResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -3656,23 +3969,20 @@ proto.gigamessages.GetFolderByIdResponse.deserializeBinaryFromReader = function(
       break;
     case 4:
       var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
+      msg.setFolderid(value);
       break;
     case 5:
       var value = /** @type {string} */ (reader.readString());
-      msg.setParentid(value);
+      msg.setTitle(value);
       break;
     case 6:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setLevel(value);
-      break;
-    case 7:
-      var value = /** @type {number} */ (reader.readInt64());
-      msg.setCreatedat(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setText(value);
       break;
-    case 8:
-      var value = /** @type {number} */ (reader.readInt64());
-      msg.setUpdatedat(value);
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
       break;
     default:
       reader.skipField();
@@ -3687,9 +3997,9 @@ proto.gigamessages.GetFolderByIdResponse.deserializeBinaryFromReader = function(
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.serializeBinary = function() {
+proto.gigamessages.GetNoteByIdResponse.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetFolderByIdResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.GetNoteByIdResponse.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -3697,11 +4007,11 @@ proto.gigamessages.GetFolderByIdResponse.prototype.serializeBinary = function()
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetFolderByIdResponse} message
+ * @param {!proto.gigamessages.GetNoteByIdResponse} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetFolderByIdResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.GetNoteByIdResponse.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
   f = message.getSuccess();
   if (f) {
@@ -3724,39 +4034,33 @@ proto.gigamessages.GetFolderByIdResponse.serializeBinaryToWriter = function(mess
       f
     );
   }
-  f = message.getTitle();
+  f = message.getFolderid();
   if (f.length > 0) {
     writer.writeString(
       4,
       f
     );
   }
-  f = message.getParentid();
+  f = message.getTitle();
   if (f.length > 0) {
     writer.writeString(
       5,
       f
     );
   }
-  f = message.getLevel();
-  if (f !== 0) {
-    writer.writeInt32(
+  f = message.getText();
+  if (f.length > 0) {
+    writer.writeString(
       6,
       f
     );
   }
-  f = message.getCreatedat();
-  if (f !== 0) {
-    writer.writeInt64(
-      7,
-      f
-    );
-  }
-  f = message.getUpdatedat();
-  if (f !== 0) {
-    writer.writeInt64(
-      8,
-      f
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
     );
   }
 };
@@ -3768,13 +4072,13 @@ proto.gigamessages.GetFolderByIdResponse.serializeBinaryToWriter = function(mess
  * You should avoid comparisons like {@code val === true/false} in those cases.
  * @return {boolean}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.getSuccess = function() {
+proto.gigamessages.GetNoteByIdResponse.prototype.getSuccess = function() {
   return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
 };
 
 
 /** @param {boolean} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setSuccess = function(value) {
+proto.gigamessages.GetNoteByIdResponse.prototype.setSuccess = function(value) {
   jspb.Message.setProto3BooleanField(this, 1, value);
 };
 
@@ -3783,13 +4087,13 @@ proto.gigamessages.GetFolderByIdResponse.prototype.setSuccess = function(value)
  * optional int32 errorCode = 2;
  * @return {number}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.getErrorcode = function() {
+proto.gigamessages.GetNoteByIdResponse.prototype.getErrorcode = function() {
   return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
 };
 
 
 /** @param {number} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setErrorcode = function(value) {
+proto.gigamessages.GetNoteByIdResponse.prototype.setErrorcode = function(value) {
   jspb.Message.setProto3IntField(this, 2, value);
 };
 
@@ -3798,89 +4102,89 @@ proto.gigamessages.GetFolderByIdResponse.prototype.setErrorcode = function(value
  * optional string id = 3;
  * @return {string}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.getId = function() {
+proto.gigamessages.GetNoteByIdResponse.prototype.getId = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setId = function(value) {
+proto.gigamessages.GetNoteByIdResponse.prototype.setId = function(value) {
   jspb.Message.setProto3StringField(this, 3, value);
 };
 
 
 /**
- * optional string title = 4;
+ * optional string folderId = 4;
  * @return {string}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.getTitle = function() {
+proto.gigamessages.GetNoteByIdResponse.prototype.getFolderid = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setTitle = function(value) {
+proto.gigamessages.GetNoteByIdResponse.prototype.setFolderid = function(value) {
   jspb.Message.setProto3StringField(this, 4, value);
 };
 
 
 /**
- * optional string parentId = 5;
+ * optional string title = 5;
  * @return {string}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.getParentid = function() {
+proto.gigamessages.GetNoteByIdResponse.prototype.getTitle = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setParentid = function(value) {
+proto.gigamessages.GetNoteByIdResponse.prototype.setTitle = function(value) {
   jspb.Message.setProto3StringField(this, 5, value);
 };
 
 
 /**
- * optional int32 level = 6;
- * @return {number}
+ * optional string text = 6;
+ * @return {string}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.getLevel = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
+proto.gigamessages.GetNoteByIdResponse.prototype.getText = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 6, ""));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setLevel = function(value) {
-  jspb.Message.setProto3IntField(this, 6, value);
+/** @param {string} value */
+proto.gigamessages.GetNoteByIdResponse.prototype.setText = function(value) {
+  jspb.Message.setProto3StringField(this, 6, value);
 };
 
 
 /**
- * optional int64 createdAt = 7;
- * @return {number}
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
  */
-proto.gigamessages.GetFolderByIdResponse.prototype.getCreatedat = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 7, 0));
+proto.gigamessages.GetNoteByIdResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setCreatedat = function(value) {
-  jspb.Message.setProto3IntField(this, 7, value);
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.GetNoteByIdResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
 };
 
 
-/**
- * optional int64 updatedAt = 8;
- * @return {number}
- */
-proto.gigamessages.GetFolderByIdResponse.prototype.getUpdatedat = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 8, 0));
+proto.gigamessages.GetNoteByIdResponse.prototype.clearError = function() {
+  this.setError(undefined);
 };
 
 
-/** @param {number} value */
-proto.gigamessages.GetFolderByIdResponse.prototype.setUpdatedat = function(value) {
-  jspb.Message.setProto3IntField(this, 8, value);
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.GetNoteByIdResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
 };
 
 
@@ -3895,12 +4199,12 @@ proto.gigamessages.GetFolderByIdResponse.prototype.setUpdatedat = function(value
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.RemoveNote = function(opt_data) {
+proto.gigamessages.GetFolderById = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.RemoveNote, jspb.Message);
+goog.inherits(proto.gigamessages.GetFolderById, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.RemoveNote.displayName = 'proto.gigamessages.RemoveNote';
+  proto.gigamessages.GetFolderById.displayName = 'proto.gigamessages.GetFolderById';
 }
 
 
@@ -3915,8 +4219,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.RemoveNote.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.RemoveNote.toObject(opt_includeInstance, this);
+proto.gigamessages.GetFolderById.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetFolderById.toObject(opt_includeInstance, this);
 };
 
 
@@ -3925,13 +4229,13 @@ proto.gigamessages.RemoveNote.prototype.toObject = function(opt_includeInstance)
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.RemoveNote} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetFolderById} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.RemoveNote.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetFolderById.toObject = function(includeInstance, msg) {
   var f, obj = {
-    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
+    folderid: jspb.Message.getFieldWithDefault(msg, 1, "")
   };
 
   if (includeInstance) {
@@ -3945,23 +4249,23 @@ proto.gigamessages.RemoveNote.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.RemoveNote}
+ * @return {!proto.gigamessages.GetFolderById}
  */
-proto.gigamessages.RemoveNote.deserializeBinary = function(bytes) {
+proto.gigamessages.GetFolderById.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.RemoveNote;
-  return proto.gigamessages.RemoveNote.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetFolderById;
+  return proto.gigamessages.GetFolderById.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.RemoveNote} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetFolderById} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.RemoveNote}
+ * @return {!proto.gigamessages.GetFolderById}
  */
-proto.gigamessages.RemoveNote.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetFolderById.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -3970,7 +4274,7 @@ proto.gigamessages.RemoveNote.deserializeBinaryFromReader = function(msg, reader
     switch (field) {
     case 1:
       var value = /** @type {string} */ (reader.readString());
-      msg.setNoteid(value);
+      msg.setFolderid(value);
       break;
     default:
       reader.skipField();
@@ -3985,9 +4289,9 @@ proto.gigamessages.RemoveNote.deserializeBinaryFromReader = function(msg, reader
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.RemoveNote.prototype.serializeBinary = function() {
+proto.gigamessages.GetFolderById.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.RemoveNote.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.GetFolderById.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -3995,13 +4299,13 @@ proto.gigamessages.RemoveNote.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.RemoveNote} message
+ * @param {!proto.gigamessages.GetFolderById} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.RemoveNote.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.GetFolderById.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getNoteid();
+  f = message.getFolderid();
   if (f.length > 0) {
     writer.writeString(
       1,
@@ -4012,16 +4316,16 @@ proto.gigamessages.RemoveNote.serializeBinaryToWriter = function(message, writer
 
 
 /**
- * optional string noteId = 1;
+ * optional string folderId = 1;
  * @return {string}
  */
-proto.gigamessages.RemoveNote.prototype.getNoteid = function() {
+proto.gigamessages.GetFolderById.prototype.getFolderid = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.RemoveNote.prototype.setNoteid = function(value) {
+proto.gigamessages.GetFolderById.prototype.setFolderid = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
@@ -4037,12 +4341,12 @@ proto.gigamessages.RemoveNote.prototype.setNoteid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.RemoveFolder = function(opt_data) {
+proto.gigamessages.GetFolderByIdResponse = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.RemoveFolder, jspb.Message);
+goog.inherits(proto.gigamessages.GetFolderByIdResponse, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.RemoveFolder.displayName = 'proto.gigamessages.RemoveFolder';
+  proto.gigamessages.GetFolderByIdResponse.displayName = 'proto.gigamessages.GetFolderByIdResponse';
 }
 
 
@@ -4057,8 +4361,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.RemoveFolder.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.RemoveFolder.toObject(opt_includeInstance, this);
+proto.gigamessages.GetFolderByIdResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetFolderByIdResponse.toObject(opt_includeInstance, this);
 };
 
 
@@ -4067,13 +4371,21 @@ proto.gigamessages.RemoveFolder.prototype.toObject = function(opt_includeInstanc
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.RemoveFolder} msg The msg instance to transform.
+ * @param {!proto.gigamessages.GetFolderByIdResponse} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.RemoveFolder.toObject = function(includeInstance, msg) {
+proto.gigamessages.GetFolderByIdResponse.toObject = function(includeInstance, msg) {
   var f, obj = {
-    folderid: jspb.Message.getFieldWithDefault(msg, 1, "")
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    id: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    title: jspb.Message.getFieldWithDefault(msg, 4, ""),
+    parentid: jspb.Message.getFieldWithDefault(msg, 5, ""),
+    level: jspb.Message.getFieldWithDefault(msg, 6, 0),
+    createdat: jspb.Message.getFieldWithDefault(msg, 7, 0),
+    updatedat: jspb.Message.getFieldWithDefault(msg, 8, 0),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -4087,23 +4399,23 @@ proto.gigamessages.RemoveFolder.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.RemoveFolder}
+ * @return {!proto.gigamessages.GetFolderByIdResponse}
  */
-proto.gigamessages.RemoveFolder.deserializeBinary = function(bytes) {
+proto.gigamessages.GetFolderByIdResponse.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.RemoveFolder;
-  return proto.gigamessages.RemoveFolder.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.GetFolderByIdResponse;
+  return proto.gigamessages.GetFolderByIdResponse.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.RemoveFolder} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.GetFolderByIdResponse} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.RemoveFolder}
+ * @return {!proto.gigamessages.GetFolderByIdResponse}
  */
-proto.gigamessages.RemoveFolder.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.GetFolderByIdResponse.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -4111,15 +4423,9387 @@ proto.gigamessages.RemoveFolder.deserializeBinaryFromReader = function(msg, read
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 3:
       var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
+      msg.setId(value);
       break;
-    default:
-      reader.skipField();
+    case 4:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTitle(value);
       break;
-    }
-  }
-  return msg;
+    case 5:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setParentid(value);
+      break;
+    case 6:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setLevel(value);
+      break;
+    case 7:
+      var value = /** @type {number} */ (reader.readInt64());
+      msg.setCreatedat(value);
+      break;
+    case 8:
+      var value = /** @type {number} */ (reader.readInt64());
+      msg.setUpdatedat(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.GetFolderByIdResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.GetFolderByIdResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetFolderByIdResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
+      1,
+      f
+    );
+  }
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+  f = message.getId();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getTitle();
+  if (f.length > 0) {
+    writer.writeString(
+      4,
+      f
+    );
+  }
+  f = message.getParentid();
+  if (f.length > 0) {
+    writer.writeString(
+      5,
+      f
+    );
+  }
+  f = message.getLevel();
+  if (f !== 0) {
+    writer.writeInt32(
+      6,
+      f
+    );
+  }
+  f = message.getCreatedat();
+  if (f !== 0) {
+    writer.writeInt64(
+      7,
+      f
+    );
+  }
+  f = message.getUpdatedat();
+  if (f !== 0) {
+    writer.writeInt64(
+      8,
+      f
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
+};
+
+
+/**
+ * optional int32 errorCode = 2;
+ * @return {number}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional string id = 3;
+ * @return {string}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setId = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional string title = 4;
+ * @return {string}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getTitle = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setTitle = function(value) {
+  jspb.Message.setProto3StringField(this, 4, value);
+};
+
+
+/**
+ * optional string parentId = 5;
+ * @return {string}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getParentid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setParentid = function(value) {
+  jspb.Message.setProto3StringField(this, 5, value);
+};
+
+
+/**
+ * optional int32 level = 6;
+ * @return {number}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getLevel = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setLevel = function(value) {
+  jspb.Message.setProto3IntField(this, 6, value);
+};
+
+
+/**
+ * optional int64 createdAt = 7;
+ * @return {number}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getCreatedat = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 7, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setCreatedat = function(value) {
+  jspb.Message.setProto3IntField(this, 7, value);
+};
+
+
+/**
+ * optional int64 updatedAt = 8;
+ * @return {number}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getUpdatedat = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 8, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setUpdatedat = function(value) {
+  jspb.Message.setProto3IntField(this, 8, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.GetFolderByIdResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.GetFolderByIdResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.GetFolderByIdResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.RemoveNote = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.RemoveNote, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.RemoveNote.displayName = 'proto.gigamessages.RemoveNote';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.RemoveNote.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.RemoveNote.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.RemoveNote} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RemoveNote.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.RemoveNote}
+ */
+proto.gigamessages.RemoveNote.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.RemoveNote;
+  return proto.gigamessages.RemoveNote.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.RemoveNote} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.RemoveNote}
+ */
+proto.gigamessages.RemoveNote.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setNoteid(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.RemoveNote.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.RemoveNote.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.RemoveNote} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RemoveNote.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getNoteid();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string noteId = 1;
+ * @return {string}
+ */
+proto.gigamessages.RemoveNote.prototype.getNoteid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.RemoveNote.prototype.setNoteid = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.RemoveFolder = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.RemoveFolder, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.RemoveFolder.displayName = 'proto.gigamessages.RemoveFolder';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.RemoveFolder.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.RemoveFolder.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.RemoveFolder} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RemoveFolder.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    folderid: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.RemoveFolder}
+ */
+proto.gigamessages.RemoveFolder.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.RemoveFolder;
+  return proto.gigamessages.RemoveFolder.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.RemoveFolder} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.RemoveFolder}
+ */
+proto.gigamessages.RemoveFolder.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setFolderid(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.RemoveFolder.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.RemoveFolder.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.RemoveFolder} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RemoveFolder.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getFolderid();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string folderId = 1;
+ * @return {string}
+ */
+proto.gigamessages.RemoveFolder.prototype.getFolderid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.RemoveFolder.prototype.setFolderid = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.GetRootFolderResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.GetRootFolderResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.GetRootFolderResponse.displayName = 'proto.gigamessages.GetRootFolderResponse';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetRootFolderResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.GetRootFolderResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetRootFolderResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    folderid: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    title: jspb.Message.getFieldWithDefault(msg, 5, ""),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.GetRootFolderResponse}
+ */
+proto.gigamessages.GetRootFolderResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.GetRootFolderResponse;
+  return proto.gigamessages.GetRootFolderResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.GetRootFolderResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.GetRootFolderResponse}
+ */
+proto.gigamessages.GetRootFolderResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setFolderid(value);
+      break;
+    case 5:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTitle(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.GetRootFolderResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.GetRootFolderResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetRootFolderResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
+      1,
+      f
+    );
+  }
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+  f = message.getFolderid();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getTitle();
+  if (f.length > 0) {
+    writer.writeString(
+      5,
+      f
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.GetRootFolderResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
+};
+
+
+/**
+ * optional int32 errorCode = 2;
+ * @return {number}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.GetRootFolderResponse.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional string folderId = 3;
+ * @return {string}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.getFolderid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.GetRootFolderResponse.prototype.setFolderid = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional string title = 5;
+ * @return {string}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.getTitle = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.GetRootFolderResponse.prototype.setTitle = function(value) {
+  jspb.Message.setProto3StringField(this, 5, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.GetRootFolderResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.GetRootFolderResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.GetRootFolderResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.EmptyResultResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.EmptyResultResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.EmptyResultResponse.displayName = 'proto.gigamessages.EmptyResultResponse';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.EmptyResultResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.EmptyResultResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.EmptyResultResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.EmptyResultResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.EmptyResultResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.EmptyResultResponse;
+  return proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.EmptyResultResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.EmptyResultResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.EmptyResultResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
+      1,
+      f
+    );
+  }
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.EmptyResultResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.EmptyResultResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
+};
+
+
+/**
+ * optional int32 errorCode = 2;
+ * @return {number}
+ */
+proto.gigamessages.EmptyResultResponse.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.EmptyResultResponse.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.EmptyResultResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.EmptyResultResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.EmptyResultResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.EmptyResultResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.GetLastLoginDataResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.GetLastLoginDataResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.GetLastLoginDataResponse.displayName = 'proto.gigamessages.GetLastLoginDataResponse';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetLastLoginDataResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.GetLastLoginDataResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetLastLoginDataResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    token: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    userid: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    email: jspb.Message.getFieldWithDefault(msg, 5, ""),
+    istokenvalid: jspb.Message.getFieldWithDefault(msg, 6, false),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.GetLastLoginDataResponse}
+ */
+proto.gigamessages.GetLastLoginDataResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.GetLastLoginDataResponse;
+  return proto.gigamessages.GetLastLoginDataResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.GetLastLoginDataResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.GetLastLoginDataResponse}
+ */
+proto.gigamessages.GetLastLoginDataResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setToken(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setUserid(value);
+      break;
+    case 5:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setEmail(value);
+      break;
+    case 6:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setIstokenvalid(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.GetLastLoginDataResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.GetLastLoginDataResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetLastLoginDataResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
+      1,
+      f
+    );
+  }
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+  f = message.getToken();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getUserid();
+  if (f !== 0) {
+    writer.writeInt32(
+      4,
+      f
+    );
+  }
+  f = message.getEmail();
+  if (f.length > 0) {
+    writer.writeString(
+      5,
+      f
+    );
+  }
+  f = message.getIstokenvalid();
+  if (f) {
+    writer.writeBool(
+      6,
+      f
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.GetLastLoginDataResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
+};
+
+
+/**
+ * optional int32 errorCode = 2;
+ * @return {number}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.GetLastLoginDataResponse.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional string token = 3;
+ * @return {string}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.getToken = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.GetLastLoginDataResponse.prototype.setToken = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional int32 userId = 4;
+ * @return {number}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.getUserid = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.GetLastLoginDataResponse.prototype.setUserid = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional string email = 5;
+ * @return {string}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.getEmail = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.GetLastLoginDataResponse.prototype.setEmail = function(value) {
+  jspb.Message.setProto3StringField(this, 5, value);
+};
+
+
+/**
+ * optional bool isTokenValid = 6;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.getIstokenvalid = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 6, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.GetLastLoginDataResponse.prototype.setIstokenvalid = function(value) {
+  jspb.Message.setProto3BooleanField(this, 6, value);
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.GetLastLoginDataResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.GetLastLoginDataResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.GetLastLoginDataResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.SearchNotes = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.SearchNotes, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.SearchNotes.displayName = 'proto.gigamessages.SearchNotes';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.SearchNotes.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.SearchNotes.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.SearchNotes} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SearchNotes.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    query: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    folderid: jspb.Message.getFieldWithDefault(msg, 2, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.SearchNotes}
+ */
+proto.gigamessages.SearchNotes.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.SearchNotes;
+  return proto.gigamessages.SearchNotes.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.SearchNotes} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.SearchNotes}
+ */
+proto.gigamessages.SearchNotes.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setQuery(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setFolderid(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.SearchNotes.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.SearchNotes.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.SearchNotes} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SearchNotes.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getQuery();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getFolderid();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string query = 1;
+ * @return {string}
+ */
+proto.gigamessages.SearchNotes.prototype.getQuery = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.SearchNotes.prototype.setQuery = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string folderId = 2;
+ * @return {string}
+ */
+proto.gigamessages.SearchNotes.prototype.getFolderid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.SearchNotes.prototype.setFolderid = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.UpdateFolder = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.UpdateFolder, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.UpdateFolder.displayName = 'proto.gigamessages.UpdateFolder';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.UpdateFolder.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.UpdateFolder.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.UpdateFolder} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.UpdateFolder.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    id: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    parentid: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    title: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    level: jspb.Message.getFieldWithDefault(msg, 4, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.UpdateFolder}
+ */
+proto.gigamessages.UpdateFolder.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.UpdateFolder;
+  return proto.gigamessages.UpdateFolder.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.UpdateFolder} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.UpdateFolder}
+ */
+proto.gigamessages.UpdateFolder.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setId(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setParentid(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTitle(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setLevel(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.UpdateFolder.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.UpdateFolder.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.UpdateFolder} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.UpdateFolder.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getParentid();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getTitle();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getLevel();
+  if (f !== 0) {
+    writer.writeInt32(
+      4,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string id = 1;
+ * @return {string}
+ */
+proto.gigamessages.UpdateFolder.prototype.getId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.UpdateFolder.prototype.setId = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string parentId = 2;
+ * @return {string}
+ */
+proto.gigamessages.UpdateFolder.prototype.getParentid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.UpdateFolder.prototype.setParentid = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional string title = 3;
+ * @return {string}
+ */
+proto.gigamessages.UpdateFolder.prototype.getTitle = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.UpdateFolder.prototype.setTitle = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional int32 level = 4;
+ * @return {number}
+ */
+proto.gigamessages.UpdateFolder.prototype.getLevel = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.UpdateFolder.prototype.setLevel = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.UpdateNote = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.UpdateNote, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.UpdateNote.displayName = 'proto.gigamessages.UpdateNote';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.UpdateNote.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.UpdateNote.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.UpdateNote} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.UpdateNote.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    id: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    folderid: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    title: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    text: jspb.Message.getFieldWithDefault(msg, 4, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.UpdateNote}
+ */
+proto.gigamessages.UpdateNote.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.UpdateNote;
+  return proto.gigamessages.UpdateNote.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.UpdateNote} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.UpdateNote}
+ */
+proto.gigamessages.UpdateNote.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setId(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setFolderid(value);
+      break;
+    case 3:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTitle(value);
+      break;
+    case 4:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setText(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.UpdateNote.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.UpdateNote.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.UpdateNote} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.UpdateNote.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getId();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getFolderid();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getTitle();
+  if (f.length > 0) {
+    writer.writeString(
+      3,
+      f
+    );
+  }
+  f = message.getText();
+  if (f.length > 0) {
+    writer.writeString(
+      4,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string id = 1;
+ * @return {string}
+ */
+proto.gigamessages.UpdateNote.prototype.getId = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.UpdateNote.prototype.setId = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string folderId = 2;
+ * @return {string}
+ */
+proto.gigamessages.UpdateNote.prototype.getFolderid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.UpdateNote.prototype.setFolderid = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional string title = 3;
+ * @return {string}
+ */
+proto.gigamessages.UpdateNote.prototype.getTitle = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.UpdateNote.prototype.setTitle = function(value) {
+  jspb.Message.setProto3StringField(this, 3, value);
+};
+
+
+/**
+ * optional string text = 4;
+ * @return {string}
+ */
+proto.gigamessages.UpdateNote.prototype.getText = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.UpdateNote.prototype.setText = function(value) {
+  jspb.Message.setProto3StringField(this, 4, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.AddToFavorites = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.AddToFavorites, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.AddToFavorites.displayName = 'proto.gigamessages.AddToFavorites';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.AddToFavorites.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.AddToFavorites.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.AddToFavorites} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.AddToFavorites.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.AddToFavorites}
+ */
+proto.gigamessages.AddToFavorites.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.AddToFavorites;
+  return proto.gigamessages.AddToFavorites.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.AddToFavorites} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.AddToFavorites}
+ */
+proto.gigamessages.AddToFavorites.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setNoteid(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.AddToFavorites.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.AddToFavorites.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.AddToFavorites} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.AddToFavorites.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getNoteid();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string noteId = 1;
+ * @return {string}
+ */
+proto.gigamessages.AddToFavorites.prototype.getNoteid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.AddToFavorites.prototype.setNoteid = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.RemoveFromFavorites = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.RemoveFromFavorites, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.RemoveFromFavorites.displayName = 'proto.gigamessages.RemoveFromFavorites';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.RemoveFromFavorites.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.RemoveFromFavorites.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.RemoveFromFavorites} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RemoveFromFavorites.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.RemoveFromFavorites}
+ */
+proto.gigamessages.RemoveFromFavorites.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.RemoveFromFavorites;
+  return proto.gigamessages.RemoveFromFavorites.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.RemoveFromFavorites} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.RemoveFromFavorites}
+ */
+proto.gigamessages.RemoveFromFavorites.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setNoteid(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.RemoveFromFavorites.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.RemoveFromFavorites.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.RemoveFromFavorites} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RemoveFromFavorites.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getNoteid();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string noteId = 1;
+ * @return {string}
+ */
+proto.gigamessages.RemoveFromFavorites.prototype.getNoteid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.RemoveFromFavorites.prototype.setNoteid = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.Synchronize = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.Synchronize, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.Synchronize.displayName = 'proto.gigamessages.Synchronize';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.Synchronize.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Synchronize.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.Synchronize} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Synchronize.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.Synchronize}
+ */
+proto.gigamessages.Synchronize.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.Synchronize;
+  return proto.gigamessages.Synchronize.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.Synchronize} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.Synchronize}
+ */
+proto.gigamessages.Synchronize.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.Synchronize.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.Synchronize.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.Synchronize} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Synchronize.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.GetLastLoginData = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.GetLastLoginData, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.GetLastLoginData.displayName = 'proto.gigamessages.GetLastLoginData';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.GetLastLoginData.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetLastLoginData.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.GetLastLoginData} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetLastLoginData.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.GetLastLoginData}
+ */
+proto.gigamessages.GetLastLoginData.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.GetLastLoginData;
+  return proto.gigamessages.GetLastLoginData.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.GetLastLoginData} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.GetLastLoginData}
+ */
+proto.gigamessages.GetLastLoginData.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.GetLastLoginData.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.GetLastLoginData.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.GetLastLoginData} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetLastLoginData.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.GetRootFolder = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.GetRootFolder, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.GetRootFolder.displayName = 'proto.gigamessages.GetRootFolder';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.GetRootFolder.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetRootFolder.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.GetRootFolder} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetRootFolder.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.GetRootFolder}
+ */
+proto.gigamessages.GetRootFolder.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.GetRootFolder;
+  return proto.gigamessages.GetRootFolder.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.GetRootFolder} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.GetRootFolder}
+ */
+proto.gigamessages.GetRootFolder.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.GetRootFolder.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.GetRootFolder.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.GetRootFolder} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetRootFolder.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.GetAllFolders = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.GetAllFolders, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.GetAllFolders.displayName = 'proto.gigamessages.GetAllFolders';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.GetAllFolders.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetAllFolders.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.GetAllFolders} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetAllFolders.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.GetAllFolders}
+ */
+proto.gigamessages.GetAllFolders.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.GetAllFolders;
+  return proto.gigamessages.GetAllFolders.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.GetAllFolders} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.GetAllFolders}
+ */
+proto.gigamessages.GetAllFolders.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.GetAllFolders.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.GetAllFolders.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.GetAllFolders} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetAllFolders.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.GetFavorites = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.GetFavorites, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.GetFavorites.displayName = 'proto.gigamessages.GetFavorites';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.GetFavorites.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.GetFavorites.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.GetFavorites} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetFavorites.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.GetFavorites}
+ */
+proto.gigamessages.GetFavorites.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.GetFavorites;
+  return proto.gigamessages.GetFavorites.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.GetFavorites} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.GetFavorites}
+ */
+proto.gigamessages.GetFavorites.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.GetFavorites.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.GetFavorites.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.GetFavorites} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.GetFavorites.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.Logout = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.Logout, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.Logout.displayName = 'proto.gigamessages.Logout';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.Logout.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Logout.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.Logout} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Logout.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.Logout}
+ */
+proto.gigamessages.Logout.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.Logout;
+  return proto.gigamessages.Logout.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.Logout} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.Logout}
+ */
+proto.gigamessages.Logout.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.Logout.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.Logout.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.Logout} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Logout.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.Request = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.gigamessages.Request.oneofGroups_);
+};
+goog.inherits(proto.gigamessages.Request, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.Request.displayName = 'proto.gigamessages.Request';
+}
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value is retained.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.gigamessages.Request.oneofGroups_ = [[2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24]];
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.Request.CommandCase = {
+  COMMAND_NOT_SET: 0,
+  INIT_DATA: 2,
+  CREATE_NOTE: 3,
+  GET_NOTES_BY_FOLDER: 4,
+  GET_NOTE_BY_ID: 5,
+  GET_FOLDER_BY_ID: 6,
+  SYNCHRONIZE: 7,
+  LOGIN: 8,
+  GET_LAST_LOGIN_DATA: 9,
+  GET_ROOT_FOLDER: 10,
+  GET_ALL_FOLDERS: 11,
+  GET_ALL_NOTES: 12,
+  CREATE_FOLDER: 13,
+  UPDATE_NOTE: 14,
+  UPDATE_FOLDER: 15,
+  REMOVE_NOTE: 16,
+  REMOVE_FOLDER: 17,
+  SEARCH_NOTES: 18,
+  REGISTER: 19,
+  ADD_TO_FAVORITES: 20,
+  REMOVE_FROM_FAVORITES: 21,
+  GET_FAVORITES: 22,
+  LOGOUT: 23,
+  LOGIN_SOCIAL: 24
+};
+
+/**
+ * @return {proto.gigamessages.Request.CommandCase}
+ */
+proto.gigamessages.Request.prototype.getCommandCase = function() {
+  return /** @type {proto.gigamessages.Request.CommandCase} */(jspb.Message.computeOneofCase(this, proto.gigamessages.Request.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.Request.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Request.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.Request} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Request.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    requestid: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    initdata: (f = msg.getInitdata()) && proto.gigamessages.InitData.toObject(includeInstance, f),
+    createnote: (f = msg.getCreatenote()) && proto.gigamessages.CreateNote.toObject(includeInstance, f),
+    getnotesbyfolder: (f = msg.getGetnotesbyfolder()) && proto.gigamessages.GetNotesList.toObject(includeInstance, f),
+    getnotebyid: (f = msg.getGetnotebyid()) && proto.gigamessages.GetNoteById.toObject(includeInstance, f),
+    getfolderbyid: (f = msg.getGetfolderbyid()) && proto.gigamessages.GetFolderById.toObject(includeInstance, f),
+    synchronize: (f = msg.getSynchronize()) && proto.gigamessages.Synchronize.toObject(includeInstance, f),
+    login: (f = msg.getLogin()) && proto.gigamessages.Login.toObject(includeInstance, f),
+    getlastlogindata: (f = msg.getGetlastlogindata()) && proto.gigamessages.GetLastLoginData.toObject(includeInstance, f),
+    getrootfolder: (f = msg.getGetrootfolder()) && proto.gigamessages.GetRootFolder.toObject(includeInstance, f),
+    getallfolders: (f = msg.getGetallfolders()) && proto.gigamessages.GetAllFolders.toObject(includeInstance, f),
+    getallnotes: (f = msg.getGetallnotes()) && proto.gigamessages.GetAllNotes.toObject(includeInstance, f),
+    createfolder: (f = msg.getCreatefolder()) && proto.gigamessages.CreateFolder.toObject(includeInstance, f),
+    updatenote: (f = msg.getUpdatenote()) && proto.gigamessages.UpdateNote.toObject(includeInstance, f),
+    updatefolder: (f = msg.getUpdatefolder()) && proto.gigamessages.UpdateFolder.toObject(includeInstance, f),
+    removenote: (f = msg.getRemovenote()) && proto.gigamessages.RemoveNote.toObject(includeInstance, f),
+    removefolder: (f = msg.getRemovefolder()) && proto.gigamessages.RemoveFolder.toObject(includeInstance, f),
+    searchnotes: (f = msg.getSearchnotes()) && proto.gigamessages.SearchNotes.toObject(includeInstance, f),
+    register: (f = msg.getRegister()) && proto.gigamessages.Login.toObject(includeInstance, f),
+    addtofavorites: (f = msg.getAddtofavorites()) && proto.gigamessages.AddToFavorites.toObject(includeInstance, f),
+    removefromfavorites: (f = msg.getRemovefromfavorites()) && proto.gigamessages.RemoveFromFavorites.toObject(includeInstance, f),
+    getfavorites: (f = msg.getGetfavorites()) && proto.gigamessages.GetFavorites.toObject(includeInstance, f),
+    logout: (f = msg.getLogout()) && proto.gigamessages.Logout.toObject(includeInstance, f),
+    loginsocial: (f = msg.getLoginsocial()) && proto.gigamessages.LoginSocial.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.Request}
+ */
+proto.gigamessages.Request.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.Request;
+  return proto.gigamessages.Request.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.Request} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.Request}
+ */
+proto.gigamessages.Request.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setRequestid(value);
+      break;
+    case 2:
+      var value = new proto.gigamessages.InitData;
+      reader.readMessage(value,proto.gigamessages.InitData.deserializeBinaryFromReader);
+      msg.setInitdata(value);
+      break;
+    case 3:
+      var value = new proto.gigamessages.CreateNote;
+      reader.readMessage(value,proto.gigamessages.CreateNote.deserializeBinaryFromReader);
+      msg.setCreatenote(value);
+      break;
+    case 4:
+      var value = new proto.gigamessages.GetNotesList;
+      reader.readMessage(value,proto.gigamessages.GetNotesList.deserializeBinaryFromReader);
+      msg.setGetnotesbyfolder(value);
+      break;
+    case 5:
+      var value = new proto.gigamessages.GetNoteById;
+      reader.readMessage(value,proto.gigamessages.GetNoteById.deserializeBinaryFromReader);
+      msg.setGetnotebyid(value);
+      break;
+    case 6:
+      var value = new proto.gigamessages.GetFolderById;
+      reader.readMessage(value,proto.gigamessages.GetFolderById.deserializeBinaryFromReader);
+      msg.setGetfolderbyid(value);
+      break;
+    case 7:
+      var value = new proto.gigamessages.Synchronize;
+      reader.readMessage(value,proto.gigamessages.Synchronize.deserializeBinaryFromReader);
+      msg.setSynchronize(value);
+      break;
+    case 8:
+      var value = new proto.gigamessages.Login;
+      reader.readMessage(value,proto.gigamessages.Login.deserializeBinaryFromReader);
+      msg.setLogin(value);
+      break;
+    case 9:
+      var value = new proto.gigamessages.GetLastLoginData;
+      reader.readMessage(value,proto.gigamessages.GetLastLoginData.deserializeBinaryFromReader);
+      msg.setGetlastlogindata(value);
+      break;
+    case 10:
+      var value = new proto.gigamessages.GetRootFolder;
+      reader.readMessage(value,proto.gigamessages.GetRootFolder.deserializeBinaryFromReader);
+      msg.setGetrootfolder(value);
+      break;
+    case 11:
+      var value = new proto.gigamessages.GetAllFolders;
+      reader.readMessage(value,proto.gigamessages.GetAllFolders.deserializeBinaryFromReader);
+      msg.setGetallfolders(value);
+      break;
+    case 12:
+      var value = new proto.gigamessages.GetAllNotes;
+      reader.readMessage(value,proto.gigamessages.GetAllNotes.deserializeBinaryFromReader);
+      msg.setGetallnotes(value);
+      break;
+    case 13:
+      var value = new proto.gigamessages.CreateFolder;
+      reader.readMessage(value,proto.gigamessages.CreateFolder.deserializeBinaryFromReader);
+      msg.setCreatefolder(value);
+      break;
+    case 14:
+      var value = new proto.gigamessages.UpdateNote;
+      reader.readMessage(value,proto.gigamessages.UpdateNote.deserializeBinaryFromReader);
+      msg.setUpdatenote(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.UpdateFolder;
+      reader.readMessage(value,proto.gigamessages.UpdateFolder.deserializeBinaryFromReader);
+      msg.setUpdatefolder(value);
+      break;
+    case 16:
+      var value = new proto.gigamessages.RemoveNote;
+      reader.readMessage(value,proto.gigamessages.RemoveNote.deserializeBinaryFromReader);
+      msg.setRemovenote(value);
+      break;
+    case 17:
+      var value = new proto.gigamessages.RemoveFolder;
+      reader.readMessage(value,proto.gigamessages.RemoveFolder.deserializeBinaryFromReader);
+      msg.setRemovefolder(value);
+      break;
+    case 18:
+      var value = new proto.gigamessages.SearchNotes;
+      reader.readMessage(value,proto.gigamessages.SearchNotes.deserializeBinaryFromReader);
+      msg.setSearchnotes(value);
+      break;
+    case 19:
+      var value = new proto.gigamessages.Login;
+      reader.readMessage(value,proto.gigamessages.Login.deserializeBinaryFromReader);
+      msg.setRegister(value);
+      break;
+    case 20:
+      var value = new proto.gigamessages.AddToFavorites;
+      reader.readMessage(value,proto.gigamessages.AddToFavorites.deserializeBinaryFromReader);
+      msg.setAddtofavorites(value);
+      break;
+    case 21:
+      var value = new proto.gigamessages.RemoveFromFavorites;
+      reader.readMessage(value,proto.gigamessages.RemoveFromFavorites.deserializeBinaryFromReader);
+      msg.setRemovefromfavorites(value);
+      break;
+    case 22:
+      var value = new proto.gigamessages.GetFavorites;
+      reader.readMessage(value,proto.gigamessages.GetFavorites.deserializeBinaryFromReader);
+      msg.setGetfavorites(value);
+      break;
+    case 23:
+      var value = new proto.gigamessages.Logout;
+      reader.readMessage(value,proto.gigamessages.Logout.deserializeBinaryFromReader);
+      msg.setLogout(value);
+      break;
+    case 24:
+      var value = new proto.gigamessages.LoginSocial;
+      reader.readMessage(value,proto.gigamessages.LoginSocial.deserializeBinaryFromReader);
+      msg.setLoginsocial(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.Request.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.Request.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.Request} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Request.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getRequestid();
+  if (f !== 0) {
+    writer.writeUint64(
+      1,
+      f
+    );
+  }
+  f = message.getInitdata();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.gigamessages.InitData.serializeBinaryToWriter
+    );
+  }
+  f = message.getCreatenote();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.gigamessages.CreateNote.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetnotesbyfolder();
+  if (f != null) {
+    writer.writeMessage(
+      4,
+      f,
+      proto.gigamessages.GetNotesList.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetnotebyid();
+  if (f != null) {
+    writer.writeMessage(
+      5,
+      f,
+      proto.gigamessages.GetNoteById.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetfolderbyid();
+  if (f != null) {
+    writer.writeMessage(
+      6,
+      f,
+      proto.gigamessages.GetFolderById.serializeBinaryToWriter
+    );
+  }
+  f = message.getSynchronize();
+  if (f != null) {
+    writer.writeMessage(
+      7,
+      f,
+      proto.gigamessages.Synchronize.serializeBinaryToWriter
+    );
+  }
+  f = message.getLogin();
+  if (f != null) {
+    writer.writeMessage(
+      8,
+      f,
+      proto.gigamessages.Login.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetlastlogindata();
+  if (f != null) {
+    writer.writeMessage(
+      9,
+      f,
+      proto.gigamessages.GetLastLoginData.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetrootfolder();
+  if (f != null) {
+    writer.writeMessage(
+      10,
+      f,
+      proto.gigamessages.GetRootFolder.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetallfolders();
+  if (f != null) {
+    writer.writeMessage(
+      11,
+      f,
+      proto.gigamessages.GetAllFolders.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetallnotes();
+  if (f != null) {
+    writer.writeMessage(
+      12,
+      f,
+      proto.gigamessages.GetAllNotes.serializeBinaryToWriter
+    );
+  }
+  f = message.getCreatefolder();
+  if (f != null) {
+    writer.writeMessage(
+      13,
+      f,
+      proto.gigamessages.CreateFolder.serializeBinaryToWriter
+    );
+  }
+  f = message.getUpdatenote();
+  if (f != null) {
+    writer.writeMessage(
+      14,
+      f,
+      proto.gigamessages.UpdateNote.serializeBinaryToWriter
+    );
+  }
+  f = message.getUpdatefolder();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.UpdateFolder.serializeBinaryToWriter
+    );
+  }
+  f = message.getRemovenote();
+  if (f != null) {
+    writer.writeMessage(
+      16,
+      f,
+      proto.gigamessages.RemoveNote.serializeBinaryToWriter
+    );
+  }
+  f = message.getRemovefolder();
+  if (f != null) {
+    writer.writeMessage(
+      17,
+      f,
+      proto.gigamessages.RemoveFolder.serializeBinaryToWriter
+    );
+  }
+  f = message.getSearchnotes();
+  if (f != null) {
+    writer.writeMessage(
+      18,
+      f,
+      proto.gigamessages.SearchNotes.serializeBinaryToWriter
+    );
+  }
+  f = message.getRegister();
+  if (f != null) {
+    writer.writeMessage(
+      19,
+      f,
+      proto.gigamessages.Login.serializeBinaryToWriter
+    );
+  }
+  f = message.getAddtofavorites();
+  if (f != null) {
+    writer.writeMessage(
+      20,
+      f,
+      proto.gigamessages.AddToFavorites.serializeBinaryToWriter
+    );
+  }
+  f = message.getRemovefromfavorites();
+  if (f != null) {
+    writer.writeMessage(
+      21,
+      f,
+      proto.gigamessages.RemoveFromFavorites.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetfavorites();
+  if (f != null) {
+    writer.writeMessage(
+      22,
+      f,
+      proto.gigamessages.GetFavorites.serializeBinaryToWriter
+    );
+  }
+  f = message.getLogout();
+  if (f != null) {
+    writer.writeMessage(
+      23,
+      f,
+      proto.gigamessages.Logout.serializeBinaryToWriter
+    );
+  }
+  f = message.getLoginsocial();
+  if (f != null) {
+    writer.writeMessage(
+      24,
+      f,
+      proto.gigamessages.LoginSocial.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional uint64 requestId = 1;
+ * @return {number}
+ */
+proto.gigamessages.Request.prototype.getRequestid = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Request.prototype.setRequestid = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+/**
+ * optional InitData initData = 2;
+ * @return {?proto.gigamessages.InitData}
+ */
+proto.gigamessages.Request.prototype.getInitdata = function() {
+  return /** @type{?proto.gigamessages.InitData} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.InitData, 2));
+};
+
+
+/** @param {?proto.gigamessages.InitData|undefined} value */
+proto.gigamessages.Request.prototype.setInitdata = function(value) {
+  jspb.Message.setOneofWrapperField(this, 2, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearInitdata = function() {
+  this.setInitdata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasInitdata = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional CreateNote createNote = 3;
+ * @return {?proto.gigamessages.CreateNote}
+ */
+proto.gigamessages.Request.prototype.getCreatenote = function() {
+  return /** @type{?proto.gigamessages.CreateNote} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.CreateNote, 3));
+};
+
+
+/** @param {?proto.gigamessages.CreateNote|undefined} value */
+proto.gigamessages.Request.prototype.setCreatenote = function(value) {
+  jspb.Message.setOneofWrapperField(this, 3, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearCreatenote = function() {
+  this.setCreatenote(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasCreatenote = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+/**
+ * optional GetNotesList getNotesByFolder = 4;
+ * @return {?proto.gigamessages.GetNotesList}
+ */
+proto.gigamessages.Request.prototype.getGetnotesbyfolder = function() {
+  return /** @type{?proto.gigamessages.GetNotesList} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetNotesList, 4));
+};
+
+
+/** @param {?proto.gigamessages.GetNotesList|undefined} value */
+proto.gigamessages.Request.prototype.setGetnotesbyfolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 4, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetnotesbyfolder = function() {
+  this.setGetnotesbyfolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetnotesbyfolder = function() {
+  return jspb.Message.getField(this, 4) != null;
+};
+
+
+/**
+ * optional GetNoteById getNoteById = 5;
+ * @return {?proto.gigamessages.GetNoteById}
+ */
+proto.gigamessages.Request.prototype.getGetnotebyid = function() {
+  return /** @type{?proto.gigamessages.GetNoteById} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetNoteById, 5));
+};
+
+
+/** @param {?proto.gigamessages.GetNoteById|undefined} value */
+proto.gigamessages.Request.prototype.setGetnotebyid = function(value) {
+  jspb.Message.setOneofWrapperField(this, 5, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetnotebyid = function() {
+  this.setGetnotebyid(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetnotebyid = function() {
+  return jspb.Message.getField(this, 5) != null;
+};
+
+
+/**
+ * optional GetFolderById getFolderById = 6;
+ * @return {?proto.gigamessages.GetFolderById}
+ */
+proto.gigamessages.Request.prototype.getGetfolderbyid = function() {
+  return /** @type{?proto.gigamessages.GetFolderById} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetFolderById, 6));
+};
+
+
+/** @param {?proto.gigamessages.GetFolderById|undefined} value */
+proto.gigamessages.Request.prototype.setGetfolderbyid = function(value) {
+  jspb.Message.setOneofWrapperField(this, 6, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetfolderbyid = function() {
+  this.setGetfolderbyid(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetfolderbyid = function() {
+  return jspb.Message.getField(this, 6) != null;
+};
+
+
+/**
+ * optional Synchronize synchronize = 7;
+ * @return {?proto.gigamessages.Synchronize}
+ */
+proto.gigamessages.Request.prototype.getSynchronize = function() {
+  return /** @type{?proto.gigamessages.Synchronize} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Synchronize, 7));
+};
+
+
+/** @param {?proto.gigamessages.Synchronize|undefined} value */
+proto.gigamessages.Request.prototype.setSynchronize = function(value) {
+  jspb.Message.setOneofWrapperField(this, 7, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearSynchronize = function() {
+  this.setSynchronize(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasSynchronize = function() {
+  return jspb.Message.getField(this, 7) != null;
+};
+
+
+/**
+ * optional Login login = 8;
+ * @return {?proto.gigamessages.Login}
+ */
+proto.gigamessages.Request.prototype.getLogin = function() {
+  return /** @type{?proto.gigamessages.Login} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Login, 8));
+};
+
+
+/** @param {?proto.gigamessages.Login|undefined} value */
+proto.gigamessages.Request.prototype.setLogin = function(value) {
+  jspb.Message.setOneofWrapperField(this, 8, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearLogin = function() {
+  this.setLogin(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasLogin = function() {
+  return jspb.Message.getField(this, 8) != null;
+};
+
+
+/**
+ * optional GetLastLoginData getLastLoginData = 9;
+ * @return {?proto.gigamessages.GetLastLoginData}
+ */
+proto.gigamessages.Request.prototype.getGetlastlogindata = function() {
+  return /** @type{?proto.gigamessages.GetLastLoginData} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetLastLoginData, 9));
+};
+
+
+/** @param {?proto.gigamessages.GetLastLoginData|undefined} value */
+proto.gigamessages.Request.prototype.setGetlastlogindata = function(value) {
+  jspb.Message.setOneofWrapperField(this, 9, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetlastlogindata = function() {
+  this.setGetlastlogindata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetlastlogindata = function() {
+  return jspb.Message.getField(this, 9) != null;
+};
+
+
+/**
+ * optional GetRootFolder getRootFolder = 10;
+ * @return {?proto.gigamessages.GetRootFolder}
+ */
+proto.gigamessages.Request.prototype.getGetrootfolder = function() {
+  return /** @type{?proto.gigamessages.GetRootFolder} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetRootFolder, 10));
+};
+
+
+/** @param {?proto.gigamessages.GetRootFolder|undefined} value */
+proto.gigamessages.Request.prototype.setGetrootfolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 10, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetrootfolder = function() {
+  this.setGetrootfolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetrootfolder = function() {
+  return jspb.Message.getField(this, 10) != null;
+};
+
+
+/**
+ * optional GetAllFolders getAllFolders = 11;
+ * @return {?proto.gigamessages.GetAllFolders}
+ */
+proto.gigamessages.Request.prototype.getGetallfolders = function() {
+  return /** @type{?proto.gigamessages.GetAllFolders} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetAllFolders, 11));
+};
+
+
+/** @param {?proto.gigamessages.GetAllFolders|undefined} value */
+proto.gigamessages.Request.prototype.setGetallfolders = function(value) {
+  jspb.Message.setOneofWrapperField(this, 11, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetallfolders = function() {
+  this.setGetallfolders(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetallfolders = function() {
+  return jspb.Message.getField(this, 11) != null;
+};
+
+
+/**
+ * optional GetAllNotes getAllNotes = 12;
+ * @return {?proto.gigamessages.GetAllNotes}
+ */
+proto.gigamessages.Request.prototype.getGetallnotes = function() {
+  return /** @type{?proto.gigamessages.GetAllNotes} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetAllNotes, 12));
+};
+
+
+/** @param {?proto.gigamessages.GetAllNotes|undefined} value */
+proto.gigamessages.Request.prototype.setGetallnotes = function(value) {
+  jspb.Message.setOneofWrapperField(this, 12, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetallnotes = function() {
+  this.setGetallnotes(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetallnotes = function() {
+  return jspb.Message.getField(this, 12) != null;
+};
+
+
+/**
+ * optional CreateFolder createFolder = 13;
+ * @return {?proto.gigamessages.CreateFolder}
+ */
+proto.gigamessages.Request.prototype.getCreatefolder = function() {
+  return /** @type{?proto.gigamessages.CreateFolder} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.CreateFolder, 13));
+};
+
+
+/** @param {?proto.gigamessages.CreateFolder|undefined} value */
+proto.gigamessages.Request.prototype.setCreatefolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 13, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearCreatefolder = function() {
+  this.setCreatefolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasCreatefolder = function() {
+  return jspb.Message.getField(this, 13) != null;
+};
+
+
+/**
+ * optional UpdateNote updateNote = 14;
+ * @return {?proto.gigamessages.UpdateNote}
+ */
+proto.gigamessages.Request.prototype.getUpdatenote = function() {
+  return /** @type{?proto.gigamessages.UpdateNote} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.UpdateNote, 14));
+};
+
+
+/** @param {?proto.gigamessages.UpdateNote|undefined} value */
+proto.gigamessages.Request.prototype.setUpdatenote = function(value) {
+  jspb.Message.setOneofWrapperField(this, 14, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearUpdatenote = function() {
+  this.setUpdatenote(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasUpdatenote = function() {
+  return jspb.Message.getField(this, 14) != null;
+};
+
+
+/**
+ * optional UpdateFolder updateFolder = 15;
+ * @return {?proto.gigamessages.UpdateFolder}
+ */
+proto.gigamessages.Request.prototype.getUpdatefolder = function() {
+  return /** @type{?proto.gigamessages.UpdateFolder} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.UpdateFolder, 15));
+};
+
+
+/** @param {?proto.gigamessages.UpdateFolder|undefined} value */
+proto.gigamessages.Request.prototype.setUpdatefolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 15, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearUpdatefolder = function() {
+  this.setUpdatefolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasUpdatefolder = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
+/**
+ * optional RemoveNote removeNote = 16;
+ * @return {?proto.gigamessages.RemoveNote}
+ */
+proto.gigamessages.Request.prototype.getRemovenote = function() {
+  return /** @type{?proto.gigamessages.RemoveNote} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.RemoveNote, 16));
+};
+
+
+/** @param {?proto.gigamessages.RemoveNote|undefined} value */
+proto.gigamessages.Request.prototype.setRemovenote = function(value) {
+  jspb.Message.setOneofWrapperField(this, 16, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearRemovenote = function() {
+  this.setRemovenote(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasRemovenote = function() {
+  return jspb.Message.getField(this, 16) != null;
+};
+
+
+/**
+ * optional RemoveFolder removeFolder = 17;
+ * @return {?proto.gigamessages.RemoveFolder}
+ */
+proto.gigamessages.Request.prototype.getRemovefolder = function() {
+  return /** @type{?proto.gigamessages.RemoveFolder} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.RemoveFolder, 17));
+};
+
+
+/** @param {?proto.gigamessages.RemoveFolder|undefined} value */
+proto.gigamessages.Request.prototype.setRemovefolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 17, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearRemovefolder = function() {
+  this.setRemovefolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasRemovefolder = function() {
+  return jspb.Message.getField(this, 17) != null;
+};
+
+
+/**
+ * optional SearchNotes searchNotes = 18;
+ * @return {?proto.gigamessages.SearchNotes}
+ */
+proto.gigamessages.Request.prototype.getSearchnotes = function() {
+  return /** @type{?proto.gigamessages.SearchNotes} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.SearchNotes, 18));
+};
+
+
+/** @param {?proto.gigamessages.SearchNotes|undefined} value */
+proto.gigamessages.Request.prototype.setSearchnotes = function(value) {
+  jspb.Message.setOneofWrapperField(this, 18, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearSearchnotes = function() {
+  this.setSearchnotes(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasSearchnotes = function() {
+  return jspb.Message.getField(this, 18) != null;
+};
+
+
+/**
+ * optional Login register = 19;
+ * @return {?proto.gigamessages.Login}
+ */
+proto.gigamessages.Request.prototype.getRegister = function() {
+  return /** @type{?proto.gigamessages.Login} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Login, 19));
+};
+
+
+/** @param {?proto.gigamessages.Login|undefined} value */
+proto.gigamessages.Request.prototype.setRegister = function(value) {
+  jspb.Message.setOneofWrapperField(this, 19, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearRegister = function() {
+  this.setRegister(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasRegister = function() {
+  return jspb.Message.getField(this, 19) != null;
+};
+
+
+/**
+ * optional AddToFavorites addToFavorites = 20;
+ * @return {?proto.gigamessages.AddToFavorites}
+ */
+proto.gigamessages.Request.prototype.getAddtofavorites = function() {
+  return /** @type{?proto.gigamessages.AddToFavorites} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.AddToFavorites, 20));
+};
+
+
+/** @param {?proto.gigamessages.AddToFavorites|undefined} value */
+proto.gigamessages.Request.prototype.setAddtofavorites = function(value) {
+  jspb.Message.setOneofWrapperField(this, 20, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearAddtofavorites = function() {
+  this.setAddtofavorites(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasAddtofavorites = function() {
+  return jspb.Message.getField(this, 20) != null;
+};
+
+
+/**
+ * optional RemoveFromFavorites removeFromFavorites = 21;
+ * @return {?proto.gigamessages.RemoveFromFavorites}
+ */
+proto.gigamessages.Request.prototype.getRemovefromfavorites = function() {
+  return /** @type{?proto.gigamessages.RemoveFromFavorites} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.RemoveFromFavorites, 21));
+};
+
+
+/** @param {?proto.gigamessages.RemoveFromFavorites|undefined} value */
+proto.gigamessages.Request.prototype.setRemovefromfavorites = function(value) {
+  jspb.Message.setOneofWrapperField(this, 21, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearRemovefromfavorites = function() {
+  this.setRemovefromfavorites(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasRemovefromfavorites = function() {
+  return jspb.Message.getField(this, 21) != null;
+};
+
+
+/**
+ * optional GetFavorites getFavorites = 22;
+ * @return {?proto.gigamessages.GetFavorites}
+ */
+proto.gigamessages.Request.prototype.getGetfavorites = function() {
+  return /** @type{?proto.gigamessages.GetFavorites} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetFavorites, 22));
+};
+
+
+/** @param {?proto.gigamessages.GetFavorites|undefined} value */
+proto.gigamessages.Request.prototype.setGetfavorites = function(value) {
+  jspb.Message.setOneofWrapperField(this, 22, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearGetfavorites = function() {
+  this.setGetfavorites(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasGetfavorites = function() {
+  return jspb.Message.getField(this, 22) != null;
+};
+
+
+/**
+ * optional Logout logout = 23;
+ * @return {?proto.gigamessages.Logout}
+ */
+proto.gigamessages.Request.prototype.getLogout = function() {
+  return /** @type{?proto.gigamessages.Logout} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Logout, 23));
+};
+
+
+/** @param {?proto.gigamessages.Logout|undefined} value */
+proto.gigamessages.Request.prototype.setLogout = function(value) {
+  jspb.Message.setOneofWrapperField(this, 23, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearLogout = function() {
+  this.setLogout(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasLogout = function() {
+  return jspb.Message.getField(this, 23) != null;
+};
+
+
+/**
+ * optional LoginSocial loginSocial = 24;
+ * @return {?proto.gigamessages.LoginSocial}
+ */
+proto.gigamessages.Request.prototype.getLoginsocial = function() {
+  return /** @type{?proto.gigamessages.LoginSocial} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.LoginSocial, 24));
+};
+
+
+/** @param {?proto.gigamessages.LoginSocial|undefined} value */
+proto.gigamessages.Request.prototype.setLoginsocial = function(value) {
+  jspb.Message.setOneofWrapperField(this, 24, proto.gigamessages.Request.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Request.prototype.clearLoginsocial = function() {
+  this.setLoginsocial(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Request.prototype.hasLoginsocial = function() {
+  return jspb.Message.getField(this, 24) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.Response = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.gigamessages.Response.oneofGroups_);
+};
+goog.inherits(proto.gigamessages.Response, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.Response.displayName = 'proto.gigamessages.Response';
+}
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value is retained.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.gigamessages.Response.oneofGroups_ = [[2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24]];
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.Response.BodyCase = {
+  BODY_NOT_SET: 0,
+  INIT_DATA: 2,
+  CREATE_NOTE: 3,
+  GET_NOTES_BY_FOLDER: 4,
+  GET_NOTE_BY_ID: 5,
+  GET_FOLDER_BY_ID: 6,
+  SYNCHRONIZE: 7,
+  LOGIN: 8,
+  GET_LAST_LOGIN_DATA: 9,
+  GET_ROOT_FOLDER: 10,
+  GET_ALL_FOLDERS: 11,
+  GET_ALL_NOTES: 12,
+  CREATE_FOLDER: 13,
+  UPDATE_NOTE: 14,
+  UPDATE_FOLDER: 15,
+  REMOVE_NOTE: 16,
+  REMOVE_FOLDER: 17,
+  SEARCH_NOTES: 18,
+  REGISTER: 19,
+  ADD_TO_FAVORITES: 20,
+  REMOVE_FROM_FAVORITES: 21,
+  GET_FAVORITES: 22,
+  LOGOUT: 23,
+  LOGIN_SOCIAL: 24
+};
+
+/**
+ * @return {proto.gigamessages.Response.BodyCase}
+ */
+proto.gigamessages.Response.prototype.getBodyCase = function() {
+  return /** @type {proto.gigamessages.Response.BodyCase} */(jspb.Message.computeOneofCase(this, proto.gigamessages.Response.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.Response.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Response.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.Response} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Response.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    requestid: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    initdata: (f = msg.getInitdata()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    createnote: (f = msg.getCreatenote()) && proto.gigamessages.CreateNoteResponse.toObject(includeInstance, f),
+    getnotesbyfolder: (f = msg.getGetnotesbyfolder()) && proto.gigamessages.GetNotesListResponse.toObject(includeInstance, f),
+    getnotebyid: (f = msg.getGetnotebyid()) && proto.gigamessages.GetNoteByIdResponse.toObject(includeInstance, f),
+    getfolderbyid: (f = msg.getGetfolderbyid()) && proto.gigamessages.GetFolderByIdResponse.toObject(includeInstance, f),
+    synchronize: (f = msg.getSynchronize()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    login: (f = msg.getLogin()) && proto.gigamessages.LoginResponse.toObject(includeInstance, f),
+    getlastlogindata: (f = msg.getGetlastlogindata()) && proto.gigamessages.GetLastLoginDataResponse.toObject(includeInstance, f),
+    getrootfolder: (f = msg.getGetrootfolder()) && proto.gigamessages.GetRootFolderResponse.toObject(includeInstance, f),
+    getallfolders: (f = msg.getGetallfolders()) && proto.gigamessages.GetFoldersListResponse.toObject(includeInstance, f),
+    getallnotes: (f = msg.getGetallnotes()) && proto.gigamessages.GetNotesListResponse.toObject(includeInstance, f),
+    createfolder: (f = msg.getCreatefolder()) && proto.gigamessages.CreateFolderResponse.toObject(includeInstance, f),
+    updatenote: (f = msg.getUpdatenote()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    updatefolder: (f = msg.getUpdatefolder()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    removenote: (f = msg.getRemovenote()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    removefolder: (f = msg.getRemovefolder()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    searchnotes: (f = msg.getSearchnotes()) && proto.gigamessages.GetNotesListResponse.toObject(includeInstance, f),
+    register: (f = msg.getRegister()) && proto.gigamessages.LoginResponse.toObject(includeInstance, f),
+    addtofavorites: (f = msg.getAddtofavorites()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    removefromfavorites: (f = msg.getRemovefromfavorites()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    getfavorites: (f = msg.getGetfavorites()) && proto.gigamessages.GetNotesListResponse.toObject(includeInstance, f),
+    logout: (f = msg.getLogout()) && proto.gigamessages.EmptyResultResponse.toObject(includeInstance, f),
+    loginsocial: (f = msg.getLoginsocial()) && proto.gigamessages.LoginResponse.toObject(includeInstance, f),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.Response}
+ */
+proto.gigamessages.Response.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.Response;
+  return proto.gigamessages.Response.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.Response} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.Response}
+ */
+proto.gigamessages.Response.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setRequestid(value);
+      break;
+    case 2:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setInitdata(value);
+      break;
+    case 3:
+      var value = new proto.gigamessages.CreateNoteResponse;
+      reader.readMessage(value,proto.gigamessages.CreateNoteResponse.deserializeBinaryFromReader);
+      msg.setCreatenote(value);
+      break;
+    case 4:
+      var value = new proto.gigamessages.GetNotesListResponse;
+      reader.readMessage(value,proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader);
+      msg.setGetnotesbyfolder(value);
+      break;
+    case 5:
+      var value = new proto.gigamessages.GetNoteByIdResponse;
+      reader.readMessage(value,proto.gigamessages.GetNoteByIdResponse.deserializeBinaryFromReader);
+      msg.setGetnotebyid(value);
+      break;
+    case 6:
+      var value = new proto.gigamessages.GetFolderByIdResponse;
+      reader.readMessage(value,proto.gigamessages.GetFolderByIdResponse.deserializeBinaryFromReader);
+      msg.setGetfolderbyid(value);
+      break;
+    case 7:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setSynchronize(value);
+      break;
+    case 8:
+      var value = new proto.gigamessages.LoginResponse;
+      reader.readMessage(value,proto.gigamessages.LoginResponse.deserializeBinaryFromReader);
+      msg.setLogin(value);
+      break;
+    case 9:
+      var value = new proto.gigamessages.GetLastLoginDataResponse;
+      reader.readMessage(value,proto.gigamessages.GetLastLoginDataResponse.deserializeBinaryFromReader);
+      msg.setGetlastlogindata(value);
+      break;
+    case 10:
+      var value = new proto.gigamessages.GetRootFolderResponse;
+      reader.readMessage(value,proto.gigamessages.GetRootFolderResponse.deserializeBinaryFromReader);
+      msg.setGetrootfolder(value);
+      break;
+    case 11:
+      var value = new proto.gigamessages.GetFoldersListResponse;
+      reader.readMessage(value,proto.gigamessages.GetFoldersListResponse.deserializeBinaryFromReader);
+      msg.setGetallfolders(value);
+      break;
+    case 12:
+      var value = new proto.gigamessages.GetNotesListResponse;
+      reader.readMessage(value,proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader);
+      msg.setGetallnotes(value);
+      break;
+    case 13:
+      var value = new proto.gigamessages.CreateFolderResponse;
+      reader.readMessage(value,proto.gigamessages.CreateFolderResponse.deserializeBinaryFromReader);
+      msg.setCreatefolder(value);
+      break;
+    case 14:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setUpdatenote(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setUpdatefolder(value);
+      break;
+    case 16:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setRemovenote(value);
+      break;
+    case 17:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setRemovefolder(value);
+      break;
+    case 18:
+      var value = new proto.gigamessages.GetNotesListResponse;
+      reader.readMessage(value,proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader);
+      msg.setSearchnotes(value);
+      break;
+    case 19:
+      var value = new proto.gigamessages.LoginResponse;
+      reader.readMessage(value,proto.gigamessages.LoginResponse.deserializeBinaryFromReader);
+      msg.setRegister(value);
+      break;
+    case 20:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setAddtofavorites(value);
+      break;
+    case 21:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setRemovefromfavorites(value);
+      break;
+    case 22:
+      var value = new proto.gigamessages.GetNotesListResponse;
+      reader.readMessage(value,proto.gigamessages.GetNotesListResponse.deserializeBinaryFromReader);
+      msg.setGetfavorites(value);
+      break;
+    case 23:
+      var value = new proto.gigamessages.EmptyResultResponse;
+      reader.readMessage(value,proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader);
+      msg.setLogout(value);
+      break;
+    case 24:
+      var value = new proto.gigamessages.LoginResponse;
+      reader.readMessage(value,proto.gigamessages.LoginResponse.deserializeBinaryFromReader);
+      msg.setLoginsocial(value);
+      break;
+    case 25:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.Response.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.Response.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.Response} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Response.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getRequestid();
+  if (f !== 0) {
+    writer.writeUint64(
+      1,
+      f
+    );
+  }
+  f = message.getInitdata();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getCreatenote();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.gigamessages.CreateNoteResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetnotesbyfolder();
+  if (f != null) {
+    writer.writeMessage(
+      4,
+      f,
+      proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetnotebyid();
+  if (f != null) {
+    writer.writeMessage(
+      5,
+      f,
+      proto.gigamessages.GetNoteByIdResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetfolderbyid();
+  if (f != null) {
+    writer.writeMessage(
+      6,
+      f,
+      proto.gigamessages.GetFolderByIdResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getSynchronize();
+  if (f != null) {
+    writer.writeMessage(
+      7,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getLogin();
+  if (f != null) {
+    writer.writeMessage(
+      8,
+      f,
+      proto.gigamessages.LoginResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetlastlogindata();
+  if (f != null) {
+    writer.writeMessage(
+      9,
+      f,
+      proto.gigamessages.GetLastLoginDataResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetrootfolder();
+  if (f != null) {
+    writer.writeMessage(
+      10,
+      f,
+      proto.gigamessages.GetRootFolderResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetallfolders();
+  if (f != null) {
+    writer.writeMessage(
+      11,
+      f,
+      proto.gigamessages.GetFoldersListResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetallnotes();
+  if (f != null) {
+    writer.writeMessage(
+      12,
+      f,
+      proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getCreatefolder();
+  if (f != null) {
+    writer.writeMessage(
+      13,
+      f,
+      proto.gigamessages.CreateFolderResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getUpdatenote();
+  if (f != null) {
+    writer.writeMessage(
+      14,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getUpdatefolder();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getRemovenote();
+  if (f != null) {
+    writer.writeMessage(
+      16,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getRemovefolder();
+  if (f != null) {
+    writer.writeMessage(
+      17,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getSearchnotes();
+  if (f != null) {
+    writer.writeMessage(
+      18,
+      f,
+      proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getRegister();
+  if (f != null) {
+    writer.writeMessage(
+      19,
+      f,
+      proto.gigamessages.LoginResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getAddtofavorites();
+  if (f != null) {
+    writer.writeMessage(
+      20,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getRemovefromfavorites();
+  if (f != null) {
+    writer.writeMessage(
+      21,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getGetfavorites();
+  if (f != null) {
+    writer.writeMessage(
+      22,
+      f,
+      proto.gigamessages.GetNotesListResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getLogout();
+  if (f != null) {
+    writer.writeMessage(
+      23,
+      f,
+      proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getLoginsocial();
+  if (f != null) {
+    writer.writeMessage(
+      24,
+      f,
+      proto.gigamessages.LoginResponse.serializeBinaryToWriter
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      25,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional uint64 requestId = 1;
+ * @return {number}
+ */
+proto.gigamessages.Response.prototype.getRequestid = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Response.prototype.setRequestid = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+/**
+ * optional EmptyResultResponse initData = 2;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getInitdata = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 2));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setInitdata = function(value) {
+  jspb.Message.setOneofWrapperField(this, 2, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearInitdata = function() {
+  this.setInitdata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasInitdata = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional CreateNoteResponse createNote = 3;
+ * @return {?proto.gigamessages.CreateNoteResponse}
+ */
+proto.gigamessages.Response.prototype.getCreatenote = function() {
+  return /** @type{?proto.gigamessages.CreateNoteResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.CreateNoteResponse, 3));
+};
+
+
+/** @param {?proto.gigamessages.CreateNoteResponse|undefined} value */
+proto.gigamessages.Response.prototype.setCreatenote = function(value) {
+  jspb.Message.setOneofWrapperField(this, 3, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearCreatenote = function() {
+  this.setCreatenote(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasCreatenote = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+/**
+ * optional GetNotesListResponse getNotesByFolder = 4;
+ * @return {?proto.gigamessages.GetNotesListResponse}
+ */
+proto.gigamessages.Response.prototype.getGetnotesbyfolder = function() {
+  return /** @type{?proto.gigamessages.GetNotesListResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetNotesListResponse, 4));
+};
+
+
+/** @param {?proto.gigamessages.GetNotesListResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetnotesbyfolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 4, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetnotesbyfolder = function() {
+  this.setGetnotesbyfolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetnotesbyfolder = function() {
+  return jspb.Message.getField(this, 4) != null;
+};
+
+
+/**
+ * optional GetNoteByIdResponse getNoteById = 5;
+ * @return {?proto.gigamessages.GetNoteByIdResponse}
+ */
+proto.gigamessages.Response.prototype.getGetnotebyid = function() {
+  return /** @type{?proto.gigamessages.GetNoteByIdResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetNoteByIdResponse, 5));
+};
+
+
+/** @param {?proto.gigamessages.GetNoteByIdResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetnotebyid = function(value) {
+  jspb.Message.setOneofWrapperField(this, 5, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetnotebyid = function() {
+  this.setGetnotebyid(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetnotebyid = function() {
+  return jspb.Message.getField(this, 5) != null;
+};
+
+
+/**
+ * optional GetFolderByIdResponse getFolderById = 6;
+ * @return {?proto.gigamessages.GetFolderByIdResponse}
+ */
+proto.gigamessages.Response.prototype.getGetfolderbyid = function() {
+  return /** @type{?proto.gigamessages.GetFolderByIdResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetFolderByIdResponse, 6));
+};
+
+
+/** @param {?proto.gigamessages.GetFolderByIdResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetfolderbyid = function(value) {
+  jspb.Message.setOneofWrapperField(this, 6, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetfolderbyid = function() {
+  this.setGetfolderbyid(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetfolderbyid = function() {
+  return jspb.Message.getField(this, 6) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse synchronize = 7;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getSynchronize = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 7));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setSynchronize = function(value) {
+  jspb.Message.setOneofWrapperField(this, 7, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearSynchronize = function() {
+  this.setSynchronize(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasSynchronize = function() {
+  return jspb.Message.getField(this, 7) != null;
+};
+
+
+/**
+ * optional LoginResponse login = 8;
+ * @return {?proto.gigamessages.LoginResponse}
+ */
+proto.gigamessages.Response.prototype.getLogin = function() {
+  return /** @type{?proto.gigamessages.LoginResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.LoginResponse, 8));
+};
+
+
+/** @param {?proto.gigamessages.LoginResponse|undefined} value */
+proto.gigamessages.Response.prototype.setLogin = function(value) {
+  jspb.Message.setOneofWrapperField(this, 8, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearLogin = function() {
+  this.setLogin(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasLogin = function() {
+  return jspb.Message.getField(this, 8) != null;
+};
+
+
+/**
+ * optional GetLastLoginDataResponse getLastLoginData = 9;
+ * @return {?proto.gigamessages.GetLastLoginDataResponse}
+ */
+proto.gigamessages.Response.prototype.getGetlastlogindata = function() {
+  return /** @type{?proto.gigamessages.GetLastLoginDataResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetLastLoginDataResponse, 9));
+};
+
+
+/** @param {?proto.gigamessages.GetLastLoginDataResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetlastlogindata = function(value) {
+  jspb.Message.setOneofWrapperField(this, 9, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetlastlogindata = function() {
+  this.setGetlastlogindata(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetlastlogindata = function() {
+  return jspb.Message.getField(this, 9) != null;
+};
+
+
+/**
+ * optional GetRootFolderResponse getRootFolder = 10;
+ * @return {?proto.gigamessages.GetRootFolderResponse}
+ */
+proto.gigamessages.Response.prototype.getGetrootfolder = function() {
+  return /** @type{?proto.gigamessages.GetRootFolderResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetRootFolderResponse, 10));
+};
+
+
+/** @param {?proto.gigamessages.GetRootFolderResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetrootfolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 10, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetrootfolder = function() {
+  this.setGetrootfolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetrootfolder = function() {
+  return jspb.Message.getField(this, 10) != null;
+};
+
+
+/**
+ * optional GetFoldersListResponse getAllFolders = 11;
+ * @return {?proto.gigamessages.GetFoldersListResponse}
+ */
+proto.gigamessages.Response.prototype.getGetallfolders = function() {
+  return /** @type{?proto.gigamessages.GetFoldersListResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetFoldersListResponse, 11));
+};
+
+
+/** @param {?proto.gigamessages.GetFoldersListResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetallfolders = function(value) {
+  jspb.Message.setOneofWrapperField(this, 11, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetallfolders = function() {
+  this.setGetallfolders(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetallfolders = function() {
+  return jspb.Message.getField(this, 11) != null;
+};
+
+
+/**
+ * optional GetNotesListResponse getAllNotes = 12;
+ * @return {?proto.gigamessages.GetNotesListResponse}
+ */
+proto.gigamessages.Response.prototype.getGetallnotes = function() {
+  return /** @type{?proto.gigamessages.GetNotesListResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetNotesListResponse, 12));
+};
+
+
+/** @param {?proto.gigamessages.GetNotesListResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetallnotes = function(value) {
+  jspb.Message.setOneofWrapperField(this, 12, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetallnotes = function() {
+  this.setGetallnotes(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetallnotes = function() {
+  return jspb.Message.getField(this, 12) != null;
+};
+
+
+/**
+ * optional CreateFolderResponse createFolder = 13;
+ * @return {?proto.gigamessages.CreateFolderResponse}
+ */
+proto.gigamessages.Response.prototype.getCreatefolder = function() {
+  return /** @type{?proto.gigamessages.CreateFolderResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.CreateFolderResponse, 13));
+};
+
+
+/** @param {?proto.gigamessages.CreateFolderResponse|undefined} value */
+proto.gigamessages.Response.prototype.setCreatefolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 13, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearCreatefolder = function() {
+  this.setCreatefolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasCreatefolder = function() {
+  return jspb.Message.getField(this, 13) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse updateNote = 14;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getUpdatenote = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 14));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setUpdatenote = function(value) {
+  jspb.Message.setOneofWrapperField(this, 14, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearUpdatenote = function() {
+  this.setUpdatenote(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasUpdatenote = function() {
+  return jspb.Message.getField(this, 14) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse updateFolder = 15;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getUpdatefolder = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 15));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setUpdatefolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 15, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearUpdatefolder = function() {
+  this.setUpdatefolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasUpdatefolder = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse removeNote = 16;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getRemovenote = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 16));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setRemovenote = function(value) {
+  jspb.Message.setOneofWrapperField(this, 16, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearRemovenote = function() {
+  this.setRemovenote(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasRemovenote = function() {
+  return jspb.Message.getField(this, 16) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse removeFolder = 17;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getRemovefolder = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 17));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setRemovefolder = function(value) {
+  jspb.Message.setOneofWrapperField(this, 17, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearRemovefolder = function() {
+  this.setRemovefolder(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasRemovefolder = function() {
+  return jspb.Message.getField(this, 17) != null;
+};
+
+
+/**
+ * optional GetNotesListResponse searchNotes = 18;
+ * @return {?proto.gigamessages.GetNotesListResponse}
+ */
+proto.gigamessages.Response.prototype.getSearchnotes = function() {
+  return /** @type{?proto.gigamessages.GetNotesListResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetNotesListResponse, 18));
+};
+
+
+/** @param {?proto.gigamessages.GetNotesListResponse|undefined} value */
+proto.gigamessages.Response.prototype.setSearchnotes = function(value) {
+  jspb.Message.setOneofWrapperField(this, 18, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearSearchnotes = function() {
+  this.setSearchnotes(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasSearchnotes = function() {
+  return jspb.Message.getField(this, 18) != null;
+};
+
+
+/**
+ * optional LoginResponse register = 19;
+ * @return {?proto.gigamessages.LoginResponse}
+ */
+proto.gigamessages.Response.prototype.getRegister = function() {
+  return /** @type{?proto.gigamessages.LoginResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.LoginResponse, 19));
+};
+
+
+/** @param {?proto.gigamessages.LoginResponse|undefined} value */
+proto.gigamessages.Response.prototype.setRegister = function(value) {
+  jspb.Message.setOneofWrapperField(this, 19, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearRegister = function() {
+  this.setRegister(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasRegister = function() {
+  return jspb.Message.getField(this, 19) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse addToFavorites = 20;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getAddtofavorites = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 20));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setAddtofavorites = function(value) {
+  jspb.Message.setOneofWrapperField(this, 20, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearAddtofavorites = function() {
+  this.setAddtofavorites(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasAddtofavorites = function() {
+  return jspb.Message.getField(this, 20) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse removeFromFavorites = 21;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getRemovefromfavorites = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 21));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setRemovefromfavorites = function(value) {
+  jspb.Message.setOneofWrapperField(this, 21, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearRemovefromfavorites = function() {
+  this.setRemovefromfavorites(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasRemovefromfavorites = function() {
+  return jspb.Message.getField(this, 21) != null;
+};
+
+
+/**
+ * optional GetNotesListResponse getFavorites = 22;
+ * @return {?proto.gigamessages.GetNotesListResponse}
+ */
+proto.gigamessages.Response.prototype.getGetfavorites = function() {
+  return /** @type{?proto.gigamessages.GetNotesListResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.GetNotesListResponse, 22));
+};
+
+
+/** @param {?proto.gigamessages.GetNotesListResponse|undefined} value */
+proto.gigamessages.Response.prototype.setGetfavorites = function(value) {
+  jspb.Message.setOneofWrapperField(this, 22, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearGetfavorites = function() {
+  this.setGetfavorites(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasGetfavorites = function() {
+  return jspb.Message.getField(this, 22) != null;
+};
+
+
+/**
+ * optional EmptyResultResponse logout = 23;
+ * @return {?proto.gigamessages.EmptyResultResponse}
+ */
+proto.gigamessages.Response.prototype.getLogout = function() {
+  return /** @type{?proto.gigamessages.EmptyResultResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EmptyResultResponse, 23));
+};
+
+
+/** @param {?proto.gigamessages.EmptyResultResponse|undefined} value */
+proto.gigamessages.Response.prototype.setLogout = function(value) {
+  jspb.Message.setOneofWrapperField(this, 23, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearLogout = function() {
+  this.setLogout(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasLogout = function() {
+  return jspb.Message.getField(this, 23) != null;
+};
+
+
+/**
+ * optional LoginResponse loginSocial = 24;
+ * @return {?proto.gigamessages.LoginResponse}
+ */
+proto.gigamessages.Response.prototype.getLoginsocial = function() {
+  return /** @type{?proto.gigamessages.LoginResponse} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.LoginResponse, 24));
+};
+
+
+/** @param {?proto.gigamessages.LoginResponse|undefined} value */
+proto.gigamessages.Response.prototype.setLoginsocial = function(value) {
+  jspb.Message.setOneofWrapperField(this, 24, proto.gigamessages.Response.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.Response.prototype.clearLoginsocial = function() {
+  this.setLoginsocial(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasLoginsocial = function() {
+  return jspb.Message.getField(this, 24) != null;
+};
+
+
+/**
+ * optional Error error = 25;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.Response.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 25));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.Response.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 25, value);
+};
+
+
+proto.gigamessages.Response.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Response.prototype.hasError = function() {
+  return jspb.Message.getField(this, 25) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.RequestSequence = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.RequestSequence.repeatedFields_, null);
+};
+goog.inherits(proto.gigamessages.RequestSequence, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.RequestSequence.displayName = 'proto.gigamessages.RequestSequence';
+}
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.gigamessages.RequestSequence.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.RequestSequence.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.RequestSequence.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.RequestSequence} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RequestSequence.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    requestsList: jspb.Message.toObjectList(msg.getRequestsList(),
+    proto.gigamessages.Request.toObject, includeInstance),
+    stoponerror: jspb.Message.getFieldWithDefault(msg, 2, false)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.RequestSequence}
+ */
+proto.gigamessages.RequestSequence.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.RequestSequence;
+  return proto.gigamessages.RequestSequence.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.RequestSequence} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.RequestSequence}
+ */
+proto.gigamessages.RequestSequence.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.gigamessages.Request;
+      reader.readMessage(value,proto.gigamessages.Request.deserializeBinaryFromReader);
+      msg.addRequests(value);
+      break;
+    case 2:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setStoponerror(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.RequestSequence.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.RequestSequence.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.RequestSequence} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RequestSequence.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getRequestsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.gigamessages.Request.serializeBinaryToWriter
+    );
+  }
+  f = message.getStoponerror();
+  if (f) {
+    writer.writeBool(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * repeated Request requests = 1;
+ * @return {!Array<!proto.gigamessages.Request>}
+ */
+proto.gigamessages.RequestSequence.prototype.getRequestsList = function() {
+  return /** @type{!Array<!proto.gigamessages.Request>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.Request, 1));
+};
+
+
+/** @param {!Array<!proto.gigamessages.Request>} value */
+proto.gigamessages.RequestSequence.prototype.setRequestsList = function(value) {
+  jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.gigamessages.Request=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.gigamessages.Request}
+ */
+proto.gigamessages.RequestSequence.prototype.addRequests = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.gigamessages.Request, opt_index);
+};
+
+
+proto.gigamessages.RequestSequence.prototype.clearRequestsList = function() {
+  this.setRequestsList([]);
+};
+
+
+/**
+ * optional bool stopOnError = 2;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.RequestSequence.prototype.getStoponerror = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 2, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.RequestSequence.prototype.setStoponerror = function(value) {
+  jspb.Message.setProto3BooleanField(this, 2, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.RequestSequenceResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.RequestSequenceResponse.repeatedFields_, null);
+};
+goog.inherits(proto.gigamessages.RequestSequenceResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.RequestSequenceResponse.displayName = 'proto.gigamessages.RequestSequenceResponse';
+}
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.gigamessages.RequestSequenceResponse.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.RequestSequenceResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.RequestSequenceResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.RequestSequenceResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RequestSequenceResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    responsesList: jspb.Message.toObjectList(msg.getResponsesList(),
+    proto.gigamessages.Response.toObject, includeInstance),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.RequestSequenceResponse}
+ */
+proto.gigamessages.RequestSequenceResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.RequestSequenceResponse;
+  return proto.gigamessages.RequestSequenceResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.RequestSequenceResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.RequestSequenceResponse}
+ */
+proto.gigamessages.RequestSequenceResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.gigamessages.Response;
+      reader.readMessage(value,proto.gigamessages.Response.deserializeBinaryFromReader);
+      msg.addResponses(value);
+      break;
+    case 2:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.RequestSequenceResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.RequestSequenceResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.RequestSequenceResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.RequestSequenceResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getResponsesList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.gigamessages.Response.serializeBinaryToWriter
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * repeated Response responses = 1;
+ * @return {!Array<!proto.gigamessages.Response>}
+ */
+proto.gigamessages.RequestSequenceResponse.prototype.getResponsesList = function() {
+  return /** @type{!Array<!proto.gigamessages.Response>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.Response, 1));
+};
+
+
+/** @param {!Array<!proto.gigamessages.Response>} value */
+proto.gigamessages.RequestSequenceResponse.prototype.setResponsesList = function(value) {
+  jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.gigamessages.Response=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.gigamessages.Response}
+ */
+proto.gigamessages.RequestSequenceResponse.prototype.addResponses = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.gigamessages.Response, opt_index);
+};
+
+
+proto.gigamessages.RequestSequenceResponse.prototype.clearResponsesList = function() {
+  this.setResponsesList([]);
+};
+
+
+/**
+ * optional Error error = 2;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.RequestSequenceResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 2));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.RequestSequenceResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+proto.gigamessages.RequestSequenceResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.RequestSequenceResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.CommandInfo = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.CommandInfo, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.CommandInfo.displayName = 'proto.gigamessages.CommandInfo';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.CommandInfo.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.CommandInfo.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.CommandInfo} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.CommandInfo.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    name: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    index: jspb.Message.getFieldWithDefault(msg, 2, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.CommandInfo}
+ */
+proto.gigamessages.CommandInfo.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.CommandInfo;
+  return proto.gigamessages.CommandInfo.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.CommandInfo} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.CommandInfo}
+ */
+proto.gigamessages.CommandInfo.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setName(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setIndex(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.CommandInfo.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.CommandInfo.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.CommandInfo} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.CommandInfo.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getName();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getIndex();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string name = 1;
+ * @return {string}
+ */
+proto.gigamessages.CommandInfo.prototype.getName = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.CommandInfo.prototype.setName = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional int32 index = 2;
+ * @return {number}
+ */
+proto.gigamessages.CommandInfo.prototype.getIndex = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.CommandInfo.prototype.setIndex = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.VersionInfo = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.VersionInfo.repeatedFields_, null);
+};
+goog.inherits(proto.gigamessages.VersionInfo, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.VersionInfo.displayName = 'proto.gigamessages.VersionInfo';
+}
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.gigamessages.VersionInfo.repeatedFields_ = [6,7];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.VersionInfo.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.VersionInfo.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.VersionInfo} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.VersionInfo.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    bridgeversion: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    coreversion: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    protocolversion: jspb.Message.getFieldWithDefault(msg, 3, 0),
+    minprotocolversion: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    buildprofile: jspb.Message.getFieldWithDefault(msg, 5, ""),
+    commandsList: jspb.Message.toObjectList(msg.getCommandsList(),
+    proto.gigamessages.CommandInfo.toObject, includeInstance),
+    eventsList: jspb.Message.getRepeatedField(msg, 7)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.VersionInfo}
+ */
+proto.gigamessages.VersionInfo.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.VersionInfo;
+  return proto.gigamessages.VersionInfo.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.VersionInfo} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.VersionInfo}
+ */
+proto.gigamessages.VersionInfo.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setBridgeversion(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setCoreversion(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setProtocolversion(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setMinprotocolversion(value);
+      break;
+    case 5:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setBuildprofile(value);
+      break;
+    case 6:
+      var value = new proto.gigamessages.CommandInfo;
+      reader.readMessage(value,proto.gigamessages.CommandInfo.deserializeBinaryFromReader);
+      msg.addCommands(value);
+      break;
+    case 7:
+      var value = /** @type {string} */ (reader.readString());
+      msg.addEvents(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.VersionInfo.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.VersionInfo.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.VersionInfo} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.VersionInfo.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getBridgeversion();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getCoreversion();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getProtocolversion();
+  if (f !== 0) {
+    writer.writeUint32(
+      3,
+      f
+    );
+  }
+  f = message.getMinprotocolversion();
+  if (f !== 0) {
+    writer.writeUint32(
+      4,
+      f
+    );
+  }
+  f = message.getBuildprofile();
+  if (f.length > 0) {
+    writer.writeString(
+      5,
+      f
+    );
+  }
+  f = message.getCommandsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      6,
+      f,
+      proto.gigamessages.CommandInfo.serializeBinaryToWriter
+    );
+  }
+  f = message.getEventsList();
+  if (f.length > 0) {
+    writer.writeRepeatedString(
+      7,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string bridgeVersion = 1;
+ * @return {string}
+ */
+proto.gigamessages.VersionInfo.prototype.getBridgeversion = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.VersionInfo.prototype.setBridgeversion = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string coreVersion = 2;
+ * @return {string}
+ */
+proto.gigamessages.VersionInfo.prototype.getCoreversion = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.VersionInfo.prototype.setCoreversion = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional uint32 protocolVersion = 3;
+ * @return {number}
+ */
+proto.gigamessages.VersionInfo.prototype.getProtocolversion = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.VersionInfo.prototype.setProtocolversion = function(value) {
+  jspb.Message.setProto3IntField(this, 3, value);
+};
+
+
+/**
+ * optional uint32 minProtocolVersion = 4;
+ * @return {number}
+ */
+proto.gigamessages.VersionInfo.prototype.getMinprotocolversion = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.VersionInfo.prototype.setMinprotocolversion = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional string buildProfile = 5;
+ * @return {string}
+ */
+proto.gigamessages.VersionInfo.prototype.getBuildprofile = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.VersionInfo.prototype.setBuildprofile = function(value) {
+  jspb.Message.setProto3StringField(this, 5, value);
+};
+
+
+/**
+ * repeated CommandInfo commands = 6;
+ * @return {!Array<!proto.gigamessages.CommandInfo>}
+ */
+proto.gigamessages.VersionInfo.prototype.getCommandsList = function() {
+  return /** @type{!Array<!proto.gigamessages.CommandInfo>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.CommandInfo, 6));
+};
+
+
+/** @param {!Array<!proto.gigamessages.CommandInfo>} value */
+proto.gigamessages.VersionInfo.prototype.setCommandsList = function(value) {
+  jspb.Message.setRepeatedWrapperField(this, 6, value);
+};
+
+
+/**
+ * @param {!proto.gigamessages.CommandInfo=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.gigamessages.CommandInfo}
+ */
+proto.gigamessages.VersionInfo.prototype.addCommands = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 6, opt_value, proto.gigamessages.CommandInfo, opt_index);
+};
+
+
+proto.gigamessages.VersionInfo.prototype.clearCommandsList = function() {
+  this.setCommandsList([]);
+};
+
+
+/**
+ * repeated string events = 7;
+ * @return {!Array<string>}
+ */
+proto.gigamessages.VersionInfo.prototype.getEventsList = function() {
+  return /** @type {!Array<string>} */ (jspb.Message.getRepeatedField(this, 7));
+};
+
+
+/** @param {!Array<string>} value */
+proto.gigamessages.VersionInfo.prototype.setEventsList = function(value) {
+  jspb.Message.setField(this, 7, value || []);
+};
+
+
+/**
+ * @param {!string} value
+ * @param {number=} opt_index
+ */
+proto.gigamessages.VersionInfo.prototype.addEvents = function(value, opt_index) {
+  jspb.Message.addToRepeatedField(this, 7, value, opt_index);
+};
+
+
+proto.gigamessages.VersionInfo.prototype.clearEventsList = function() {
+  this.setEventsList([]);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.Handshake = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.Handshake, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.Handshake.displayName = 'proto.gigamessages.Handshake';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.Handshake.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Handshake.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.Handshake} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Handshake.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    protocolversion: jspb.Message.getFieldWithDefault(msg, 1, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.Handshake}
+ */
+proto.gigamessages.Handshake.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.Handshake;
+  return proto.gigamessages.Handshake.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.Handshake} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.Handshake}
+ */
+proto.gigamessages.Handshake.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readUint32());
+      msg.setProtocolversion(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.Handshake.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.Handshake.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.Handshake} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Handshake.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getProtocolversion();
+  if (f !== 0) {
+    writer.writeUint32(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional uint32 protocolVersion = 1;
+ * @return {number}
+ */
+proto.gigamessages.Handshake.prototype.getProtocolversion = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Handshake.prototype.setProtocolversion = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.HandshakeResponse = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.HandshakeResponse, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.HandshakeResponse.displayName = 'proto.gigamessages.HandshakeResponse';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.HandshakeResponse.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.HandshakeResponse.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.HandshakeResponse} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.HandshakeResponse.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    success: jspb.Message.getFieldWithDefault(msg, 1, false),
+    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    version: (f = msg.getVersion()) && proto.gigamessages.VersionInfo.toObject(includeInstance, f),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.HandshakeResponse}
+ */
+proto.gigamessages.HandshakeResponse.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.HandshakeResponse;
+  return proto.gigamessages.HandshakeResponse.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.HandshakeResponse} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.HandshakeResponse}
+ */
+proto.gigamessages.HandshakeResponse.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {boolean} */ (reader.readBool());
+      msg.setSuccess(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setErrorcode(value);
+      break;
+    case 3:
+      var value = new proto.gigamessages.VersionInfo;
+      reader.readMessage(value,proto.gigamessages.VersionInfo.deserializeBinaryFromReader);
+      msg.setVersion(value);
+      break;
+    case 15:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.HandshakeResponse.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.HandshakeResponse.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.HandshakeResponse} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.HandshakeResponse.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getSuccess();
+  if (f) {
+    writer.writeBool(
+      1,
+      f
+    );
+  }
+  f = message.getErrorcode();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+  f = message.getVersion();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.gigamessages.VersionInfo.serializeBinaryToWriter
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      15,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional bool success = 1;
+ * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
+ * You should avoid comparisons like {@code val === true/false} in those cases.
+ * @return {boolean}
+ */
+proto.gigamessages.HandshakeResponse.prototype.getSuccess = function() {
+  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+};
+
+
+/** @param {boolean} value */
+proto.gigamessages.HandshakeResponse.prototype.setSuccess = function(value) {
+  jspb.Message.setProto3BooleanField(this, 1, value);
+};
+
+
+/**
+ * optional int32 errorCode = 2;
+ * @return {number}
+ */
+proto.gigamessages.HandshakeResponse.prototype.getErrorcode = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.HandshakeResponse.prototype.setErrorcode = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional VersionInfo version = 3;
+ * @return {?proto.gigamessages.VersionInfo}
+ */
+proto.gigamessages.HandshakeResponse.prototype.getVersion = function() {
+  return /** @type{?proto.gigamessages.VersionInfo} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.VersionInfo, 3));
+};
+
+
+/** @param {?proto.gigamessages.VersionInfo|undefined} value */
+proto.gigamessages.HandshakeResponse.prototype.setVersion = function(value) {
+  jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+proto.gigamessages.HandshakeResponse.prototype.clearVersion = function() {
+  this.setVersion(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.HandshakeResponse.prototype.hasVersion = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+/**
+ * optional Error error = 15;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.HandshakeResponse.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 15));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.HandshakeResponse.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 15, value);
+};
+
+
+proto.gigamessages.HandshakeResponse.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.HandshakeResponse.prototype.hasError = function() {
+  return jspb.Message.getField(this, 15) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.Histogram = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.Histogram.repeatedFields_, null);
+};
+goog.inherits(proto.gigamessages.Histogram, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.Histogram.displayName = 'proto.gigamessages.Histogram';
+}
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.gigamessages.Histogram.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.Histogram.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Histogram.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.Histogram} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Histogram.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    bucketsList: jspb.Message.toObjectList(msg.getBucketsList(),
+    proto.gigamessages.HistogramBucket.toObject, includeInstance),
+    count: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    sum: jspb.Message.getFieldWithDefault(msg, 3, 0.0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.Histogram}
+ */
+proto.gigamessages.Histogram.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.Histogram;
+  return proto.gigamessages.Histogram.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.Histogram} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.Histogram}
+ */
+proto.gigamessages.Histogram.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.gigamessages.HistogramBucket;
+      reader.readMessage(value,proto.gigamessages.HistogramBucket.deserializeBinaryFromReader);
+      msg.addBuckets(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setCount(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readDouble());
+      msg.setSum(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.Histogram.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.Histogram.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.Histogram} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Histogram.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getBucketsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.gigamessages.HistogramBucket.serializeBinaryToWriter
+    );
+  }
+  f = message.getCount();
+  if (f !== 0) {
+    writer.writeUint64(
+      2,
+      f
+    );
+  }
+  f = message.getSum();
+  if (f !== 0.0) {
+    writer.writeDouble(
+      3,
+      f
+    );
+  }
+};
+
+
+/**
+ * repeated HistogramBucket buckets = 1;
+ * @return {!Array<!proto.gigamessages.HistogramBucket>}
+ */
+proto.gigamessages.Histogram.prototype.getBucketsList = function() {
+  return /** @type{!Array<!proto.gigamessages.HistogramBucket>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.HistogramBucket, 1));
+};
+
+
+/** @param {!Array<!proto.gigamessages.HistogramBucket>} value */
+proto.gigamessages.Histogram.prototype.setBucketsList = function(value) {
+  jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.gigamessages.HistogramBucket=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.gigamessages.HistogramBucket}
+ */
+proto.gigamessages.Histogram.prototype.addBuckets = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.gigamessages.HistogramBucket, opt_index);
+};
+
+
+proto.gigamessages.Histogram.prototype.clearBucketsList = function() {
+  this.setBucketsList([]);
+};
+
+
+/**
+ * optional uint64 count = 2;
+ * @return {number}
+ */
+proto.gigamessages.Histogram.prototype.getCount = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Histogram.prototype.setCount = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional double sum = 3;
+ * @return {number}
+ */
+proto.gigamessages.Histogram.prototype.getSum = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0.0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.Histogram.prototype.setSum = function(value) {
+  jspb.Message.setProto3FloatField(this, 3, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.HistogramBucket = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.HistogramBucket, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.HistogramBucket.displayName = 'proto.gigamessages.HistogramBucket';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.HistogramBucket.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.HistogramBucket.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.HistogramBucket} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.HistogramBucket.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    le: jspb.Message.getFieldWithDefault(msg, 1, 0.0),
+    count: jspb.Message.getFieldWithDefault(msg, 2, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.HistogramBucket}
+ */
+proto.gigamessages.HistogramBucket.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.HistogramBucket;
+  return proto.gigamessages.HistogramBucket.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.HistogramBucket} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.HistogramBucket}
+ */
+proto.gigamessages.HistogramBucket.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readDouble());
+      msg.setLe(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setCount(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.HistogramBucket.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.HistogramBucket.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.HistogramBucket} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.HistogramBucket.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getLe();
+  if (f !== 0.0) {
+    writer.writeDouble(
+      1,
+      f
+    );
+  }
+  f = message.getCount();
+  if (f !== 0) {
+    writer.writeUint64(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional double le = 1;
+ * @return {number}
+ */
+proto.gigamessages.HistogramBucket.prototype.getLe = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0.0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.HistogramBucket.prototype.setLe = function(value) {
+  jspb.Message.setProto3FloatField(this, 1, value);
+};
+
+
+/**
+ * optional uint64 count = 2;
+ * @return {number}
+ */
+proto.gigamessages.HistogramBucket.prototype.getCount = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.HistogramBucket.prototype.setCount = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.CommandMetrics = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.CommandMetrics, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.CommandMetrics.displayName = 'proto.gigamessages.CommandMetrics';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.CommandMetrics.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.CommandMetrics.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.CommandMetrics} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.CommandMetrics.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    name: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    index: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    calls: jspb.Message.getFieldWithDefault(msg, 3, 0),
+    errors: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    requestbytes: jspb.Message.getFieldWithDefault(msg, 5, 0),
+    responsebytes: jspb.Message.getFieldWithDefault(msg, 6, 0),
+    latency: (f = msg.getLatency()) && proto.gigamessages.Histogram.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.CommandMetrics}
+ */
+proto.gigamessages.CommandMetrics.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.CommandMetrics;
+  return proto.gigamessages.CommandMetrics.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.CommandMetrics} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.CommandMetrics}
+ */
+proto.gigamessages.CommandMetrics.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setName(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setIndex(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setCalls(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setErrors(value);
+      break;
+    case 5:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setRequestbytes(value);
+      break;
+    case 6:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setResponsebytes(value);
+      break;
+    case 7:
+      var value = new proto.gigamessages.Histogram;
+      reader.readMessage(value,proto.gigamessages.Histogram.deserializeBinaryFromReader);
+      msg.setLatency(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.CommandMetrics.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.CommandMetrics.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.CommandMetrics} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.CommandMetrics.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getName();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getIndex();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+  f = message.getCalls();
+  if (f !== 0) {
+    writer.writeUint64(
+      3,
+      f
+    );
+  }
+  f = message.getErrors();
+  if (f !== 0) {
+    writer.writeUint64(
+      4,
+      f
+    );
+  }
+  f = message.getRequestbytes();
+  if (f !== 0) {
+    writer.writeUint64(
+      5,
+      f
+    );
+  }
+  f = message.getResponsebytes();
+  if (f !== 0) {
+    writer.writeUint64(
+      6,
+      f
+    );
+  }
+  f = message.getLatency();
+  if (f != null) {
+    writer.writeMessage(
+      7,
+      f,
+      proto.gigamessages.Histogram.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional string name = 1;
+ * @return {string}
+ */
+proto.gigamessages.CommandMetrics.prototype.getName = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.CommandMetrics.prototype.setName = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional int32 index = 2;
+ * @return {number}
+ */
+proto.gigamessages.CommandMetrics.prototype.getIndex = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.CommandMetrics.prototype.setIndex = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional uint64 calls = 3;
+ * @return {number}
+ */
+proto.gigamessages.CommandMetrics.prototype.getCalls = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.CommandMetrics.prototype.setCalls = function(value) {
+  jspb.Message.setProto3IntField(this, 3, value);
+};
+
+
+/**
+ * optional uint64 errors = 4;
+ * @return {number}
+ */
+proto.gigamessages.CommandMetrics.prototype.getErrors = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.CommandMetrics.prototype.setErrors = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional uint64 requestBytes = 5;
+ * @return {number}
+ */
+proto.gigamessages.CommandMetrics.prototype.getRequestbytes = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 5, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.CommandMetrics.prototype.setRequestbytes = function(value) {
+  jspb.Message.setProto3IntField(this, 5, value);
+};
+
+
+/**
+ * optional uint64 responseBytes = 6;
+ * @return {number}
+ */
+proto.gigamessages.CommandMetrics.prototype.getResponsebytes = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 6, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.CommandMetrics.prototype.setResponsebytes = function(value) {
+  jspb.Message.setProto3IntField(this, 6, value);
+};
+
+
+/**
+ * optional Histogram latency = 7;
+ * @return {?proto.gigamessages.Histogram}
+ */
+proto.gigamessages.CommandMetrics.prototype.getLatency = function() {
+  return /** @type{?proto.gigamessages.Histogram} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Histogram, 7));
+};
+
+
+/** @param {?proto.gigamessages.Histogram|undefined} value */
+proto.gigamessages.CommandMetrics.prototype.setLatency = function(value) {
+  jspb.Message.setWrapperField(this, 7, value);
+};
+
+
+proto.gigamessages.CommandMetrics.prototype.clearLatency = function() {
+  this.setLatency(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CommandMetrics.prototype.hasLatency = function() {
+  return jspb.Message.getField(this, 7) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.EventMetrics = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.EventMetrics, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.EventMetrics.displayName = 'proto.gigamessages.EventMetrics';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.EventMetrics.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.EventMetrics.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.EventMetrics} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.EventMetrics.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    published: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    delivered: jspb.Message.getFieldWithDefault(msg, 2, 0),
+    dropped: jspb.Message.getFieldWithDefault(msg, 3, 0),
+    publishedbytes: jspb.Message.getFieldWithDefault(msg, 4, 0),
+    deliverylatency: (f = msg.getDeliverylatency()) && proto.gigamessages.Histogram.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.EventMetrics}
+ */
+proto.gigamessages.EventMetrics.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.EventMetrics;
+  return proto.gigamessages.EventMetrics.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.EventMetrics} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.EventMetrics}
+ */
+proto.gigamessages.EventMetrics.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setPublished(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setDelivered(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setDropped(value);
+      break;
+    case 4:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setPublishedbytes(value);
+      break;
+    case 5:
+      var value = new proto.gigamessages.Histogram;
+      reader.readMessage(value,proto.gigamessages.Histogram.deserializeBinaryFromReader);
+      msg.setDeliverylatency(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.EventMetrics.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.EventMetrics.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.EventMetrics} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.EventMetrics.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getPublished();
+  if (f !== 0) {
+    writer.writeUint64(
+      1,
+      f
+    );
+  }
+  f = message.getDelivered();
+  if (f !== 0) {
+    writer.writeUint64(
+      2,
+      f
+    );
+  }
+  f = message.getDropped();
+  if (f !== 0) {
+    writer.writeUint64(
+      3,
+      f
+    );
+  }
+  f = message.getPublishedbytes();
+  if (f !== 0) {
+    writer.writeUint64(
+      4,
+      f
+    );
+  }
+  f = message.getDeliverylatency();
+  if (f != null) {
+    writer.writeMessage(
+      5,
+      f,
+      proto.gigamessages.Histogram.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional uint64 published = 1;
+ * @return {number}
+ */
+proto.gigamessages.EventMetrics.prototype.getPublished = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.EventMetrics.prototype.setPublished = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+/**
+ * optional uint64 delivered = 2;
+ * @return {number}
+ */
+proto.gigamessages.EventMetrics.prototype.getDelivered = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.EventMetrics.prototype.setDelivered = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+/**
+ * optional uint64 dropped = 3;
+ * @return {number}
+ */
+proto.gigamessages.EventMetrics.prototype.getDropped = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.EventMetrics.prototype.setDropped = function(value) {
+  jspb.Message.setProto3IntField(this, 3, value);
+};
+
+
+/**
+ * optional uint64 publishedBytes = 4;
+ * @return {number}
+ */
+proto.gigamessages.EventMetrics.prototype.getPublishedbytes = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.EventMetrics.prototype.setPublishedbytes = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
+};
+
+
+/**
+ * optional Histogram deliveryLatency = 5;
+ * @return {?proto.gigamessages.Histogram}
+ */
+proto.gigamessages.EventMetrics.prototype.getDeliverylatency = function() {
+  return /** @type{?proto.gigamessages.Histogram} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Histogram, 5));
+};
+
+
+/** @param {?proto.gigamessages.Histogram|undefined} value */
+proto.gigamessages.EventMetrics.prototype.setDeliverylatency = function(value) {
+  jspb.Message.setWrapperField(this, 5, value);
+};
+
+
+proto.gigamessages.EventMetrics.prototype.clearDeliverylatency = function() {
+  this.setDeliverylatency(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.EventMetrics.prototype.hasDeliverylatency = function() {
+  return jspb.Message.getField(this, 5) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.Metrics = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, proto.gigamessages.Metrics.repeatedFields_, null);
+};
+goog.inherits(proto.gigamessages.Metrics, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.Metrics.displayName = 'proto.gigamessages.Metrics';
+}
+/**
+ * List of repeated fields within this message type.
+ * @private {!Array<number>}
+ * @const
+ */
+proto.gigamessages.Metrics.repeatedFields_ = [1];
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.Metrics.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.Metrics.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.Metrics} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Metrics.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    commandsList: jspb.Message.toObjectList(msg.getCommandsList(),
+    proto.gigamessages.CommandMetrics.toObject, includeInstance),
+    events: (f = msg.getEvents()) && proto.gigamessages.EventMetrics.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.Metrics}
+ */
+proto.gigamessages.Metrics.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.Metrics;
+  return proto.gigamessages.Metrics.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.Metrics} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.Metrics}
+ */
+proto.gigamessages.Metrics.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.gigamessages.CommandMetrics;
+      reader.readMessage(value,proto.gigamessages.CommandMetrics.deserializeBinaryFromReader);
+      msg.addCommands(value);
+      break;
+    case 2:
+      var value = new proto.gigamessages.EventMetrics;
+      reader.readMessage(value,proto.gigamessages.EventMetrics.deserializeBinaryFromReader);
+      msg.setEvents(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.Metrics.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.Metrics.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.Metrics} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.Metrics.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getCommandsList();
+  if (f.length > 0) {
+    writer.writeRepeatedMessage(
+      1,
+      f,
+      proto.gigamessages.CommandMetrics.serializeBinaryToWriter
+    );
+  }
+  f = message.getEvents();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.gigamessages.EventMetrics.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * repeated CommandMetrics commands = 1;
+ * @return {!Array<!proto.gigamessages.CommandMetrics>}
+ */
+proto.gigamessages.Metrics.prototype.getCommandsList = function() {
+  return /** @type{!Array<!proto.gigamessages.CommandMetrics>} */ (
+    jspb.Message.getRepeatedWrapperField(this, proto.gigamessages.CommandMetrics, 1));
+};
+
+
+/** @param {!Array<!proto.gigamessages.CommandMetrics>} value */
+proto.gigamessages.Metrics.prototype.setCommandsList = function(value) {
+  jspb.Message.setRepeatedWrapperField(this, 1, value);
+};
+
+
+/**
+ * @param {!proto.gigamessages.CommandMetrics=} opt_value
+ * @param {number=} opt_index
+ * @return {!proto.gigamessages.CommandMetrics}
+ */
+proto.gigamessages.Metrics.prototype.addCommands = function(opt_value, opt_index) {
+  return jspb.Message.addToRepeatedWrapperField(this, 1, opt_value, proto.gigamessages.CommandMetrics, opt_index);
+};
+
+
+proto.gigamessages.Metrics.prototype.clearCommandsList = function() {
+  this.setCommandsList([]);
+};
+
+
+/**
+ * optional EventMetrics events = 2;
+ * @return {?proto.gigamessages.EventMetrics}
+ */
+proto.gigamessages.Metrics.prototype.getEvents = function() {
+  return /** @type{?proto.gigamessages.EventMetrics} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EventMetrics, 2));
+};
+
+
+/** @param {?proto.gigamessages.EventMetrics|undefined} value */
+proto.gigamessages.Metrics.prototype.setEvents = function(value) {
+  jspb.Message.setWrapperField(this, 2, value);
+};
+
+
+proto.gigamessages.Metrics.prototype.clearEvents = function() {
+  this.setEvents(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.Metrics.prototype.hasEvents = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.CoreEvent = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.gigamessages.CoreEvent.oneofGroups_);
+};
+goog.inherits(proto.gigamessages.CoreEvent, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.CoreEvent.displayName = 'proto.gigamessages.CoreEvent';
+}
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value is retained.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.gigamessages.CoreEvent.oneofGroups_ = [[1,2,3,4,5,6,7,8,9,10]];
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.CoreEvent.PayloadCase = {
+  PAYLOAD_NOT_SET: 0,
+  SYNC_STARTED: 1,
+  SYNC_PROGRESS: 2,
+  SYNC_FINISHED: 3,
+  SYNC_FAILED: 4,
+  NOTE_CHANGED: 5,
+  FOLDER_CHANGED: 6,
+  AUTH_EXPIRED: 7,
+  LOG: 8,
+  EVENTS_DROPPED: 9,
+  RESYNC_REQUIRED: 10
+};
+
+/**
+ * @return {proto.gigamessages.CoreEvent.PayloadCase}
+ */
+proto.gigamessages.CoreEvent.prototype.getPayloadCase = function() {
+  return /** @type {proto.gigamessages.CoreEvent.PayloadCase} */(jspb.Message.computeOneofCase(this, proto.gigamessages.CoreEvent.oneofGroups_[0]));
+};
+
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.CoreEvent.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.CoreEvent.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.CoreEvent} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.CoreEvent.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    syncstarted: (f = msg.getSyncstarted()) && proto.gigamessages.SyncStarted.toObject(includeInstance, f),
+    syncprogress: (f = msg.getSyncprogress()) && proto.gigamessages.SyncProgress.toObject(includeInstance, f),
+    syncfinished: (f = msg.getSyncfinished()) && proto.gigamessages.SyncFinished.toObject(includeInstance, f),
+    syncfailed: (f = msg.getSyncfailed()) && proto.gigamessages.SyncFailed.toObject(includeInstance, f),
+    notechanged: (f = msg.getNotechanged()) && proto.gigamessages.NoteChanged.toObject(includeInstance, f),
+    folderchanged: (f = msg.getFolderchanged()) && proto.gigamessages.FolderChanged.toObject(includeInstance, f),
+    authexpired: (f = msg.getAuthexpired()) && proto.gigamessages.AuthExpired.toObject(includeInstance, f),
+    log: (f = msg.getLog()) && proto.gigamessages.LogRecord.toObject(includeInstance, f),
+    eventsdropped: (f = msg.getEventsdropped()) && proto.gigamessages.EventsDropped.toObject(includeInstance, f),
+    resyncrequired: (f = msg.getResyncrequired()) && proto.gigamessages.ResyncRequired.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.CoreEvent}
+ */
+proto.gigamessages.CoreEvent.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.CoreEvent;
+  return proto.gigamessages.CoreEvent.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.CoreEvent} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.CoreEvent}
+ */
+proto.gigamessages.CoreEvent.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.gigamessages.SyncStarted;
+      reader.readMessage(value,proto.gigamessages.SyncStarted.deserializeBinaryFromReader);
+      msg.setSyncstarted(value);
+      break;
+    case 2:
+      var value = new proto.gigamessages.SyncProgress;
+      reader.readMessage(value,proto.gigamessages.SyncProgress.deserializeBinaryFromReader);
+      msg.setSyncprogress(value);
+      break;
+    case 3:
+      var value = new proto.gigamessages.SyncFinished;
+      reader.readMessage(value,proto.gigamessages.SyncFinished.deserializeBinaryFromReader);
+      msg.setSyncfinished(value);
+      break;
+    case 4:
+      var value = new proto.gigamessages.SyncFailed;
+      reader.readMessage(value,proto.gigamessages.SyncFailed.deserializeBinaryFromReader);
+      msg.setSyncfailed(value);
+      break;
+    case 5:
+      var value = new proto.gigamessages.NoteChanged;
+      reader.readMessage(value,proto.gigamessages.NoteChanged.deserializeBinaryFromReader);
+      msg.setNotechanged(value);
+      break;
+    case 6:
+      var value = new proto.gigamessages.FolderChanged;
+      reader.readMessage(value,proto.gigamessages.FolderChanged.deserializeBinaryFromReader);
+      msg.setFolderchanged(value);
+      break;
+    case 7:
+      var value = new proto.gigamessages.AuthExpired;
+      reader.readMessage(value,proto.gigamessages.AuthExpired.deserializeBinaryFromReader);
+      msg.setAuthexpired(value);
+      break;
+    case 8:
+      var value = new proto.gigamessages.LogRecord;
+      reader.readMessage(value,proto.gigamessages.LogRecord.deserializeBinaryFromReader);
+      msg.setLog(value);
+      break;
+    case 9:
+      var value = new proto.gigamessages.EventsDropped;
+      reader.readMessage(value,proto.gigamessages.EventsDropped.deserializeBinaryFromReader);
+      msg.setEventsdropped(value);
+      break;
+    case 10:
+      var value = new proto.gigamessages.ResyncRequired;
+      reader.readMessage(value,proto.gigamessages.ResyncRequired.deserializeBinaryFromReader);
+      msg.setResyncrequired(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.CoreEvent.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.CoreEvent.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.CoreEvent} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.CoreEvent.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getSyncstarted();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.gigamessages.SyncStarted.serializeBinaryToWriter
+    );
+  }
+  f = message.getSyncprogress();
+  if (f != null) {
+    writer.writeMessage(
+      2,
+      f,
+      proto.gigamessages.SyncProgress.serializeBinaryToWriter
+    );
+  }
+  f = message.getSyncfinished();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.gigamessages.SyncFinished.serializeBinaryToWriter
+    );
+  }
+  f = message.getSyncfailed();
+  if (f != null) {
+    writer.writeMessage(
+      4,
+      f,
+      proto.gigamessages.SyncFailed.serializeBinaryToWriter
+    );
+  }
+  f = message.getNotechanged();
+  if (f != null) {
+    writer.writeMessage(
+      5,
+      f,
+      proto.gigamessages.NoteChanged.serializeBinaryToWriter
+    );
+  }
+  f = message.getFolderchanged();
+  if (f != null) {
+    writer.writeMessage(
+      6,
+      f,
+      proto.gigamessages.FolderChanged.serializeBinaryToWriter
+    );
+  }
+  f = message.getAuthexpired();
+  if (f != null) {
+    writer.writeMessage(
+      7,
+      f,
+      proto.gigamessages.AuthExpired.serializeBinaryToWriter
+    );
+  }
+  f = message.getLog();
+  if (f != null) {
+    writer.writeMessage(
+      8,
+      f,
+      proto.gigamessages.LogRecord.serializeBinaryToWriter
+    );
+  }
+  f = message.getEventsdropped();
+  if (f != null) {
+    writer.writeMessage(
+      9,
+      f,
+      proto.gigamessages.EventsDropped.serializeBinaryToWriter
+    );
+  }
+  f = message.getResyncrequired();
+  if (f != null) {
+    writer.writeMessage(
+      10,
+      f,
+      proto.gigamessages.ResyncRequired.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional SyncStarted syncStarted = 1;
+ * @return {?proto.gigamessages.SyncStarted}
+ */
+proto.gigamessages.CoreEvent.prototype.getSyncstarted = function() {
+  return /** @type{?proto.gigamessages.SyncStarted} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.SyncStarted, 1));
+};
+
+
+/** @param {?proto.gigamessages.SyncStarted|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setSyncstarted = function(value) {
+  jspb.Message.setOneofWrapperField(this, 1, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearSyncstarted = function() {
+  this.setSyncstarted(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasSyncstarted = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+/**
+ * optional SyncProgress syncProgress = 2;
+ * @return {?proto.gigamessages.SyncProgress}
+ */
+proto.gigamessages.CoreEvent.prototype.getSyncprogress = function() {
+  return /** @type{?proto.gigamessages.SyncProgress} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.SyncProgress, 2));
+};
+
+
+/** @param {?proto.gigamessages.SyncProgress|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setSyncprogress = function(value) {
+  jspb.Message.setOneofWrapperField(this, 2, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearSyncprogress = function() {
+  this.setSyncprogress(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasSyncprogress = function() {
+  return jspb.Message.getField(this, 2) != null;
+};
+
+
+/**
+ * optional SyncFinished syncFinished = 3;
+ * @return {?proto.gigamessages.SyncFinished}
+ */
+proto.gigamessages.CoreEvent.prototype.getSyncfinished = function() {
+  return /** @type{?proto.gigamessages.SyncFinished} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.SyncFinished, 3));
+};
+
+
+/** @param {?proto.gigamessages.SyncFinished|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setSyncfinished = function(value) {
+  jspb.Message.setOneofWrapperField(this, 3, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearSyncfinished = function() {
+  this.setSyncfinished(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasSyncfinished = function() {
+  return jspb.Message.getField(this, 3) != null;
+};
+
+
+/**
+ * optional SyncFailed syncFailed = 4;
+ * @return {?proto.gigamessages.SyncFailed}
+ */
+proto.gigamessages.CoreEvent.prototype.getSyncfailed = function() {
+  return /** @type{?proto.gigamessages.SyncFailed} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.SyncFailed, 4));
+};
+
+
+/** @param {?proto.gigamessages.SyncFailed|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setSyncfailed = function(value) {
+  jspb.Message.setOneofWrapperField(this, 4, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearSyncfailed = function() {
+  this.setSyncfailed(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasSyncfailed = function() {
+  return jspb.Message.getField(this, 4) != null;
+};
+
+
+/**
+ * optional NoteChanged noteChanged = 5;
+ * @return {?proto.gigamessages.NoteChanged}
+ */
+proto.gigamessages.CoreEvent.prototype.getNotechanged = function() {
+  return /** @type{?proto.gigamessages.NoteChanged} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.NoteChanged, 5));
+};
+
+
+/** @param {?proto.gigamessages.NoteChanged|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setNotechanged = function(value) {
+  jspb.Message.setOneofWrapperField(this, 5, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearNotechanged = function() {
+  this.setNotechanged(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasNotechanged = function() {
+  return jspb.Message.getField(this, 5) != null;
+};
+
+
+/**
+ * optional FolderChanged folderChanged = 6;
+ * @return {?proto.gigamessages.FolderChanged}
+ */
+proto.gigamessages.CoreEvent.prototype.getFolderchanged = function() {
+  return /** @type{?proto.gigamessages.FolderChanged} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.FolderChanged, 6));
+};
+
+
+/** @param {?proto.gigamessages.FolderChanged|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setFolderchanged = function(value) {
+  jspb.Message.setOneofWrapperField(this, 6, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearFolderchanged = function() {
+  this.setFolderchanged(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasFolderchanged = function() {
+  return jspb.Message.getField(this, 6) != null;
+};
+
+
+/**
+ * optional AuthExpired authExpired = 7;
+ * @return {?proto.gigamessages.AuthExpired}
+ */
+proto.gigamessages.CoreEvent.prototype.getAuthexpired = function() {
+  return /** @type{?proto.gigamessages.AuthExpired} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.AuthExpired, 7));
+};
+
+
+/** @param {?proto.gigamessages.AuthExpired|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setAuthexpired = function(value) {
+  jspb.Message.setOneofWrapperField(this, 7, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearAuthexpired = function() {
+  this.setAuthexpired(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasAuthexpired = function() {
+  return jspb.Message.getField(this, 7) != null;
+};
+
+
+/**
+ * optional LogRecord log = 8;
+ * @return {?proto.gigamessages.LogRecord}
+ */
+proto.gigamessages.CoreEvent.prototype.getLog = function() {
+  return /** @type{?proto.gigamessages.LogRecord} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.LogRecord, 8));
+};
+
+
+/** @param {?proto.gigamessages.LogRecord|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setLog = function(value) {
+  jspb.Message.setOneofWrapperField(this, 8, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearLog = function() {
+  this.setLog(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasLog = function() {
+  return jspb.Message.getField(this, 8) != null;
+};
+
+
+/**
+ * optional EventsDropped eventsDropped = 9;
+ * @return {?proto.gigamessages.EventsDropped}
+ */
+proto.gigamessages.CoreEvent.prototype.getEventsdropped = function() {
+  return /** @type{?proto.gigamessages.EventsDropped} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.EventsDropped, 9));
+};
+
+
+/** @param {?proto.gigamessages.EventsDropped|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setEventsdropped = function(value) {
+  jspb.Message.setOneofWrapperField(this, 9, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearEventsdropped = function() {
+  this.setEventsdropped(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasEventsdropped = function() {
+  return jspb.Message.getField(this, 9) != null;
+};
+
+
+/**
+ * optional ResyncRequired resyncRequired = 10;
+ * @return {?proto.gigamessages.ResyncRequired}
+ */
+proto.gigamessages.CoreEvent.prototype.getResyncrequired = function() {
+  return /** @type{?proto.gigamessages.ResyncRequired} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.ResyncRequired, 10));
+};
+
+
+/** @param {?proto.gigamessages.ResyncRequired|undefined} value */
+proto.gigamessages.CoreEvent.prototype.setResyncrequired = function(value) {
+  jspb.Message.setOneofWrapperField(this, 10, proto.gigamessages.CoreEvent.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.CoreEvent.prototype.clearResyncrequired = function() {
+  this.setResyncrequired(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.CoreEvent.prototype.hasResyncrequired = function() {
+  return jspb.Message.getField(this, 10) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.SyncStarted = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.SyncStarted, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.SyncStarted.displayName = 'proto.gigamessages.SyncStarted';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.SyncStarted.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.SyncStarted.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.SyncStarted} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncStarted.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.SyncStarted}
+ */
+proto.gigamessages.SyncStarted.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.SyncStarted;
+  return proto.gigamessages.SyncStarted.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.SyncStarted} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.SyncStarted}
+ */
+proto.gigamessages.SyncStarted.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.SyncStarted.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.SyncStarted.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.SyncStarted} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncStarted.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.SyncProgress = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.SyncProgress, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.SyncProgress.displayName = 'proto.gigamessages.SyncProgress';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.SyncProgress.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.SyncProgress.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.SyncProgress} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncProgress.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    done: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    total: jspb.Message.getFieldWithDefault(msg, 2, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.SyncProgress}
+ */
+proto.gigamessages.SyncProgress.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.SyncProgress;
+  return proto.gigamessages.SyncProgress.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.SyncProgress} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.SyncProgress}
+ */
+proto.gigamessages.SyncProgress.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setDone(value);
+      break;
+    case 2:
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setTotal(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.SyncProgress.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.SyncProgress.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.SyncProgress} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncProgress.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getDone();
+  if (f !== 0) {
+    writer.writeInt32(
+      1,
+      f
+    );
+  }
+  f = message.getTotal();
+  if (f !== 0) {
+    writer.writeInt32(
+      2,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional int32 done = 1;
+ * @return {number}
+ */
+proto.gigamessages.SyncProgress.prototype.getDone = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.SyncProgress.prototype.setDone = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+/**
+ * optional int32 total = 2;
+ * @return {number}
+ */
+proto.gigamessages.SyncProgress.prototype.getTotal = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.SyncProgress.prototype.setTotal = function(value) {
+  jspb.Message.setProto3IntField(this, 2, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.SyncFinished = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.SyncFinished, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.SyncFinished.displayName = 'proto.gigamessages.SyncFinished';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.SyncFinished.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.SyncFinished.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.SyncFinished} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncFinished.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.SyncFinished}
+ */
+proto.gigamessages.SyncFinished.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.SyncFinished;
+  return proto.gigamessages.SyncFinished.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.SyncFinished} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.SyncFinished}
+ */
+proto.gigamessages.SyncFinished.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.SyncFinished.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.SyncFinished.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.SyncFinished} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncFinished.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.SyncFailed = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.SyncFailed, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.SyncFailed.displayName = 'proto.gigamessages.SyncFailed';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.SyncFailed.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.SyncFailed.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.SyncFailed} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncFailed.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.SyncFailed}
+ */
+proto.gigamessages.SyncFailed.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.SyncFailed;
+  return proto.gigamessages.SyncFailed.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.SyncFailed} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.SyncFailed}
+ */
+proto.gigamessages.SyncFailed.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.SyncFailed.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.SyncFailed.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.SyncFailed} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.SyncFailed.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      1,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
+};
+
+
+/**
+ * optional Error error = 1;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.SyncFailed.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 1));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.SyncFailed.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 1, value);
+};
+
+
+proto.gigamessages.SyncFailed.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.SyncFailed.prototype.hasError = function() {
+  return jspb.Message.getField(this, 1) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.NoteChanged = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.NoteChanged, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.NoteChanged.displayName = 'proto.gigamessages.NoteChanged';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.NoteChanged.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.NoteChanged.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.NoteChanged} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.NoteChanged.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    noteid: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    folderid: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    kind: jspb.Message.getFieldWithDefault(msg, 3, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.NoteChanged}
+ */
+proto.gigamessages.NoteChanged.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.NoteChanged;
+  return proto.gigamessages.NoteChanged.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.NoteChanged} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.NoteChanged}
+ */
+proto.gigamessages.NoteChanged.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setNoteid(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setFolderid(value);
+      break;
+    case 3:
+      var value = /** @type {!proto.gigamessages.ChangeKind} */ (reader.readEnum());
+      msg.setKind(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.NoteChanged.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.NoteChanged.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.NoteChanged} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.NoteChanged.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getNoteid();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+  f = message.getFolderid();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getKind();
+  if (f !== 0.0) {
+    writer.writeEnum(
+      3,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string noteId = 1;
+ * @return {string}
+ */
+proto.gigamessages.NoteChanged.prototype.getNoteid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.NoteChanged.prototype.setNoteid = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
+};
+
+
+/**
+ * optional string folderId = 2;
+ * @return {string}
+ */
+proto.gigamessages.NoteChanged.prototype.getFolderid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.NoteChanged.prototype.setFolderid = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional ChangeKind kind = 3;
+ * @return {!proto.gigamessages.ChangeKind}
+ */
+proto.gigamessages.NoteChanged.prototype.getKind = function() {
+  return /** @type {!proto.gigamessages.ChangeKind} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/** @param {!proto.gigamessages.ChangeKind} value */
+proto.gigamessages.NoteChanged.prototype.setKind = function(value) {
+  jspb.Message.setProto3EnumField(this, 3, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.FolderChanged = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.FolderChanged, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.FolderChanged.displayName = 'proto.gigamessages.FolderChanged';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.FolderChanged.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.FolderChanged.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.FolderChanged} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.FolderChanged.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    folderid: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    parentid: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    kind: jspb.Message.getFieldWithDefault(msg, 3, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.FolderChanged}
+ */
+proto.gigamessages.FolderChanged.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.FolderChanged;
+  return proto.gigamessages.FolderChanged.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.FolderChanged} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.FolderChanged}
+ */
+proto.gigamessages.FolderChanged.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setFolderid(value);
+      break;
+    case 2:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setParentid(value);
+      break;
+    case 3:
+      var value = /** @type {!proto.gigamessages.ChangeKind} */ (reader.readEnum());
+      msg.setKind(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
@@ -4127,9 +13811,9 @@ proto.gigamessages.RemoveFolder.deserializeBinaryFromReader = function(msg, read
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.RemoveFolder.prototype.serializeBinary = function() {
+proto.gigamessages.FolderChanged.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.RemoveFolder.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.FolderChanged.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -4137,11 +13821,11 @@ proto.gigamessages.RemoveFolder.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.RemoveFolder} message
+ * @param {!proto.gigamessages.FolderChanged} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.RemoveFolder.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.FolderChanged.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
   f = message.getFolderid();
   if (f.length > 0) {
@@ -4150,6 +13834,20 @@ proto.gigamessages.RemoveFolder.serializeBinaryToWriter = function(message, writ
       f
     );
   }
+  f = message.getParentid();
+  if (f.length > 0) {
+    writer.writeString(
+      2,
+      f
+    );
+  }
+  f = message.getKind();
+  if (f !== 0.0) {
+    writer.writeEnum(
+      3,
+      f
+    );
+  }
 };
 
 
@@ -4157,17 +13855,163 @@ proto.gigamessages.RemoveFolder.serializeBinaryToWriter = function(message, writ
  * optional string folderId = 1;
  * @return {string}
  */
-proto.gigamessages.RemoveFolder.prototype.getFolderid = function() {
+proto.gigamessages.FolderChanged.prototype.getFolderid = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.RemoveFolder.prototype.setFolderid = function(value) {
+proto.gigamessages.FolderChanged.prototype.setFolderid = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
+/**
+ * optional string parentId = 2;
+ * @return {string}
+ */
+proto.gigamessages.FolderChanged.prototype.getParentid = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/** @param {string} value */
+proto.gigamessages.FolderChanged.prototype.setParentid = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
+};
+
+
+/**
+ * optional ChangeKind kind = 3;
+ * @return {!proto.gigamessages.ChangeKind}
+ */
+proto.gigamessages.FolderChanged.prototype.getKind = function() {
+  return /** @type {!proto.gigamessages.ChangeKind} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/** @param {!proto.gigamessages.ChangeKind} value */
+proto.gigamessages.FolderChanged.prototype.setKind = function(value) {
+  jspb.Message.setProto3EnumField(this, 3, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.AuthExpired = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.AuthExpired, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.AuthExpired.displayName = 'proto.gigamessages.AuthExpired';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.AuthExpired.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.AuthExpired.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.AuthExpired} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.AuthExpired.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.AuthExpired}
+ */
+proto.gigamessages.AuthExpired.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.AuthExpired;
+  return proto.gigamessages.AuthExpired.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.AuthExpired} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.AuthExpired}
+ */
+proto.gigamessages.AuthExpired.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.AuthExpired.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.AuthExpired.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.AuthExpired} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.AuthExpired.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+};
+
+
 
 /**
  * Generated by JsPbCodeGenerator.
@@ -4179,12 +14023,12 @@ proto.gigamessages.RemoveFolder.prototype.setFolderid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetRootFolderResponse = function(opt_data) {
+proto.gigamessages.LogRecord = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.GetRootFolderResponse, jspb.Message);
+goog.inherits(proto.gigamessages.LogRecord, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetRootFolderResponse.displayName = 'proto.gigamessages.GetRootFolderResponse';
+  proto.gigamessages.LogRecord.displayName = 'proto.gigamessages.LogRecord';
 }
 
 
@@ -4199,8 +14043,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetRootFolderResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetRootFolderResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.LogRecord.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.LogRecord.toObject(opt_includeInstance, this);
 };
 
 
@@ -4209,16 +14053,16 @@ proto.gigamessages.GetRootFolderResponse.prototype.toObject = function(opt_inclu
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetRootFolderResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.LogRecord} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetRootFolderResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.LogRecord.toObject = function(includeInstance, msg) {
   var f, obj = {
-    success: jspb.Message.getFieldWithDefault(msg, 1, false),
-    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    folderid: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    title: jspb.Message.getFieldWithDefault(msg, 5, "")
+    level: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    target: jspb.Message.getFieldWithDefault(msg, 2, ""),
+    message: jspb.Message.getFieldWithDefault(msg, 3, ""),
+    timestamp: jspb.Message.getFieldWithDefault(msg, 4, 0)
   };
 
   if (includeInstance) {
@@ -4232,23 +14076,23 @@ proto.gigamessages.GetRootFolderResponse.toObject = function(includeInstance, ms
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetRootFolderResponse}
+ * @return {!proto.gigamessages.LogRecord}
  */
-proto.gigamessages.GetRootFolderResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.LogRecord.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetRootFolderResponse;
-  return proto.gigamessages.GetRootFolderResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.LogRecord;
+  return proto.gigamessages.LogRecord.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetRootFolderResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.LogRecord} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetRootFolderResponse}
+ * @return {!proto.gigamessages.LogRecord}
  */
-proto.gigamessages.GetRootFolderResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.LogRecord.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -4256,20 +14100,20 @@ proto.gigamessages.GetRootFolderResponse.deserializeBinaryFromReader = function(
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setSuccess(value);
+      var value = /** @type {!proto.gigamessages.LogLevel} */ (reader.readEnum());
+      msg.setLevel(value);
       break;
     case 2:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setErrorcode(value);
+      var value = /** @type {string} */ (reader.readString());
+      msg.setTarget(value);
       break;
     case 3:
       var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
+      msg.setMessage(value);
       break;
-    case 5:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
+    case 4:
+      var value = /** @type {number} */ (reader.readInt64());
+      msg.setTimestamp(value);
       break;
     default:
       reader.skipField();
@@ -4284,9 +14128,9 @@ proto.gigamessages.GetRootFolderResponse.deserializeBinaryFromReader = function(
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetRootFolderResponse.prototype.serializeBinary = function() {
+proto.gigamessages.LogRecord.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetRootFolderResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.LogRecord.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -4294,37 +14138,37 @@ proto.gigamessages.GetRootFolderResponse.prototype.serializeBinary = function()
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetRootFolderResponse} message
+ * @param {!proto.gigamessages.LogRecord} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetRootFolderResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.LogRecord.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSuccess();
-  if (f) {
-    writer.writeBool(
+  f = message.getLevel();
+  if (f !== 0.0) {
+    writer.writeEnum(
       1,
       f
     );
   }
-  f = message.getErrorcode();
-  if (f !== 0) {
-    writer.writeInt32(
+  f = message.getTarget();
+  if (f.length > 0) {
+    writer.writeString(
       2,
       f
     );
   }
-  f = message.getFolderid();
+  f = message.getMessage();
   if (f.length > 0) {
     writer.writeString(
       3,
       f
     );
   }
-  f = message.getTitle();
-  if (f.length > 0) {
-    writer.writeString(
-      5,
+  f = message.getTimestamp();
+  if (f !== 0) {
+    writer.writeInt64(
+      4,
       f
     );
   }
@@ -4332,64 +14176,62 @@ proto.gigamessages.GetRootFolderResponse.serializeBinaryToWriter = function(mess
 
 
 /**
- * optional bool success = 1;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
- * @return {boolean}
+ * optional LogLevel level = 1;
+ * @return {!proto.gigamessages.LogLevel}
  */
-proto.gigamessages.GetRootFolderResponse.prototype.getSuccess = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+proto.gigamessages.LogRecord.prototype.getLevel = function() {
+  return /** @type {!proto.gigamessages.LogLevel} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
-/** @param {boolean} value */
-proto.gigamessages.GetRootFolderResponse.prototype.setSuccess = function(value) {
-  jspb.Message.setProto3BooleanField(this, 1, value);
+/** @param {!proto.gigamessages.LogLevel} value */
+proto.gigamessages.LogRecord.prototype.setLevel = function(value) {
+  jspb.Message.setProto3EnumField(this, 1, value);
 };
 
 
 /**
- * optional int32 errorCode = 2;
- * @return {number}
+ * optional string target = 2;
+ * @return {string}
  */
-proto.gigamessages.GetRootFolderResponse.prototype.getErrorcode = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+proto.gigamessages.LogRecord.prototype.getTarget = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.GetRootFolderResponse.prototype.setErrorcode = function(value) {
-  jspb.Message.setProto3IntField(this, 2, value);
+/** @param {string} value */
+proto.gigamessages.LogRecord.prototype.setTarget = function(value) {
+  jspb.Message.setProto3StringField(this, 2, value);
 };
 
 
 /**
- * optional string folderId = 3;
+ * optional string message = 3;
  * @return {string}
  */
-proto.gigamessages.GetRootFolderResponse.prototype.getFolderid = function() {
+proto.gigamessages.LogRecord.prototype.getMessage = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.GetRootFolderResponse.prototype.setFolderid = function(value) {
+proto.gigamessages.LogRecord.prototype.setMessage = function(value) {
   jspb.Message.setProto3StringField(this, 3, value);
 };
 
 
 /**
- * optional string title = 5;
- * @return {string}
+ * optional int64 timestamp = 4;
+ * @return {number}
  */
-proto.gigamessages.GetRootFolderResponse.prototype.getTitle = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+proto.gigamessages.LogRecord.prototype.getTimestamp = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.GetRootFolderResponse.prototype.setTitle = function(value) {
-  jspb.Message.setProto3StringField(this, 5, value);
+/** @param {number} value */
+proto.gigamessages.LogRecord.prototype.setTimestamp = function(value) {
+  jspb.Message.setProto3IntField(this, 4, value);
 };
 
 
@@ -4404,12 +14246,12 @@ proto.gigamessages.GetRootFolderResponse.prototype.setTitle = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.EmptyResultResponse = function(opt_data) {
+proto.gigamessages.EventsDropped = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.EmptyResultResponse, jspb.Message);
+goog.inherits(proto.gigamessages.EventsDropped, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.EmptyResultResponse.displayName = 'proto.gigamessages.EmptyResultResponse';
+  proto.gigamessages.EventsDropped.displayName = 'proto.gigamessages.EventsDropped';
 }
 
 
@@ -4424,8 +14266,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.EmptyResultResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.EmptyResultResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.EventsDropped.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.EventsDropped.toObject(opt_includeInstance, this);
 };
 
 
@@ -4434,14 +14276,13 @@ proto.gigamessages.EmptyResultResponse.prototype.toObject = function(opt_include
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.EmptyResultResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.EventsDropped} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.EmptyResultResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.EventsDropped.toObject = function(includeInstance, msg) {
   var f, obj = {
-    success: jspb.Message.getFieldWithDefault(msg, 1, false),
-    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0)
+    count: jspb.Message.getFieldWithDefault(msg, 1, 0)
   };
 
   if (includeInstance) {
@@ -4455,23 +14296,23 @@ proto.gigamessages.EmptyResultResponse.toObject = function(includeInstance, msg)
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.EmptyResultResponse}
+ * @return {!proto.gigamessages.EventsDropped}
  */
-proto.gigamessages.EmptyResultResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.EventsDropped.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.EmptyResultResponse;
-  return proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.EventsDropped;
+  return proto.gigamessages.EventsDropped.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.EmptyResultResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.EventsDropped} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.EmptyResultResponse}
+ * @return {!proto.gigamessages.EventsDropped}
  */
-proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.EventsDropped.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -4479,12 +14320,8 @@ proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader = function(ms
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setSuccess(value);
-      break;
-    case 2:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setErrorcode(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setCount(value);
       break;
     default:
       reader.skipField();
@@ -4499,9 +14336,9 @@ proto.gigamessages.EmptyResultResponse.deserializeBinaryFromReader = function(ms
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.EmptyResultResponse.prototype.serializeBinary = function() {
+proto.gigamessages.EventsDropped.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.EventsDropped.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -4509,58 +14346,176 @@ proto.gigamessages.EmptyResultResponse.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.EmptyResultResponse} message
+ * @param {!proto.gigamessages.EventsDropped} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.EmptyResultResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.EventsDropped.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSuccess();
-  if (f) {
-    writer.writeBool(
+  f = message.getCount();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
       f
     );
   }
-  f = message.getErrorcode();
-  if (f !== 0) {
-    writer.writeInt32(
-      2,
-      f
-    );
+};
+
+
+/**
+ * optional uint64 count = 1;
+ * @return {number}
+ */
+proto.gigamessages.EventsDropped.prototype.getCount = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.EventsDropped.prototype.setCount = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.ResyncRequired = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.ResyncRequired, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.ResyncRequired.displayName = 'proto.gigamessages.ResyncRequired';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.ResyncRequired.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ResyncRequired.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.ResyncRequired} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.ResyncRequired.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    latestseq: jspb.Message.getFieldWithDefault(msg, 1, 0)
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
   }
+  return obj;
 };
+}
 
 
 /**
- * optional bool success = 1;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
- * @return {boolean}
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.ResyncRequired}
  */
-proto.gigamessages.EmptyResultResponse.prototype.getSuccess = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+proto.gigamessages.ResyncRequired.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.ResyncRequired;
+  return proto.gigamessages.ResyncRequired.deserializeBinaryFromReader(msg, reader);
 };
 
 
-/** @param {boolean} value */
-proto.gigamessages.EmptyResultResponse.prototype.setSuccess = function(value) {
-  jspb.Message.setProto3BooleanField(this, 1, value);
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.ResyncRequired} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.ResyncRequired}
+ */
+proto.gigamessages.ResyncRequired.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setLatestseq(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
 /**
- * optional int32 errorCode = 2;
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.ResyncRequired.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.ResyncRequired.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.ResyncRequired} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.ResyncRequired.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getLatestseq();
+  if (f !== 0) {
+    writer.writeUint64(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional uint64 latestSeq = 1;
  * @return {number}
  */
-proto.gigamessages.EmptyResultResponse.prototype.getErrorcode = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+proto.gigamessages.ResyncRequired.prototype.getLatestseq = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
 /** @param {number} value */
-proto.gigamessages.EmptyResultResponse.prototype.setErrorcode = function(value) {
-  jspb.Message.setProto3IntField(this, 2, value);
+proto.gigamessages.ResyncRequired.prototype.setLatestseq = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
@@ -4575,13 +14530,41 @@ proto.gigamessages.EmptyResultResponse.prototype.setErrorcode = function(value)
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.GetLastLoginDataResponse = function(opt_data) {
-  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+proto.gigamessages.ServerFrame = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.gigamessages.ServerFrame.oneofGroups_);
 };
-goog.inherits(proto.gigamessages.GetLastLoginDataResponse, jspb.Message);
+goog.inherits(proto.gigamessages.ServerFrame, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.GetLastLoginDataResponse.displayName = 'proto.gigamessages.GetLastLoginDataResponse';
+  proto.gigamessages.ServerFrame.displayName = 'proto.gigamessages.ServerFrame';
 }
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value is retained.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.gigamessages.ServerFrame.oneofGroups_ = [[2,3,4,5]];
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.ServerFrame.BodyCase = {
+  BODY_NOT_SET: 0,
+  AUTH: 2,
+  COMMAND: 3,
+  SUBSCRIBE: 4,
+  UNSUBSCRIBE: 5
+};
+
+/**
+ * @return {proto.gigamessages.ServerFrame.BodyCase}
+ */
+proto.gigamessages.ServerFrame.prototype.getBodyCase = function() {
+  return /** @type {proto.gigamessages.ServerFrame.BodyCase} */(jspb.Message.computeOneofCase(this, proto.gigamessages.ServerFrame.oneofGroups_[0]));
+};
+
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -4595,8 +14578,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.GetLastLoginDataResponse.toObject(opt_includeInstance, this);
+proto.gigamessages.ServerFrame.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerFrame.toObject(opt_includeInstance, this);
 };
 
 
@@ -4605,18 +14588,17 @@ proto.gigamessages.GetLastLoginDataResponse.prototype.toObject = function(opt_in
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.GetLastLoginDataResponse} msg The msg instance to transform.
+ * @param {!proto.gigamessages.ServerFrame} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetLastLoginDataResponse.toObject = function(includeInstance, msg) {
+proto.gigamessages.ServerFrame.toObject = function(includeInstance, msg) {
   var f, obj = {
-    success: jspb.Message.getFieldWithDefault(msg, 1, false),
-    errorcode: jspb.Message.getFieldWithDefault(msg, 2, 0),
-    token: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    userid: jspb.Message.getFieldWithDefault(msg, 4, 0),
-    email: jspb.Message.getFieldWithDefault(msg, 5, ""),
-    istokenvalid: jspb.Message.getFieldWithDefault(msg, 6, false)
+    requestid: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    auth: (f = msg.getAuth()) && proto.gigamessages.ServerAuth.toObject(includeInstance, f),
+    command: (f = msg.getCommand()) && proto.gigamessages.ServerCommand.toObject(includeInstance, f),
+    subscribe: (f = msg.getSubscribe()) && proto.gigamessages.ServerSubscribe.toObject(includeInstance, f),
+    unsubscribe: (f = msg.getUnsubscribe()) && proto.gigamessages.ServerUnsubscribe.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -4630,23 +14612,23 @@ proto.gigamessages.GetLastLoginDataResponse.toObject = function(includeInstance,
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.GetLastLoginDataResponse}
+ * @return {!proto.gigamessages.ServerFrame}
  */
-proto.gigamessages.GetLastLoginDataResponse.deserializeBinary = function(bytes) {
+proto.gigamessages.ServerFrame.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.GetLastLoginDataResponse;
-  return proto.gigamessages.GetLastLoginDataResponse.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.ServerFrame;
+  return proto.gigamessages.ServerFrame.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.GetLastLoginDataResponse} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.ServerFrame} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.GetLastLoginDataResponse}
+ * @return {!proto.gigamessages.ServerFrame}
  */
-proto.gigamessages.GetLastLoginDataResponse.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.ServerFrame.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -4654,28 +14636,28 @@ proto.gigamessages.GetLastLoginDataResponse.deserializeBinaryFromReader = functi
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setSuccess(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setRequestid(value);
       break;
     case 2:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setErrorcode(value);
+      var value = new proto.gigamessages.ServerAuth;
+      reader.readMessage(value,proto.gigamessages.ServerAuth.deserializeBinaryFromReader);
+      msg.setAuth(value);
       break;
     case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setToken(value);
+      var value = new proto.gigamessages.ServerCommand;
+      reader.readMessage(value,proto.gigamessages.ServerCommand.deserializeBinaryFromReader);
+      msg.setCommand(value);
       break;
     case 4:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setUserid(value);
+      var value = new proto.gigamessages.ServerSubscribe;
+      reader.readMessage(value,proto.gigamessages.ServerSubscribe.deserializeBinaryFromReader);
+      msg.setSubscribe(value);
       break;
     case 5:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setEmail(value);
-      break;
-    case 6:
-      var value = /** @type {boolean} */ (reader.readBool());
-      msg.setIstokenvalid(value);
+      var value = new proto.gigamessages.ServerUnsubscribe;
+      reader.readMessage(value,proto.gigamessages.ServerUnsubscribe.deserializeBinaryFromReader);
+      msg.setUnsubscribe(value);
       break;
     default:
       reader.skipField();
@@ -4690,9 +14672,9 @@ proto.gigamessages.GetLastLoginDataResponse.deserializeBinaryFromReader = functi
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.serializeBinary = function() {
+proto.gigamessages.ServerFrame.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.GetLastLoginDataResponse.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.ServerFrame.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -4700,148 +14682,328 @@ proto.gigamessages.GetLastLoginDataResponse.prototype.serializeBinary = function
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.GetLastLoginDataResponse} message
+ * @param {!proto.gigamessages.ServerFrame} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.GetLastLoginDataResponse.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.ServerFrame.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getSuccess();
-  if (f) {
-    writer.writeBool(
+  f = message.getRequestid();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
       f
     );
   }
-  f = message.getErrorcode();
-  if (f !== 0) {
-    writer.writeInt32(
+  f = message.getAuth();
+  if (f != null) {
+    writer.writeMessage(
       2,
-      f
+      f,
+      proto.gigamessages.ServerAuth.serializeBinaryToWriter
     );
   }
-  f = message.getToken();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getCommand();
+  if (f != null) {
+    writer.writeMessage(
       3,
-      f
+      f,
+      proto.gigamessages.ServerCommand.serializeBinaryToWriter
     );
   }
-  f = message.getUserid();
-  if (f !== 0) {
-    writer.writeInt32(
+  f = message.getSubscribe();
+  if (f != null) {
+    writer.writeMessage(
       4,
-      f
+      f,
+      proto.gigamessages.ServerSubscribe.serializeBinaryToWriter
     );
   }
-  f = message.getEmail();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getUnsubscribe();
+  if (f != null) {
+    writer.writeMessage(
       5,
-      f
-    );
-  }
-  f = message.getIstokenvalid();
-  if (f) {
-    writer.writeBool(
-      6,
-      f
+      f,
+      proto.gigamessages.ServerUnsubscribe.serializeBinaryToWriter
     );
   }
 };
 
 
 /**
- * optional bool success = 1;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
- * @return {boolean}
+ * optional uint64 requestId = 1;
+ * @return {number}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.getSuccess = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 1, false));
+proto.gigamessages.ServerFrame.prototype.getRequestid = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
-/** @param {boolean} value */
-proto.gigamessages.GetLastLoginDataResponse.prototype.setSuccess = function(value) {
-  jspb.Message.setProto3BooleanField(this, 1, value);
+/** @param {number} value */
+proto.gigamessages.ServerFrame.prototype.setRequestid = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
 /**
- * optional int32 errorCode = 2;
- * @return {number}
+ * optional ServerAuth auth = 2;
+ * @return {?proto.gigamessages.ServerAuth}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.getErrorcode = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 2, 0));
+proto.gigamessages.ServerFrame.prototype.getAuth = function() {
+  return /** @type{?proto.gigamessages.ServerAuth} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.ServerAuth, 2));
 };
 
 
-/** @param {number} value */
-proto.gigamessages.GetLastLoginDataResponse.prototype.setErrorcode = function(value) {
-  jspb.Message.setProto3IntField(this, 2, value);
+/** @param {?proto.gigamessages.ServerAuth|undefined} value */
+proto.gigamessages.ServerFrame.prototype.setAuth = function(value) {
+  jspb.Message.setOneofWrapperField(this, 2, proto.gigamessages.ServerFrame.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.ServerFrame.prototype.clearAuth = function() {
+  this.setAuth(undefined);
 };
 
 
 /**
- * optional string token = 3;
- * @return {string}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.getToken = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+proto.gigamessages.ServerFrame.prototype.hasAuth = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
-/** @param {string} value */
-proto.gigamessages.GetLastLoginDataResponse.prototype.setToken = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
+/**
+ * optional ServerCommand command = 3;
+ * @return {?proto.gigamessages.ServerCommand}
+ */
+proto.gigamessages.ServerFrame.prototype.getCommand = function() {
+  return /** @type{?proto.gigamessages.ServerCommand} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.ServerCommand, 3));
+};
+
+
+/** @param {?proto.gigamessages.ServerCommand|undefined} value */
+proto.gigamessages.ServerFrame.prototype.setCommand = function(value) {
+  jspb.Message.setOneofWrapperField(this, 3, proto.gigamessages.ServerFrame.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.ServerFrame.prototype.clearCommand = function() {
+  this.setCommand(undefined);
 };
 
 
 /**
- * optional int32 userId = 4;
- * @return {number}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.getUserid = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+proto.gigamessages.ServerFrame.prototype.hasCommand = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
-/** @param {number} value */
-proto.gigamessages.GetLastLoginDataResponse.prototype.setUserid = function(value) {
-  jspb.Message.setProto3IntField(this, 4, value);
+/**
+ * optional ServerSubscribe subscribe = 4;
+ * @return {?proto.gigamessages.ServerSubscribe}
+ */
+proto.gigamessages.ServerFrame.prototype.getSubscribe = function() {
+  return /** @type{?proto.gigamessages.ServerSubscribe} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.ServerSubscribe, 4));
+};
+
+
+/** @param {?proto.gigamessages.ServerSubscribe|undefined} value */
+proto.gigamessages.ServerFrame.prototype.setSubscribe = function(value) {
+  jspb.Message.setOneofWrapperField(this, 4, proto.gigamessages.ServerFrame.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.ServerFrame.prototype.clearSubscribe = function() {
+  this.setSubscribe(undefined);
 };
 
 
 /**
- * optional string email = 5;
- * @return {string}
+ * Returns whether this field is set.
+ * @return {boolean}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.getEmail = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 5, ""));
+proto.gigamessages.ServerFrame.prototype.hasSubscribe = function() {
+  return jspb.Message.getField(this, 4) != null;
 };
 
 
-/** @param {string} value */
-proto.gigamessages.GetLastLoginDataResponse.prototype.setEmail = function(value) {
-  jspb.Message.setProto3StringField(this, 5, value);
+/**
+ * optional ServerUnsubscribe unsubscribe = 5;
+ * @return {?proto.gigamessages.ServerUnsubscribe}
+ */
+proto.gigamessages.ServerFrame.prototype.getUnsubscribe = function() {
+  return /** @type{?proto.gigamessages.ServerUnsubscribe} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.ServerUnsubscribe, 5));
+};
+
+
+/** @param {?proto.gigamessages.ServerUnsubscribe|undefined} value */
+proto.gigamessages.ServerFrame.prototype.setUnsubscribe = function(value) {
+  jspb.Message.setOneofWrapperField(this, 5, proto.gigamessages.ServerFrame.oneofGroups_[0], value);
+};
+
+
+proto.gigamessages.ServerFrame.prototype.clearUnsubscribe = function() {
+  this.setUnsubscribe(undefined);
 };
 
 
 /**
- * optional bool isTokenValid = 6;
- * Note that Boolean fields may be set to 0/1 when serialized from a Java server.
- * You should avoid comparisons like {@code val === true/false} in those cases.
+ * Returns whether this field is set.
  * @return {boolean}
  */
-proto.gigamessages.GetLastLoginDataResponse.prototype.getIstokenvalid = function() {
-  return /** @type {boolean} */ (jspb.Message.getFieldWithDefault(this, 6, false));
+proto.gigamessages.ServerFrame.prototype.hasUnsubscribe = function() {
+  return jspb.Message.getField(this, 5) != null;
+};
+
+
+
+/**
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
+ */
+proto.gigamessages.ServerAuth = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+};
+goog.inherits(proto.gigamessages.ServerAuth, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.ServerAuth.displayName = 'proto.gigamessages.ServerAuth';
+}
+
+
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.ServerAuth.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerAuth.toObject(opt_includeInstance, this);
+};
+
+
+/**
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.ServerAuth} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.ServerAuth.toObject = function(includeInstance, msg) {
+  var f, obj = {
+    token: jspb.Message.getFieldWithDefault(msg, 1, "")
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
+};
+}
+
+
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.ServerAuth}
+ */
+proto.gigamessages.ServerAuth.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.ServerAuth;
+  return proto.gigamessages.ServerAuth.deserializeBinaryFromReader(msg, reader);
+};
+
+
+/**
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.ServerAuth} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.ServerAuth}
+ */
+proto.gigamessages.ServerAuth.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    case 1:
+      var value = /** @type {string} */ (reader.readString());
+      msg.setToken(value);
+      break;
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
+};
+
+
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.ServerAuth.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.ServerAuth.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.ServerAuth} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.ServerAuth.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
+  f = message.getToken();
+  if (f.length > 0) {
+    writer.writeString(
+      1,
+      f
+    );
+  }
+};
+
+
+/**
+ * optional string token = 1;
+ * @return {string}
+ */
+proto.gigamessages.ServerAuth.prototype.getToken = function() {
+  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
-/** @param {boolean} value */
-proto.gigamessages.GetLastLoginDataResponse.prototype.setIstokenvalid = function(value) {
-  jspb.Message.setProto3BooleanField(this, 6, value);
+/** @param {string} value */
+proto.gigamessages.ServerAuth.prototype.setToken = function(value) {
+  jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
@@ -4856,12 +15018,12 @@ proto.gigamessages.GetLastLoginDataResponse.prototype.setIstokenvalid = function
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.SearchNotes = function(opt_data) {
+proto.gigamessages.ServerCommand = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.SearchNotes, jspb.Message);
+goog.inherits(proto.gigamessages.ServerCommand, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.SearchNotes.displayName = 'proto.gigamessages.SearchNotes';
+  proto.gigamessages.ServerCommand.displayName = 'proto.gigamessages.ServerCommand';
 }
 
 
@@ -4876,8 +15038,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.SearchNotes.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.SearchNotes.toObject(opt_includeInstance, this);
+proto.gigamessages.ServerCommand.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerCommand.toObject(opt_includeInstance, this);
 };
 
 
@@ -4886,14 +15048,14 @@ proto.gigamessages.SearchNotes.prototype.toObject = function(opt_includeInstance
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.SearchNotes} msg The msg instance to transform.
+ * @param {!proto.gigamessages.ServerCommand} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.SearchNotes.toObject = function(includeInstance, msg) {
+proto.gigamessages.ServerCommand.toObject = function(includeInstance, msg) {
   var f, obj = {
-    query: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    folderid: jspb.Message.getFieldWithDefault(msg, 2, "")
+    index: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    data: msg.getData_asB64()
   };
 
   if (includeInstance) {
@@ -4907,23 +15069,23 @@ proto.gigamessages.SearchNotes.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.SearchNotes}
+ * @return {!proto.gigamessages.ServerCommand}
  */
-proto.gigamessages.SearchNotes.deserializeBinary = function(bytes) {
+proto.gigamessages.ServerCommand.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.SearchNotes;
-  return proto.gigamessages.SearchNotes.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.ServerCommand;
+  return proto.gigamessages.ServerCommand.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.SearchNotes} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.ServerCommand} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.SearchNotes}
+ * @return {!proto.gigamessages.ServerCommand}
  */
-proto.gigamessages.SearchNotes.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.ServerCommand.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -4931,12 +15093,12 @@ proto.gigamessages.SearchNotes.deserializeBinaryFromReader = function(msg, reade
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setQuery(value);
+      var value = /** @type {number} */ (reader.readInt32());
+      msg.setIndex(value);
       break;
     case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setData(value);
       break;
     default:
       reader.skipField();
@@ -4951,9 +15113,9 @@ proto.gigamessages.SearchNotes.deserializeBinaryFromReader = function(msg, reade
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.SearchNotes.prototype.serializeBinary = function() {
+proto.gigamessages.ServerCommand.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.SearchNotes.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.ServerCommand.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -4961,22 +15123,22 @@ proto.gigamessages.SearchNotes.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.SearchNotes} message
+ * @param {!proto.gigamessages.ServerCommand} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.SearchNotes.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.ServerCommand.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getQuery();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getIndex();
+  if (f !== 0) {
+    writer.writeInt32(
       1,
       f
     );
   }
-  f = message.getFolderid();
+  f = message.getData_asU8();
   if (f.length > 0) {
-    writer.writeString(
+    writer.writeBytes(
       2,
       f
     );
@@ -4985,32 +15147,56 @@ proto.gigamessages.SearchNotes.serializeBinaryToWriter = function(message, write
 
 
 /**
- * optional string query = 1;
- * @return {string}
+ * optional int32 index = 1;
+ * @return {number}
  */
-proto.gigamessages.SearchNotes.prototype.getQuery = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.ServerCommand.prototype.getIndex = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.SearchNotes.prototype.setQuery = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/** @param {number} value */
+proto.gigamessages.ServerCommand.prototype.setIndex = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
 /**
- * optional string folderId = 2;
+ * optional bytes data = 2;
+ * @return {!(string|Uint8Array)}
+ */
+proto.gigamessages.ServerCommand.prototype.getData = function() {
+  return /** @type {!(string|Uint8Array)} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * optional bytes data = 2;
+ * This is a type-conversion wrapper around `getData()`
  * @return {string}
  */
-proto.gigamessages.SearchNotes.prototype.getFolderid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+proto.gigamessages.ServerCommand.prototype.getData_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getData()));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.SearchNotes.prototype.setFolderid = function(value) {
-  jspb.Message.setProto3StringField(this, 2, value);
+/**
+ * optional bytes data = 2;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getData()`
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.ServerCommand.prototype.getData_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getData()));
+};
+
+
+/** @param {!(string|Uint8Array)} value */
+proto.gigamessages.ServerCommand.prototype.setData = function(value) {
+  jspb.Message.setProto3BytesField(this, 2, value);
 };
 
 
@@ -5025,12 +15211,12 @@ proto.gigamessages.SearchNotes.prototype.setFolderid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.UpdateFolder = function(opt_data) {
+proto.gigamessages.ServerSubscribe = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.UpdateFolder, jspb.Message);
+goog.inherits(proto.gigamessages.ServerSubscribe, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.UpdateFolder.displayName = 'proto.gigamessages.UpdateFolder';
+  proto.gigamessages.ServerSubscribe.displayName = 'proto.gigamessages.ServerSubscribe';
 }
 
 
@@ -5045,8 +15231,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.UpdateFolder.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.UpdateFolder.toObject(opt_includeInstance, this);
+proto.gigamessages.ServerSubscribe.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerSubscribe.toObject(opt_includeInstance, this);
 };
 
 
@@ -5055,16 +15241,13 @@ proto.gigamessages.UpdateFolder.prototype.toObject = function(opt_includeInstanc
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.UpdateFolder} msg The msg instance to transform.
+ * @param {!proto.gigamessages.ServerSubscribe} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.UpdateFolder.toObject = function(includeInstance, msg) {
+proto.gigamessages.ServerSubscribe.toObject = function(includeInstance, msg) {
   var f, obj = {
-    id: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    parentid: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    title: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    level: jspb.Message.getFieldWithDefault(msg, 4, 0)
+    sinceseq: jspb.Message.getFieldWithDefault(msg, 1, 0)
   };
 
   if (includeInstance) {
@@ -5078,23 +15261,23 @@ proto.gigamessages.UpdateFolder.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.UpdateFolder}
+ * @return {!proto.gigamessages.ServerSubscribe}
  */
-proto.gigamessages.UpdateFolder.deserializeBinary = function(bytes) {
+proto.gigamessages.ServerSubscribe.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.UpdateFolder;
-  return proto.gigamessages.UpdateFolder.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.ServerSubscribe;
+  return proto.gigamessages.ServerSubscribe.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.UpdateFolder} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.ServerSubscribe} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.UpdateFolder}
+ * @return {!proto.gigamessages.ServerSubscribe}
  */
-proto.gigamessages.UpdateFolder.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.ServerSubscribe.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -5102,20 +15285,8 @@ proto.gigamessages.UpdateFolder.deserializeBinaryFromReader = function(msg, read
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setId(value);
-      break;
-    case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setParentid(value);
-      break;
-    case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
-      break;
-    case 4:
-      var value = /** @type {number} */ (reader.readInt32());
-      msg.setLevel(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setSinceseq(value);
       break;
     default:
       reader.skipField();
@@ -5130,9 +15301,9 @@ proto.gigamessages.UpdateFolder.deserializeBinaryFromReader = function(msg, read
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.UpdateFolder.prototype.serializeBinary = function() {
+proto.gigamessages.ServerSubscribe.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.UpdateFolder.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.ServerSubscribe.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5140,37 +15311,16 @@ proto.gigamessages.UpdateFolder.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.UpdateFolder} message
+ * @param {!proto.gigamessages.ServerSubscribe} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.UpdateFolder.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.ServerSubscribe.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getId();
-  if (f.length > 0) {
-    writer.writeString(
-      1,
-      f
-    );
-  }
-  f = message.getParentid();
-  if (f.length > 0) {
-    writer.writeString(
-      2,
-      f
-    );
-  }
-  f = message.getTitle();
-  if (f.length > 0) {
-    writer.writeString(
-      3,
-      f
-    );
-  }
-  f = message.getLevel();
+  f = message.getSinceseq();
   if (f !== 0) {
-    writer.writeInt32(
-      4,
+    writer.writeUint64(
+      1,
       f
     );
   }
@@ -5178,62 +15328,133 @@ proto.gigamessages.UpdateFolder.serializeBinaryToWriter = function(message, writ
 
 
 /**
- * optional string id = 1;
- * @return {string}
+ * optional uint64 sinceSeq = 1;
+ * @return {number}
  */
-proto.gigamessages.UpdateFolder.prototype.getId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.ServerSubscribe.prototype.getSinceseq = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.UpdateFolder.prototype.setId = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/** @param {number} value */
+proto.gigamessages.ServerSubscribe.prototype.setSinceseq = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
 };
 
 
+
 /**
- * optional string parentId = 2;
- * @return {string}
+ * Generated by JsPbCodeGenerator.
+ * @param {Array=} opt_data Optional initial data array, typically from a
+ * server response, or constructed directly in Javascript. The array is used
+ * in place and becomes part of the constructed object. It is not cloned.
+ * If no data is provided, the constructed object will be empty, but still
+ * valid.
+ * @extends {jspb.Message}
+ * @constructor
  */
-proto.gigamessages.UpdateFolder.prototype.getParentid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+proto.gigamessages.ServerUnsubscribe = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
+goog.inherits(proto.gigamessages.ServerUnsubscribe, jspb.Message);
+if (goog.DEBUG && !COMPILED) {
+  proto.gigamessages.ServerUnsubscribe.displayName = 'proto.gigamessages.ServerUnsubscribe';
+}
 
 
-/** @param {string} value */
-proto.gigamessages.UpdateFolder.prototype.setParentid = function(value) {
-  jspb.Message.setProto3StringField(this, 2, value);
+if (jspb.Message.GENERATE_TO_OBJECT) {
+/**
+ * Creates an object representation of this proto suitable for use in Soy templates.
+ * Field names that are reserved in JavaScript and will be renamed to pb_name.
+ * To access a reserved field use, foo.pb_<name>, eg, foo.pb_default.
+ * For the list of reserved names please see:
+ *     com.google.apps.jspb.JsClassTemplate.JS_RESERVED_WORDS.
+ * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
+ *     for transitional soy proto support: http://goto/soy-param-migration
+ * @return {!Object}
+ */
+proto.gigamessages.ServerUnsubscribe.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerUnsubscribe.toObject(opt_includeInstance, this);
 };
 
 
 /**
- * optional string title = 3;
- * @return {string}
+ * Static version of the {@see toObject} method.
+ * @param {boolean|undefined} includeInstance Whether to include the JSPB
+ *     instance for transitional soy proto support:
+ *     http://goto/soy-param-migration
+ * @param {!proto.gigamessages.ServerUnsubscribe} msg The msg instance to transform.
+ * @return {!Object}
+ * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.UpdateFolder.prototype.getTitle = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+proto.gigamessages.ServerUnsubscribe.toObject = function(includeInstance, msg) {
+  var f, obj = {
+
+  };
+
+  if (includeInstance) {
+    obj.$jspbMessageInstance = msg;
+  }
+  return obj;
 };
+}
 
 
-/** @param {string} value */
-proto.gigamessages.UpdateFolder.prototype.setTitle = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
+/**
+ * Deserializes binary data (in protobuf wire format).
+ * @param {jspb.ByteSource} bytes The bytes to deserialize.
+ * @return {!proto.gigamessages.ServerUnsubscribe}
+ */
+proto.gigamessages.ServerUnsubscribe.deserializeBinary = function(bytes) {
+  var reader = new jspb.BinaryReader(bytes);
+  var msg = new proto.gigamessages.ServerUnsubscribe;
+  return proto.gigamessages.ServerUnsubscribe.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
- * optional int32 level = 4;
- * @return {number}
+ * Deserializes binary data (in protobuf wire format) from the
+ * given reader into the given message object.
+ * @param {!proto.gigamessages.ServerUnsubscribe} msg The message object to deserialize into.
+ * @param {!jspb.BinaryReader} reader The BinaryReader to use.
+ * @return {!proto.gigamessages.ServerUnsubscribe}
  */
-proto.gigamessages.UpdateFolder.prototype.getLevel = function() {
-  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 4, 0));
+proto.gigamessages.ServerUnsubscribe.deserializeBinaryFromReader = function(msg, reader) {
+  while (reader.nextField()) {
+    if (reader.isEndGroup()) {
+      break;
+    }
+    var field = reader.getFieldNumber();
+    switch (field) {
+    default:
+      reader.skipField();
+      break;
+    }
+  }
+  return msg;
 };
 
 
-/** @param {number} value */
-proto.gigamessages.UpdateFolder.prototype.setLevel = function(value) {
-  jspb.Message.setProto3IntField(this, 4, value);
+/**
+ * Serializes the message to binary data (in protobuf wire format).
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.ServerUnsubscribe.prototype.serializeBinary = function() {
+  var writer = new jspb.BinaryWriter();
+  proto.gigamessages.ServerUnsubscribe.serializeBinaryToWriter(this, writer);
+  return writer.getResultBuffer();
+};
+
+
+/**
+ * Serializes the given message to binary data (in protobuf wire
+ * format), writing to the given BinaryWriter.
+ * @param {!proto.gigamessages.ServerUnsubscribe} message
+ * @param {!jspb.BinaryWriter} writer
+ * @suppress {unusedLocalVariables} f is only used for nested messages
+ */
+proto.gigamessages.ServerUnsubscribe.serializeBinaryToWriter = function(message, writer) {
+  var f = undefined;
 };
 
 
@@ -5248,13 +15469,39 @@ proto.gigamessages.UpdateFolder.prototype.setLevel = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.UpdateNote = function(opt_data) {
-  jspb.Message.initialize(this, opt_data, 0, -1, null, null);
+proto.gigamessages.ServerMessage = function(opt_data) {
+  jspb.Message.initialize(this, opt_data, 0, -1, null, proto.gigamessages.ServerMessage.oneofGroups_);
 };
-goog.inherits(proto.gigamessages.UpdateNote, jspb.Message);
+goog.inherits(proto.gigamessages.ServerMessage, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.UpdateNote.displayName = 'proto.gigamessages.UpdateNote';
+  proto.gigamessages.ServerMessage.displayName = 'proto.gigamessages.ServerMessage';
 }
+/**
+ * Oneof group definitions for this message. Each group defines the field
+ * numbers belonging to that group. When of these fields' value is set, all
+ * other fields in the group are cleared. During deserialization, if multiple
+ * fields are encountered for a group, only the last value is retained.
+ * @private {!Array<!Array<number>>}
+ * @const
+ */
+proto.gigamessages.ServerMessage.oneofGroups_ = [[1,2]];
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.ServerMessage.BodyCase = {
+  BODY_NOT_SET: 0,
+  REPLY: 1,
+  EVENT: 2
+};
+
+/**
+ * @return {proto.gigamessages.ServerMessage.BodyCase}
+ */
+proto.gigamessages.ServerMessage.prototype.getBodyCase = function() {
+  return /** @type {proto.gigamessages.ServerMessage.BodyCase} */(jspb.Message.computeOneofCase(this, proto.gigamessages.ServerMessage.oneofGroups_[0]));
+};
+
 
 
 if (jspb.Message.GENERATE_TO_OBJECT) {
@@ -5267,9 +15514,9 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  * @param {boolean=} opt_includeInstance Whether to include the JSPB instance
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
- */
-proto.gigamessages.UpdateNote.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.UpdateNote.toObject(opt_includeInstance, this);
+ */
+proto.gigamessages.ServerMessage.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerMessage.toObject(opt_includeInstance, this);
 };
 
 
@@ -5278,16 +15525,14 @@ proto.gigamessages.UpdateNote.prototype.toObject = function(opt_includeInstance)
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.UpdateNote} msg The msg instance to transform.
+ * @param {!proto.gigamessages.ServerMessage} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.UpdateNote.toObject = function(includeInstance, msg) {
+proto.gigamessages.ServerMessage.toObject = function(includeInstance, msg) {
   var f, obj = {
-    id: jspb.Message.getFieldWithDefault(msg, 1, ""),
-    folderid: jspb.Message.getFieldWithDefault(msg, 2, ""),
-    title: jspb.Message.getFieldWithDefault(msg, 3, ""),
-    text: jspb.Message.getFieldWithDefault(msg, 4, "")
+    reply: (f = msg.getReply()) && proto.gigamessages.ServerReply.toObject(includeInstance, f),
+    event: (f = msg.getEvent()) && proto.gigamessages.ServerEvent.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -5301,23 +15546,23 @@ proto.gigamessages.UpdateNote.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.UpdateNote}
+ * @return {!proto.gigamessages.ServerMessage}
  */
-proto.gigamessages.UpdateNote.deserializeBinary = function(bytes) {
+proto.gigamessages.ServerMessage.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.UpdateNote;
-  return proto.gigamessages.UpdateNote.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.ServerMessage;
+  return proto.gigamessages.ServerMessage.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.UpdateNote} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.ServerMessage} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.UpdateNote}
+ * @return {!proto.gigamessages.ServerMessage}
  */
-proto.gigamessages.UpdateNote.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.ServerMessage.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -5325,20 +15570,14 @@ proto.gigamessages.UpdateNote.deserializeBinaryFromReader = function(msg, reader
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setId(value);
+      var value = new proto.gigamessages.ServerReply;
+      reader.readMessage(value,proto.gigamessages.ServerReply.deserializeBinaryFromReader);
+      msg.setReply(value);
       break;
     case 2:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setFolderid(value);
-      break;
-    case 3:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setTitle(value);
-      break;
-    case 4:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setText(value);
+      var value = new proto.gigamessages.ServerEvent;
+      reader.readMessage(value,proto.gigamessages.ServerEvent.deserializeBinaryFromReader);
+      msg.setEvent(value);
       break;
     default:
       reader.skipField();
@@ -5353,9 +15592,9 @@ proto.gigamessages.UpdateNote.deserializeBinaryFromReader = function(msg, reader
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.UpdateNote.prototype.serializeBinary = function() {
+proto.gigamessages.ServerMessage.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.UpdateNote.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.ServerMessage.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5363,100 +15602,88 @@ proto.gigamessages.UpdateNote.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.UpdateNote} message
+ * @param {!proto.gigamessages.ServerMessage} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.UpdateNote.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.ServerMessage.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getId();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getReply();
+  if (f != null) {
+    writer.writeMessage(
       1,
-      f
+      f,
+      proto.gigamessages.ServerReply.serializeBinaryToWriter
     );
   }
-  f = message.getFolderid();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getEvent();
+  if (f != null) {
+    writer.writeMessage(
       2,
-      f
-    );
-  }
-  f = message.getTitle();
-  if (f.length > 0) {
-    writer.writeString(
-      3,
-      f
-    );
-  }
-  f = message.getText();
-  if (f.length > 0) {
-    writer.writeString(
-      4,
-      f
+      f,
+      proto.gigamessages.ServerEvent.serializeBinaryToWriter
     );
   }
 };
 
 
 /**
- * optional string id = 1;
- * @return {string}
+ * optional ServerReply reply = 1;
+ * @return {?proto.gigamessages.ServerReply}
  */
-proto.gigamessages.UpdateNote.prototype.getId = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.ServerMessage.prototype.getReply = function() {
+  return /** @type{?proto.gigamessages.ServerReply} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.ServerReply, 1));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.UpdateNote.prototype.setId = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/** @param {?proto.gigamessages.ServerReply|undefined} value */
+proto.gigamessages.ServerMessage.prototype.setReply = function(value) {
+  jspb.Message.setOneofWrapperField(this, 1, proto.gigamessages.ServerMessage.oneofGroups_[0], value);
 };
 
 
-/**
- * optional string folderId = 2;
- * @return {string}
- */
-proto.gigamessages.UpdateNote.prototype.getFolderid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+proto.gigamessages.ServerMessage.prototype.clearReply = function() {
+  this.setReply(undefined);
 };
 
 
-/** @param {string} value */
-proto.gigamessages.UpdateNote.prototype.setFolderid = function(value) {
-  jspb.Message.setProto3StringField(this, 2, value);
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.ServerMessage.prototype.hasReply = function() {
+  return jspb.Message.getField(this, 1) != null;
 };
 
 
 /**
- * optional string title = 3;
- * @return {string}
+ * optional ServerEvent event = 2;
+ * @return {?proto.gigamessages.ServerEvent}
  */
-proto.gigamessages.UpdateNote.prototype.getTitle = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 3, ""));
+proto.gigamessages.ServerMessage.prototype.getEvent = function() {
+  return /** @type{?proto.gigamessages.ServerEvent} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.ServerEvent, 2));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.UpdateNote.prototype.setTitle = function(value) {
-  jspb.Message.setProto3StringField(this, 3, value);
+/** @param {?proto.gigamessages.ServerEvent|undefined} value */
+proto.gigamessages.ServerMessage.prototype.setEvent = function(value) {
+  jspb.Message.setOneofWrapperField(this, 2, proto.gigamessages.ServerMessage.oneofGroups_[0], value);
 };
 
 
-/**
- * optional string text = 4;
- * @return {string}
- */
-proto.gigamessages.UpdateNote.prototype.getText = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 4, ""));
+proto.gigamessages.ServerMessage.prototype.clearEvent = function() {
+  this.setEvent(undefined);
 };
 
 
-/** @param {string} value */
-proto.gigamessages.UpdateNote.prototype.setText = function(value) {
-  jspb.Message.setProto3StringField(this, 4, value);
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.ServerMessage.prototype.hasEvent = function() {
+  return jspb.Message.getField(this, 2) != null;
 };
 
 
@@ -5471,12 +15698,12 @@ proto.gigamessages.UpdateNote.prototype.setText = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.AddToFavorites = function(opt_data) {
+proto.gigamessages.ServerReply = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.AddToFavorites, jspb.Message);
+goog.inherits(proto.gigamessages.ServerReply, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.AddToFavorites.displayName = 'proto.gigamessages.AddToFavorites';
+  proto.gigamessages.ServerReply.displayName = 'proto.gigamessages.ServerReply';
 }
 
 
@@ -5491,8 +15718,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.AddToFavorites.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.AddToFavorites.toObject(opt_includeInstance, this);
+proto.gigamessages.ServerReply.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerReply.toObject(opt_includeInstance, this);
 };
 
 
@@ -5501,13 +15728,15 @@ proto.gigamessages.AddToFavorites.prototype.toObject = function(opt_includeInsta
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.AddToFavorites} msg The msg instance to transform.
+ * @param {!proto.gigamessages.ServerReply} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.AddToFavorites.toObject = function(includeInstance, msg) {
+proto.gigamessages.ServerReply.toObject = function(includeInstance, msg) {
   var f, obj = {
-    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
+    requestid: jspb.Message.getFieldWithDefault(msg, 1, 0),
+    response: msg.getResponse_asB64(),
+    error: (f = msg.getError()) && proto.gigamessages.Error.toObject(includeInstance, f)
   };
 
   if (includeInstance) {
@@ -5521,23 +15750,23 @@ proto.gigamessages.AddToFavorites.toObject = function(includeInstance, msg) {
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.AddToFavorites}
+ * @return {!proto.gigamessages.ServerReply}
  */
-proto.gigamessages.AddToFavorites.deserializeBinary = function(bytes) {
+proto.gigamessages.ServerReply.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.AddToFavorites;
-  return proto.gigamessages.AddToFavorites.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.ServerReply;
+  return proto.gigamessages.ServerReply.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.AddToFavorites} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.ServerReply} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.AddToFavorites}
+ * @return {!proto.gigamessages.ServerReply}
  */
-proto.gigamessages.AddToFavorites.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.ServerReply.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -5545,8 +15774,17 @@ proto.gigamessages.AddToFavorites.deserializeBinaryFromReader = function(msg, re
     var field = reader.getFieldNumber();
     switch (field) {
     case 1:
-      var value = /** @type {string} */ (reader.readString());
-      msg.setNoteid(value);
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setRequestid(value);
+      break;
+    case 2:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setResponse(value);
+      break;
+    case 3:
+      var value = new proto.gigamessages.Error;
+      reader.readMessage(value,proto.gigamessages.Error.deserializeBinaryFromReader);
+      msg.setError(value);
       break;
     default:
       reader.skipField();
@@ -5561,9 +15799,9 @@ proto.gigamessages.AddToFavorites.deserializeBinaryFromReader = function(msg, re
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.AddToFavorites.prototype.serializeBinary = function() {
+proto.gigamessages.ServerReply.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.AddToFavorites.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.ServerReply.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5571,34 +15809,118 @@ proto.gigamessages.AddToFavorites.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.AddToFavorites} message
+ * @param {!proto.gigamessages.ServerReply} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.AddToFavorites.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.ServerReply.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getNoteid();
-  if (f.length > 0) {
-    writer.writeString(
+  f = message.getRequestid();
+  if (f !== 0) {
+    writer.writeUint64(
       1,
       f
     );
   }
+  f = message.getResponse_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      2,
+      f
+    );
+  }
+  f = message.getError();
+  if (f != null) {
+    writer.writeMessage(
+      3,
+      f,
+      proto.gigamessages.Error.serializeBinaryToWriter
+    );
+  }
 };
 
 
 /**
- * optional string noteId = 1;
+ * optional uint64 requestId = 1;
+ * @return {number}
+ */
+proto.gigamessages.ServerReply.prototype.getRequestid = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 1, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.ServerReply.prototype.setRequestid = function(value) {
+  jspb.Message.setProto3IntField(this, 1, value);
+};
+
+
+/**
+ * optional bytes response = 2;
+ * @return {!(string|Uint8Array)}
+ */
+proto.gigamessages.ServerReply.prototype.getResponse = function() {
+  return /** @type {!(string|Uint8Array)} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * optional bytes response = 2;
+ * This is a type-conversion wrapper around `getResponse()`
  * @return {string}
  */
-proto.gigamessages.AddToFavorites.prototype.getNoteid = function() {
-  return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
+proto.gigamessages.ServerReply.prototype.getResponse_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getResponse()));
 };
 
 
-/** @param {string} value */
-proto.gigamessages.AddToFavorites.prototype.setNoteid = function(value) {
-  jspb.Message.setProto3StringField(this, 1, value);
+/**
+ * optional bytes response = 2;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getResponse()`
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.ServerReply.prototype.getResponse_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getResponse()));
+};
+
+
+/** @param {!(string|Uint8Array)} value */
+proto.gigamessages.ServerReply.prototype.setResponse = function(value) {
+  jspb.Message.setProto3BytesField(this, 2, value);
+};
+
+
+/**
+ * optional Error error = 3;
+ * @return {?proto.gigamessages.Error}
+ */
+proto.gigamessages.ServerReply.prototype.getError = function() {
+  return /** @type{?proto.gigamessages.Error} */ (
+    jspb.Message.getWrapperField(this, proto.gigamessages.Error, 3));
+};
+
+
+/** @param {?proto.gigamessages.Error|undefined} value */
+proto.gigamessages.ServerReply.prototype.setError = function(value) {
+  jspb.Message.setWrapperField(this, 3, value);
+};
+
+
+proto.gigamessages.ServerReply.prototype.clearError = function() {
+  this.setError(undefined);
+};
+
+
+/**
+ * Returns whether this field is set.
+ * @return {boolean}
+ */
+proto.gigamessages.ServerReply.prototype.hasError = function() {
+  return jspb.Message.getField(this, 3) != null;
 };
 
 
@@ -5613,12 +15935,12 @@ proto.gigamessages.AddToFavorites.prototype.setNoteid = function(value) {
  * @extends {jspb.Message}
  * @constructor
  */
-proto.gigamessages.RemoveFromFavorites = function(opt_data) {
+proto.gigamessages.ServerEvent = function(opt_data) {
   jspb.Message.initialize(this, opt_data, 0, -1, null, null);
 };
-goog.inherits(proto.gigamessages.RemoveFromFavorites, jspb.Message);
+goog.inherits(proto.gigamessages.ServerEvent, jspb.Message);
 if (goog.DEBUG && !COMPILED) {
-  proto.gigamessages.RemoveFromFavorites.displayName = 'proto.gigamessages.RemoveFromFavorites';
+  proto.gigamessages.ServerEvent.displayName = 'proto.gigamessages.ServerEvent';
 }
 
 
@@ -5633,8 +15955,8 @@ if (jspb.Message.GENERATE_TO_OBJECT) {
  *     for transitional soy proto support: http://goto/soy-param-migration
  * @return {!Object}
  */
-proto.gigamessages.RemoveFromFavorites.prototype.toObject = function(opt_includeInstance) {
-  return proto.gigamessages.RemoveFromFavorites.toObject(opt_includeInstance, this);
+proto.gigamessages.ServerEvent.prototype.toObject = function(opt_includeInstance) {
+  return proto.gigamessages.ServerEvent.toObject(opt_includeInstance, this);
 };
 
 
@@ -5643,13 +15965,15 @@ proto.gigamessages.RemoveFromFavorites.prototype.toObject = function(opt_include
  * @param {boolean|undefined} includeInstance Whether to include the JSPB
  *     instance for transitional soy proto support:
  *     http://goto/soy-param-migration
- * @param {!proto.gigamessages.RemoveFromFavorites} msg The msg instance to transform.
+ * @param {!proto.gigamessages.ServerEvent} msg The msg instance to transform.
  * @return {!Object}
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.RemoveFromFavorites.toObject = function(includeInstance, msg) {
+proto.gigamessages.ServerEvent.toObject = function(includeInstance, msg) {
   var f, obj = {
-    noteid: jspb.Message.getFieldWithDefault(msg, 1, "")
+    name: jspb.Message.getFieldWithDefault(msg, 1, ""),
+    data: msg.getData_asB64(),
+    seq: jspb.Message.getFieldWithDefault(msg, 3, 0)
   };
 
   if (includeInstance) {
@@ -5663,23 +15987,23 @@ proto.gigamessages.RemoveFromFavorites.toObject = function(includeInstance, msg)
 /**
  * Deserializes binary data (in protobuf wire format).
  * @param {jspb.ByteSource} bytes The bytes to deserialize.
- * @return {!proto.gigamessages.RemoveFromFavorites}
+ * @return {!proto.gigamessages.ServerEvent}
  */
-proto.gigamessages.RemoveFromFavorites.deserializeBinary = function(bytes) {
+proto.gigamessages.ServerEvent.deserializeBinary = function(bytes) {
   var reader = new jspb.BinaryReader(bytes);
-  var msg = new proto.gigamessages.RemoveFromFavorites;
-  return proto.gigamessages.RemoveFromFavorites.deserializeBinaryFromReader(msg, reader);
+  var msg = new proto.gigamessages.ServerEvent;
+  return proto.gigamessages.ServerEvent.deserializeBinaryFromReader(msg, reader);
 };
 
 
 /**
  * Deserializes binary data (in protobuf wire format) from the
  * given reader into the given message object.
- * @param {!proto.gigamessages.RemoveFromFavorites} msg The message object to deserialize into.
+ * @param {!proto.gigamessages.ServerEvent} msg The message object to deserialize into.
  * @param {!jspb.BinaryReader} reader The BinaryReader to use.
- * @return {!proto.gigamessages.RemoveFromFavorites}
+ * @return {!proto.gigamessages.ServerEvent}
  */
-proto.gigamessages.RemoveFromFavorites.deserializeBinaryFromReader = function(msg, reader) {
+proto.gigamessages.ServerEvent.deserializeBinaryFromReader = function(msg, reader) {
   while (reader.nextField()) {
     if (reader.isEndGroup()) {
       break;
@@ -5688,7 +16012,15 @@ proto.gigamessages.RemoveFromFavorites.deserializeBinaryFromReader = function(ms
     switch (field) {
     case 1:
       var value = /** @type {string} */ (reader.readString());
-      msg.setNoteid(value);
+      msg.setName(value);
+      break;
+    case 2:
+      var value = /** @type {!Uint8Array} */ (reader.readBytes());
+      msg.setData(value);
+      break;
+    case 3:
+      var value = /** @type {number} */ (reader.readUint64());
+      msg.setSeq(value);
       break;
     default:
       reader.skipField();
@@ -5703,9 +16035,9 @@ proto.gigamessages.RemoveFromFavorites.deserializeBinaryFromReader = function(ms
  * Serializes the message to binary data (in protobuf wire format).
  * @return {!Uint8Array}
  */
-proto.gigamessages.RemoveFromFavorites.prototype.serializeBinary = function() {
+proto.gigamessages.ServerEvent.prototype.serializeBinary = function() {
   var writer = new jspb.BinaryWriter();
-  proto.gigamessages.RemoveFromFavorites.serializeBinaryToWriter(this, writer);
+  proto.gigamessages.ServerEvent.serializeBinaryToWriter(this, writer);
   return writer.getResultBuffer();
 };
 
@@ -5713,35 +16045,143 @@ proto.gigamessages.RemoveFromFavorites.prototype.serializeBinary = function() {
 /**
  * Serializes the given message to binary data (in protobuf wire
  * format), writing to the given BinaryWriter.
- * @param {!proto.gigamessages.RemoveFromFavorites} message
+ * @param {!proto.gigamessages.ServerEvent} message
  * @param {!jspb.BinaryWriter} writer
  * @suppress {unusedLocalVariables} f is only used for nested messages
  */
-proto.gigamessages.RemoveFromFavorites.serializeBinaryToWriter = function(message, writer) {
+proto.gigamessages.ServerEvent.serializeBinaryToWriter = function(message, writer) {
   var f = undefined;
-  f = message.getNoteid();
+  f = message.getName();
   if (f.length > 0) {
     writer.writeString(
       1,
       f
     );
   }
+  f = message.getData_asU8();
+  if (f.length > 0) {
+    writer.writeBytes(
+      2,
+      f
+    );
+  }
+  f = message.getSeq();
+  if (f !== 0) {
+    writer.writeUint64(
+      3,
+      f
+    );
+  }
 };
 
 
 /**
- * optional string noteId = 1;
+ * optional string name = 1;
  * @return {string}
  */
-proto.gigamessages.RemoveFromFavorites.prototype.getNoteid = function() {
+proto.gigamessages.ServerEvent.prototype.getName = function() {
   return /** @type {string} */ (jspb.Message.getFieldWithDefault(this, 1, ""));
 };
 
 
 /** @param {string} value */
-proto.gigamessages.RemoveFromFavorites.prototype.setNoteid = function(value) {
+proto.gigamessages.ServerEvent.prototype.setName = function(value) {
   jspb.Message.setProto3StringField(this, 1, value);
 };
 
 
+/**
+ * optional bytes data = 2;
+ * @return {!(string|Uint8Array)}
+ */
+proto.gigamessages.ServerEvent.prototype.getData = function() {
+  return /** @type {!(string|Uint8Array)} */ (jspb.Message.getFieldWithDefault(this, 2, ""));
+};
+
+
+/**
+ * optional bytes data = 2;
+ * This is a type-conversion wrapper around `getData()`
+ * @return {string}
+ */
+proto.gigamessages.ServerEvent.prototype.getData_asB64 = function() {
+  return /** @type {string} */ (jspb.Message.bytesAsB64(
+      this.getData()));
+};
+
+
+/**
+ * optional bytes data = 2;
+ * Note that Uint8Array is not supported on all browsers.
+ * @see http://caniuse.com/Uint8Array
+ * This is a type-conversion wrapper around `getData()`
+ * @return {!Uint8Array}
+ */
+proto.gigamessages.ServerEvent.prototype.getData_asU8 = function() {
+  return /** @type {!Uint8Array} */ (jspb.Message.bytesAsU8(
+      this.getData()));
+};
+
+
+/** @param {!(string|Uint8Array)} value */
+proto.gigamessages.ServerEvent.prototype.setData = function(value) {
+  jspb.Message.setProto3BytesField(this, 2, value);
+};
+
+
+/**
+ * optional uint64 seq = 3;
+ * @return {number}
+ */
+proto.gigamessages.ServerEvent.prototype.getSeq = function() {
+  return /** @type {number} */ (jspb.Message.getFieldWithDefault(this, 3, 0));
+};
+
+
+/** @param {number} value */
+proto.gigamessages.ServerEvent.prototype.setSeq = function(value) {
+  jspb.Message.setProto3IntField(this, 3, value);
+};
+
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.ErrorCode = {
+  NO_ERROR: 0,
+  UNKNOWN_ERROR: 1,
+  AUTH_FAILED: 2,
+  VALIDATION_FAILED: 3,
+  NETWORK_ERROR: 4,
+  STORAGE_ERROR: 5,
+  NOT_FOUND: 6,
+  SHUT_DOWN: 7,
+  INCOMPATIBLE_VERSION: 8,
+  CANCELLED: 9,
+  TIMED_OUT: 10,
+  ABORTED: 11,
+  BUSY: 12
+};
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.ChangeKind = {
+  CREATED: 0,
+  UPDATED: 1,
+  REMOVED: 2
+};
+
+/**
+ * @enum {number}
+ */
+proto.gigamessages.LogLevel = {
+  OFF: 0,
+  ERROR: 1,
+  WARN: 2,
+  INFO: 3,
+  DEBUG: 4,
+  TRACE: 5
+};
+
 goog.object.extend(exports, proto.gigamessages);
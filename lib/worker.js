@@ -0,0 +1,25 @@
+// Runs the core of a `GiganotesCore` in a process of its own, see
+// lib/index.js. Forked with the API path and data path of the core as
+// arguments: initializes the core, serves it on the socket server in the data
+// path and reports `{ socketPath, tokenPath }`, or `{ error }` if it couldn't,
+// to the parent. Stops once the parent disconnects.
+const giganotes = require('./index');
+
+const [apiPath, dataPath] = process.argv.slice(2);
+
+var start = function() {
+  var response = giganotes.initData(apiPath, dataPath);
+  if (!response.getSuccess()) return { error: response.getError().getMessage() };
+  return giganotes.startServer(dataPath);
+}
+
+try {
+  process.send(start());
+} catch (err) {
+  process.send({ error: err.message });
+}
+
+process.on('disconnect', function() {
+  giganotes.stopServer(dataPath);
+  giganotes.shutdown().then(() => process.exit(0), () => process.exit(1));
+});
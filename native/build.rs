@@ -1,7 +1,15 @@
-extern crate neon_build;
+use std::env;
 
 fn main() {
+    #[cfg(feature = "addon")]
     neon_build::setup(); // must be called in build.rs
 
     // add project-specific build logic here...
+
+    // Rust types for the command protocol shared with lib/messages_pb.js
+    println!("cargo:rerun-if-changed=../protos/messages.proto");
+    prost_build::compile_protos(&["../protos/messages.proto"], &["../protos/"]).unwrap();
+
+    // Reported by `getVersion`
+    println!("cargo:rustc-env=BUILD_PROFILE={}", env::var("PROFILE").unwrap());
 }
@@ -0,0 +1,30 @@
+use std::fs;
+
+// Manifest of the `giganotes-core` path dependency.
+const CORE_MANIFEST: &str = "../../../giganotes-core/Cargo.toml";
+
+// Reads the `version` of the `[package]` section of a Cargo manifest.
+fn package_version(manifest: &str) -> Option<String> {
+    let mut in_package = false;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+        } else if in_package && line.starts_with("version") {
+            let (_, value) = line.split_once('=')?;
+            return Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+fn main() {
+    neon_build::setup(); // must be called in build.rs
+
+    // Reported by `getVersion`
+    println!("cargo:rerun-if-changed={}", CORE_MANIFEST);
+    let core_version = fs::read_to_string(CORE_MANIFEST)
+        .ok()
+        .and_then(|manifest| package_version(&manifest))
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=GIGANOTES_CORE_VERSION={}", core_version);
+}
@@ -0,0 +1,22 @@
+use giganotes_core::core::{handle_async_command, handle_command, WORKER};
+use giganotescore::backend::{self, Backend};
+
+// Makes the bridge run against giganotes-core. Must be called before the
+// bridge runs any command. Installing it again, e.g. when a worker thread
+// loads the module as well, has no effect.
+pub fn install_core() {
+    let core = Backend {
+        handle_command,
+        handle_async_command,
+        events: WORKER.receiver.clone(),
+        version: env!("GIGANOTES_CORE_VERSION"),
+        // giganotes-core pushes its own payloads rather than `CoreEvent`s, so
+        // the bridge can't tell when a synchronization has finished.
+        core_events: false,
+        // giganotes-core has no way to stop its `WORKER`. After a shutdown it
+        // sits idle: the bridge no longer hands it any work, and has waited
+        // for every synchronization to finish.
+        stop: None,
+    };
+    let _ = backend::install(core);
+}
@@ -0,0 +1,8 @@
+// The Node.js module loaded by lib/index.js: the bridge of giganotes-core-js
+// running against giganotes-core.
+mod install;
+
+neon::register_module!(mut m, {
+    install::install_core();
+    giganotescore::addon::register(&mut m)
+});
@@ -0,0 +1,7 @@
+// The `giganotes` command line client, see `giganotescore::cli`.
+mod install;
+
+fn main() {
+    install::install_core();
+    giganotescore::cli::main();
+}
@@ -0,0 +1,37 @@
+use std::sync::Once;
+use std::{env, fs, process};
+
+use giganotescore::backend::handle_command;
+use giganotescore::command::Command;
+use giganotescore::dispatch::{self, encode};
+use giganotescore::messages::{ErrorCode, InitData, ResponseStatus};
+use prost::Message;
+
+// Initializes the core once, with a data path of the fuzzer's own and an API
+// path nothing listens on. Targets skip `InitData` requests, so inputs never
+// reach the network or the file system outside the data path.
+pub fn init() {
+    static INIT: Once = Once::new();
+
+    INIT.call_once(|| {
+        let data_path = env::temp_dir().join(format!("giganotes-fuzz-{}", process::id()));
+        fs::create_dir_all(&data_path).unwrap();
+
+        let init = InitData {
+            data_path: data_path.to_string_lossy().into_owned(),
+            api_path: "http://127.0.0.1:9".to_string(),
+        };
+        let response = dispatch::run(Command::InitData, &encode(&init), handle_command);
+        assert!(dispatch::succeeded(&response), "InitData failed");
+    });
+}
+
+// Every response of the bridge decodes as a `ResponseStatus`, and a failed
+// one carries an error with a code.
+pub fn check_response(response: &[u8]) {
+    let status = ResponseStatus::decode(response).expect("responses decode");
+    if !status.success {
+        let error = status.error.expect("failed responses carry an error");
+        assert_ne!(error.code(), ErrorCode::NoError);
+    }
+}
@@ -0,0 +1,29 @@
+#![no_main]
+
+// Feeds a command index and a payload through the dispatch used by
+// `handleCommand`, like a renderer sending garbage would.
+
+mod common;
+
+use giganotescore::backend::{handle_async_command, handle_command};
+use giganotescore::command::Command;
+use giganotescore::dispatch::{self, CoreEntry};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &[u8]| {
+    let (&index, payload) = match input.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    let command = match Command::from_index(f64::from(index as i8)) {
+        Ok(Command::InitData) | Err(_) => return,
+        Ok(command) => command,
+    };
+    let entry: CoreEntry = match command {
+        Command::Synchronize => handle_async_command,
+        _ => handle_command,
+    };
+
+    common::init();
+    common::check_response(&dispatch::run(command, payload, entry));
+});
@@ -0,0 +1,44 @@
+#![no_main]
+
+// Feeds events the core might push through naming, coalescing and draining.
+// Each byte of the input is the length of the next event.
+
+use giganotescore::dispatch::encode;
+use giganotescore::events::{event_name, Event, EVENT_NAMES};
+use giganotescore::messages::CoreEvent;
+use giganotescore::queue::{EventQueue, Overflow};
+use libfuzzer_sys::fuzz_target;
+use prost::Message;
+
+const CAPACITY: usize = 4;
+
+fuzz_target!(|input: &[u8]| {
+    let queue = EventQueue::new(CAPACITY, Overflow::Coalesce);
+
+    let mut rest = input;
+    let mut seq = 0;
+    while let Some((&len, tail)) = rest.split_first() {
+        let len = (len as usize).min(tail.len());
+        let (data, tail) = tail.split_at(len);
+        rest = tail;
+
+        assert!(EVENT_NAMES.contains(&event_name(data)));
+        if let Ok(event) = CoreEvent::decode(data) {
+            assert_eq!(CoreEvent::decode(&encode(&event)[..]).unwrap(), event);
+        }
+
+        seq += 1;
+        queue.push(Event {
+            seq,
+            data: data.to_vec(),
+            replayed: false,
+        });
+    }
+
+    // At most `CAPACITY` events, preceded by an `eventsDropped` event.
+    let drained = queue.drain();
+    assert!(drained.len() <= CAPACITY + 1);
+    for event in drained {
+        assert!(EVENT_NAMES.contains(&event_name(&event.data)));
+    }
+});
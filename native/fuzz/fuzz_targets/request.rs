@@ -0,0 +1,49 @@
+#![no_main]
+
+// Feeds `Request` envelopes and sequences of them through `handleRequest` and
+// `handleRequests`. The first byte picks which.
+
+mod common;
+
+use giganotescore::envelope;
+use giganotescore::messages::request::Command as RequestCommand;
+use giganotescore::messages::{Request, RequestSequence, RequestSequenceResponse, Response};
+use libfuzzer_sys::fuzz_target;
+use prost::Message;
+
+fuzz_target!(|input: &[u8]| {
+    let (&kind, data) = match input.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+
+    common::init();
+    if kind % 2 == 0 {
+        if Request::decode(data).map_or(false, |request| init_data(&request)) {
+            return;
+        }
+        check(Response::decode(&envelope::handle(data)[..]).expect("responses decode"));
+    } else {
+        let init = |sequence: RequestSequence| sequence.requests.iter().any(init_data);
+        if RequestSequence::decode(data).map_or(false, init) {
+            return;
+        }
+        let response = RequestSequenceResponse::decode(&envelope::handle_sequence(data)[..])
+            .expect("sequence responses decode");
+        if response.error.is_none() {
+            response.responses.into_iter().for_each(check);
+        }
+    }
+});
+
+fn init_data(request: &Request) -> bool {
+    match request.command {
+        Some(RequestCommand::InitData(_)) => true,
+        _ => false,
+    }
+}
+
+// A response carries either the response of its command or an error.
+fn check(response: Response) {
+    assert!(response.body.is_some() || response.error.is_some());
+}
@@ -0,0 +1,655 @@
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use neon::context::{Context, TaskContext};
+use neon::event::EventHandler;
+use neon::object::Object;
+use neon::result::JsResult;
+use neon::task::Task;
+use neon::types::{JsFunction, JsUndefined, JsValue};
+use neon::declare_types;
+use neon::prelude::*;
+use log::LevelFilter;
+
+#[cfg(all(unix, feature = "server"))]
+mod server;
+mod throw;
+
+use crate::backend::{handle_async_command, handle_command};
+use crate::cancel::{self, CancelHandle};
+use crate::command::Command;
+use crate::dispatch::{self, CoreEntry};
+use crate::guard::{catch_panic, install_panic_hook};
+use crate::queue::{EventQueue, Overflow, Queued, DEFAULT_CAPACITY};
+use crate::{envelope, events, lifecycle, logging, metrics, version};
+use throw::{guard, TaskError};
+
+// Default size after which the log file is rotated, 10 MiB.
+const DEFAULT_MAX_LOG_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+// Default number of rotated log files that are kept.
+const DEFAULT_MAX_LOG_FILES: usize = 5;
+
+// Configures logging from an optional options object:
+// `{ level, filters, console, dataPath, file, maxFileSize, maxFiles,
+// forwardLevel }`. `filters` maps module paths to levels, `file` is a
+// relative path resolved inside `dataPath`, which is then required, and
+// records up to `forwardLevel` are emitted to JS as "log" events. Without
+// options everything down to `default_log_level` goes to stdout. May be
+// called again to reconfigure logging.
+fn init_logging<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsString> {
+    let options = match cx.argument_opt(0) {
+        Some(options) if options.is_a::<JsObject>() => {
+            Some(options.downcast_or_throw::<JsObject, _>(cx)?)
+        }
+        _ => None,
+    };
+
+    let mut config = logging::LogConfig {
+        level: default_log_level(),
+        filters: Vec::new(),
+        console: true,
+        file: None,
+        forward_level: LevelFilter::Off,
+    };
+
+    if let Some(options) = options {
+        if let Some(level) = string_option(cx, options, "level")? {
+            config.level = level_filter(cx, &level)?;
+        }
+        if let Some(console) = bool_option(cx, options, "console")? {
+            config.console = console;
+        }
+        if let Some(level) = string_option(cx, options, "forwardLevel")? {
+            config.forward_level = level_filter(cx, &level)?;
+        }
+
+        let filters = options.get(cx, "filters")?;
+        if let Ok(filters) = filters.downcast::<JsObject>() {
+            for module in filters.get_own_property_names(cx)?.to_vec(cx)? {
+                let module = module.downcast_or_throw::<JsString, _>(cx)?.value();
+                let level = filters
+                    .get(cx, module.as_str())?
+                    .downcast_or_throw::<JsString, _>(cx)?
+                    .value();
+                config.filters.push((module, level_filter(cx, &level)?));
+            }
+        }
+
+        if let Some(file) = string_option(cx, options, "file")? {
+            let data_path = match string_option(cx, options, "dataPath")? {
+                Some(data_path) if !data_path.is_empty() => data_path,
+                _ => return cx.throw_type_error("Option \"dataPath\" is required with \"file\""),
+            };
+            let path = logging::log_file_path(Path::new(&data_path), &file)
+                .or_else(|err| cx.throw_type_error(err.to_string()))?;
+            config.file = Some(logging::FileConfig {
+                path,
+                max_size: number_option(cx, options, "maxFileSize")?
+                    .map_or(DEFAULT_MAX_LOG_FILE_SIZE, |size| size as u64),
+                max_files: number_option(cx, options, "maxFiles")?
+                    .map_or(DEFAULT_MAX_LOG_FILES, |count| count as usize),
+            });
+        }
+    }
+
+    logging::configure(config).or_else(|err| cx.throw_error(err.to_string()))?;
+    Ok(cx.string("OK"))
+}
+
+// Level logged without a `level` option: `info` in release builds, which
+// would log too much at `debug`, and `debug` in the others.
+fn default_log_level() -> LevelFilter {
+    if env!("BUILD_PROFILE") == "release" {
+        LevelFilter::Info
+    } else {
+        LevelFilter::Debug
+    }
+}
+
+fn level_filter(cx: &mut FunctionContext, level: &str) -> NeonResult<LevelFilter> {
+    LevelFilter::from_str(level)
+        .or_else(|_| cx.throw_type_error(format!("Unknown log level \"{}\"", level)))
+}
+
+// Reads an optional property of `options`, treating `undefined` and `null` as
+// absent and throwing a `TypeError` for values of the wrong type.
+fn option<'a, C: Context<'a>, V: Value>(
+    cx: &mut C,
+    options: Handle<JsObject>,
+    key: &str,
+) -> NeonResult<Option<Handle<'a, V>>> {
+    let value = options.get(cx, key)?;
+    if value.is_a::<JsUndefined>() || value.is_a::<JsNull>() {
+        return Ok(None);
+    }
+    value
+        .downcast::<V>()
+        .map(Some)
+        .or_else(|_| cx.throw_type_error(format!("Invalid value for option \"{}\"", key)))
+}
+
+fn string_option<'a, C: Context<'a>>(
+    cx: &mut C,
+    options: Handle<JsObject>,
+    key: &str,
+) -> NeonResult<Option<String>> {
+    Ok(option::<_, JsString>(cx, options, key)?.map(|value| value.value()))
+}
+
+fn number_option<'a, C: Context<'a>>(
+    cx: &mut C,
+    options: Handle<JsObject>,
+    key: &str,
+) -> NeonResult<Option<f64>> {
+    Ok(option::<_, JsNumber>(cx, options, key)?.map(|value| value.value()))
+}
+
+fn bool_option<'a, C: Context<'a>>(
+    cx: &mut C,
+    options: Handle<JsObject>,
+    key: &str,
+) -> NeonResult<Option<bool>> {
+    Ok(option::<_, JsBoolean>(cx, options, key)?.map(|value| value.value()))
+}
+
+// Copies `data` into a new `ArrayBuffer`. The legacy neon runtime can't hand
+// memory owned by Rust to JS as an external buffer, so a single `memcpy` into
+// memory owned by V8 is the cheapest way across.
+fn array_buffer<'a, C: Context<'a>>(cx: &mut C, data: &[u8]) -> JsResult<'a, JsArrayBuffer> {
+    let mut buffer = JsArrayBuffer::new(cx, data.len() as u32)?;
+    cx.borrow_mut(&mut buffer, |slice| slice.as_mut_slice::<u8>().copy_from_slice(data));
+    Ok(buffer)
+}
+
+// Copies `length` bytes from Rust into a new `ArrayBuffer`, with
+// `array_buffer` or, if `elementwise` is true, byte by byte like responses
+// were copied before. The baseline bench/buffers.js compares against, only
+// exported by the module running against the fake core.
+#[cfg(feature = "mock-core")]
+fn benchmark_copy<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsArrayBuffer> {
+    let length = cx.argument::<JsNumber>(0)?.value() as usize;
+    let elementwise = cx.argument::<JsBoolean>(1)?.value();
+    let data = vec![b'x'; length];
+    if !elementwise {
+        return array_buffer(cx, &data);
+    }
+
+    let mut buffer = JsArrayBuffer::new(cx, data.len() as u32)?;
+    cx.borrow_mut(&mut buffer, |slice| {
+        let buffer = slice.as_mut_slice::<u8>();
+        for (i, x) in data.iter().enumerate() {
+            buffer[i] = *x;
+        }
+    });
+    Ok(buffer)
+}
+
+// Returns the encoded `VersionInfo` of the native module.
+fn get_version<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsArrayBuffer> {
+    array_buffer(cx, &dispatch::encode(&version::version_info()))
+}
+
+// Checks the encoded `Handshake` of a client. Returns an encoded
+// `HandshakeResponse`, which fails if the client's protocol version isn't
+// supported.
+fn handshake<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsArrayBuffer> {
+    let b: Handle<JsArrayBuffer> = cx.argument(0)?;
+    let v = cx.borrow(&b, |slice| version::handshake(slice.as_slice::<u8>()));
+
+    array_buffer(cx, &v)
+}
+
+// Returns the metrics collected since the module was loaded, as an encoded
+// `Metrics` message, or as Prometheus text if the `format` argument is
+// "prometheus".
+fn get_metrics<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsValue> {
+    let format = match cx.argument_opt(0) {
+        Some(format) => Some(format.downcast_or_throw::<JsString, _>(cx)?.value()),
+        None => None,
+    };
+
+    match format.as_deref() {
+        Some("prometheus") => Ok(cx.string(metrics::prometheus()).upcast()),
+        None | Some("binary") => {
+            Ok(array_buffer(cx, &dispatch::encode(&metrics::snapshot()))?.upcast())
+        }
+        Some(format) => cx.throw_type_error(format!("Unknown metrics format \"{}\"", format)),
+    }
+}
+
+// Reads the command index argument at position `i`. Throws a `TypeError`
+// for anything that doesn't name a known command, so malformed indices
+// never reach the core.
+fn command_argument(cx: &mut FunctionContext, i: i32) -> NeonResult<Command> {
+    let index = cx.argument::<JsValue>(i)?;
+    let index = match index.downcast::<JsNumber>() {
+        Ok(index) => index.value(),
+        Err(_) => return cx.throw_type_error("Command index must be a number"),
+    };
+
+    Command::from_index(index).or_else(|err| cx.throw_type_error(err.to_string()))
+}
+
+// Reads the `(buffer, commandIndex)` arguments and runs the command on the
+// main thread.
+fn handle_core_command<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsArrayBuffer> {
+    let b: Handle<JsArrayBuffer> = cx.argument(0)?;
+    let command = command_argument(cx, 1)?;
+    let v = cx.borrow(&b, |slice| dispatch::run(command, slice.as_slice::<u8>(), handle_command));
+
+    array_buffer(cx, &v)
+}
+
+
+// Core commands can run for a long time, either because they hit the network
+// (e.g. synchronization) or because they scan a large database. This struct
+// wraps the data required to run a command on a libuv thread. The payload is
+// copied out of the JS buffer, since the buffer can't be borrowed across
+// threads, and shared with the thread a cancellable read runs on.
+pub struct CommandTask {
+    command: Command,
+    data: Arc<Vec<u8>>,
+
+    // Core entry point the command is handed to, either `handle_command` or
+    // `handle_async_command`.
+    entry: CoreEntry,
+
+    cancel: Option<CancelHandle>,
+    deadline: Option<Instant>,
+}
+
+// Implementation of a neon `Task` for `CommandTask`. The command is executed
+// on the thread pool and the JS callback receives the encoded response.
+impl Task for CommandTask {
+    type Output = Vec<u8>;
+    type Error = TaskError;
+    type JsEvent = JsArrayBuffer;
+
+    // The work performed on the `libuv` thread.
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        let (command, entry) = (self.command, self.entry);
+        let data = Arc::clone(&self.data);
+        let run = move || dispatch::run(command, &data, entry);
+
+        Ok(cancel::run(command, self.cancel.as_ref(), self.deadline, run)?)
+    }
+
+    // Scheduled on the main thread once `perform` has returned. Copies the
+    // response into a fresh `ArrayBuffer` for the callback.
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<JsArrayBuffer> {
+        let result = result.or_else(|err| err.throw(&mut cx))?;
+
+        array_buffer(&mut cx, &result)
+    }
+}
+
+// Reads the `(buffer, commandIndex, callback, cancellationToken, timeoutMs)`
+// arguments shared by the callback style exports and schedules a
+// `CommandTask` on the `libuv` thread pool. The last two are optional. The
+// callback receives the encoded response.
+fn schedule_command<'a>(
+    cx: &mut FunctionContext<'a>,
+    entry: CoreEntry,
+) -> JsResult<'a, JsUndefined> {
+    let b: Handle<JsArrayBuffer> = cx.argument(0)?;
+    let command = command_argument(cx, 1)?;
+    let cb = cx.argument::<JsFunction>(2)?;
+    let data = Arc::new(cx.borrow(&b, |slice| slice.as_slice::<u8>().to_vec()));
+    let (cancel, deadline) = cancel_arguments(cx, 3)?;
+
+    let task = CommandTask {
+        command,
+        data,
+        entry,
+        cancel,
+        deadline,
+    };
+    task.schedule(cb);
+
+    Ok(JsUndefined::new())
+}
+
+// Reads the optional `CancellationToken` and timeout in milliseconds at
+// positions `i` and `i + 1`.
+fn cancel_arguments(
+    cx: &mut FunctionContext,
+    i: i32,
+) -> NeonResult<(Option<CancelHandle>, Option<Instant>)> {
+    let cancel = match cx.argument_opt(i) {
+        Some(token) if token.is_a::<JsCancellationToken>() => {
+            let token = token.downcast_or_throw::<JsCancellationToken, _>(cx)?;
+            Some(cx.borrow(&token, |cancel| (*cancel).clone()))
+        }
+        _ => None,
+    };
+    let deadline = match cx.argument_opt(i + 1) {
+        Some(timeout) if timeout.is_a::<JsNumber>() => {
+            let timeout = timeout.downcast_or_throw::<JsNumber, _>(cx)?.value();
+            Some(Instant::now() + Duration::from_millis(timeout.max(0.0) as u64))
+        }
+        _ => None,
+    };
+    Ok((cancel, deadline))
+}
+
+// Async counterpart of `handle_core_command`, so that slow queries don't
+// block the main thread.
+fn handle_core_command_async<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+    schedule_command(cx, handle_command)
+}
+
+// Schedules an async core command (e.g. synchronization) on the `libuv`
+// thread pool.
+fn handle_async_core_command<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+    schedule_command(cx, handle_async_command)
+}
+
+// Runs a `Request` envelope, or a `RequestSequence` of them, on a libuv thread. Unlike
+// `CommandTask` the commands and their core entry points are taken from the
+// requests themselves.
+pub struct RequestTask {
+    data: Vec<u8>,
+
+    // Whether `data` is a `RequestSequence` rather than a single `Request`.
+    sequence: bool,
+}
+
+impl Task for RequestTask {
+    type Output = Vec<u8>;
+    type Error = TaskError;
+    type JsEvent = JsArrayBuffer;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Ok(catch_panic(|| {
+            if self.sequence {
+                envelope::handle_sequence(&self.data)
+            } else {
+                envelope::handle(&self.data)
+            }
+        })?)
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<JsArrayBuffer> {
+        let result = result.or_else(|err| err.throw(&mut cx))?;
+
+        array_buffer(&mut cx, &result)
+    }
+}
+
+// Reads the `(buffer, callback)` arguments of `handleRequest` and schedules a
+// `RequestTask`. The callback receives the encoded `Response`.
+fn handle_request<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+    let b: Handle<JsArrayBuffer> = cx.argument(0)?;
+    let cb = cx.argument::<JsFunction>(1)?;
+    let data = cx.borrow(&b, |slice| slice.as_slice::<u8>().to_vec());
+
+    let task = RequestTask {
+        data,
+        sequence: false,
+    };
+    task.schedule(cb);
+
+    Ok(JsUndefined::new())
+}
+
+// Runs an encoded `RequestSequence` in one call. Accepts the sequence and a
+// `function (err, result)` callback, which receives the encoded
+// `RequestSequenceResponse`. The sequence is not atomic, see
+// `envelope::handle_sequence`.
+fn handle_requests<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+    let b: Handle<JsArrayBuffer> = cx.argument(0)?;
+    let cb = cx.argument::<JsFunction>(1)?;
+    let data = cx.borrow(&b, |slice| slice.as_slice::<u8>().to_vec());
+
+    let task = RequestTask {
+        data,
+        sequence: true,
+    };
+    task.schedule(cb);
+
+    Ok(JsUndefined::new())
+}
+
+// Shutting down waits for every in-flight command to finish, which may take
+// as long as a running synchronization. This task waits on a libuv thread.
+pub struct ShutdownTask;
+
+impl Task for ShutdownTask {
+    type Output = ();
+    type Error = TaskError;
+    type JsEvent = JsUndefined;
+
+    fn perform(&self) -> Result<Self::Output, Self::Error> {
+        Ok(catch_panic(lifecycle::shutdown)?)
+    }
+
+    fn complete(
+        self,
+        mut cx: TaskContext,
+        result: Result<Self::Output, Self::Error>,
+    ) -> JsResult<JsUndefined> {
+        result.or_else(|err| err.throw(&mut cx))?;
+        Ok(JsUndefined::new())
+    }
+}
+
+// Stops the bridge from accepting commands and shuts it down once the
+// in-flight ones have finished, see `lifecycle::shutdown`. Accepts a
+// `function (err)` style callback. Commands are rejected with a `SHUT_DOWN`
+// error until `InitData` restarts the bridge.
+fn shutdown<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+    let cb = cx.argument::<JsFunction>(0)?;
+    ShutdownTask.schedule(cb);
+    Ok(JsUndefined::new())
+}
+
+// Delivers the events queued for a JS callback. Runs on the main thread,
+// scheduled from the event pump thread through an `EventHandler` whenever
+// the queue stops being empty. The callback is called as
+// `callback(event, data, seq)` for every event, see `events::Event` for `seq`.
+fn schedule_drain(handler: &EventHandler, queue: Arc<EventQueue>) {
+    handler.schedule_with(move |cx, this, callback| {
+        // Like with `EventHandler::schedule`, a callback which throws ends
+        // the drain; the rest of the drained events are lost.
+        let _ = deliver(cx, this, callback, queue.drain());
+    });
+}
+
+fn deliver(
+    cx: &mut TaskContext,
+    this: Handle<JsValue>,
+    callback: Handle<JsFunction>,
+    events: Vec<Queued>,
+) -> NeonResult<()> {
+    for event in events {
+        metrics::record_event_delivered(event.queued_at.elapsed());
+
+        let event_name = cx.string(events::event_name(&event.data));
+        let data = array_buffer(cx, &event.data)?;
+        let seq = cx.number(event.seq as f64);
+
+        let args: Vec<Handle<JsValue>> = vec![event_name.upcast(), data.upcast(), seq.upcast()];
+        callback.call(cx, this, args)?;
+    }
+    Ok(())
+}
+
+// Reads the optional `{ capacity, overflow, sinceSeq }` options of a
+// listener. `capacity` and `overflow` configure its event queue, `overflow`
+// being one of "dropOldest" (the default), "coalesce" or "block". `sinceSeq`
+// is the sequence number of the last event seen by a previous listener, the
+// events missed since are replayed.
+fn listener_options<'a, C: Context<'a>>(
+    cx: &mut C,
+    options: Option<Handle<'a, JsValue>>,
+) -> NeonResult<(EventQueue, Option<u64>)> {
+    let options = match options {
+        Some(options) if options.is_a::<JsObject>() => {
+            options.downcast_or_throw::<JsObject, _>(cx)?
+        }
+        _ => {
+            let queue = EventQueue::new(DEFAULT_CAPACITY, Overflow::DropOldest);
+            return Ok((queue, None));
+        }
+    };
+
+    let capacity = number_option(cx, options, "capacity")?
+        .map_or(DEFAULT_CAPACITY, |capacity| capacity as usize);
+    let overflow = match string_option(cx, options, "overflow")? {
+        Some(name) => match Overflow::from_name(&name) {
+            Some(overflow) => overflow,
+            None => {
+                return cx.throw_type_error(format!("Unknown overflow policy \"{}\"", name))
+            }
+        },
+        None => Overflow::DropOldest,
+    };
+    let since = number_option(cx, options, "sinceSeq")?.map(|seq| seq as u64);
+    Ok((EventQueue::new(capacity, overflow), since))
+}
+
+// A JS callback's registration for core events. Events reach the callback
+// through a bounded `EventQueue`, so a callback which falls behind, e.g.
+// because the main thread is busy, can't make them pile up forever.
+pub struct Listener {
+    queue: Arc<EventQueue>,
+    _subscription: events::Subscription,
+}
+
+impl Listener {
+    // Subscribes the callback of `handler` to events, replaying the events
+    // after `since`.
+    fn new(handler: EventHandler, queue: EventQueue, since: Option<u64>) -> Listener {
+        let queue = Arc::new(queue);
+        let pushed = Arc::clone(&queue);
+        let sink = move |event| {
+            if pushed.push(event) {
+                schedule_drain(&handler, Arc::clone(&pushed));
+            }
+        };
+        let subscription = events::subscribe(Box::new(sink), since, queue.capacity());
+
+        Listener {
+            queue,
+            _subscription: subscription,
+        }
+    }
+}
+
+impl Drop for Listener {
+    // Releases a pump blocked on the full queue, which would otherwise wait
+    // for a drain that never comes.
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
+// Rust struct that holds the data required by the `JsEventEmitter` class.
+pub struct EventEmitter {
+    // The channel's registration for core events. Every channel receives
+    // every event. Set to `None` once the channel has been shut down, which
+    // unsubscribes it.
+    listener: Option<Listener>,
+}
+
+// Implementation of the `JsEventEmitter` class. This is the only public
+// interface of the Rust code. Events are pushed to the callback passed to
+// the constructor as they arrive; the class exposes the `shutdown` method
+// to JS.
+declare_types! {
+    pub class JsEventEmitter for EventEmitter {
+        // Called by the `JsEventEmitter` constructor with a
+        // `function (event, data, seq)` callback and optional listener
+        // options, see `listener_options`.
+        init(mut cx) {
+            let cb = cx.argument::<JsFunction>(0)?;
+            let options = cx.argument_opt(1);
+            let (queue, since) = listener_options(&mut cx, options)?;
+            let this = JsUndefined::new();
+            let handler = EventHandler::new(&cx, this, cb);
+
+            // Construct a new `EventEmitter` to be wrapped by the class.
+            Ok(EventEmitter {
+                listener: Some(Listener::new(handler, queue, since)),
+            })
+        }
+
+        // The shutdown method unsubscribes the channel from core events. While
+        // subscribed, the channel keeps the Node.js event loop alive. It may
+        // be called more than once. Shutting down the core itself is done by
+        // the `shutdown` export.
+        method shutdown(mut cx) {
+            let mut this = cx.this();
+
+            cx.borrow_mut(&mut this, |mut emitter| emitter.listener = None);
+
+            Ok(JsUndefined::new().upcast())
+        }
+    }
+}
+
+// Implementation of the `JsCancellationToken` class, exported as
+// `CancellationToken`. Passed to the callback style command exports, after
+// the callback, to be able to cancel the command later on.
+declare_types! {
+    pub class JsCancellationToken for CancelHandle {
+        init(_cx) {
+            Ok(CancelHandle::default())
+        }
+
+        // Makes the command fail with a `CANCELLED` error, unless it has
+        // already finished or is a write the core has already started, see
+        // `cancel::run`. May be called more than once.
+        method cancel(mut cx) {
+            let this = cx.this();
+
+            cx.borrow(&this, |cancel| cancel.cancel());
+
+            Ok(JsUndefined::new().upcast())
+        }
+    }
+}
+
+// Exports the module's functions and classes on `m`. Every exported function
+// runs under `guard`, so a panic in the core is rethrown as a JS `Error`
+// instead of aborting the process. Called by the `register_module!` of the
+// module being built, which installs its core first.
+pub fn register(m: &mut ModuleContext) -> NeonResult<()> {
+    install_panic_hook();
+
+    m.export_function("getVersion", |cx| guard(cx, get_version))?;
+    m.export_function("handshake", |cx| guard(cx, handshake))?;
+    m.export_function("getMetrics", |cx| guard(cx, get_metrics))?;
+    m.export_function("initLogging", |cx| guard(cx, init_logging))?;
+    #[cfg(feature = "mock-core")]
+    m.export_function("benchmarkCopy", |cx| guard(cx, benchmark_copy))?;
+    m.export_function("handleCommand", |cx| guard(cx, handle_core_command))?;
+    m.export_function("handleCommandAsync", |cx| guard(cx, handle_core_command_async))?;
+    m.export_function("handleAsyncCommand", |cx| guard(cx, handle_async_core_command))?;
+    m.export_function("handleRequest", |cx| guard(cx, handle_request))?;
+    m.export_function("handleRequests", |cx| guard(cx, handle_requests))?;
+    m.export_function("shutdown", |cx| guard(cx, shutdown))?;
+    #[cfg(all(unix, feature = "server"))]
+    {
+        m.export_function("startServer", |cx| guard(cx, server::start_server))?;
+        m.export_function("stopServer", |cx| guard(cx, server::stop_server))?;
+    }
+    m.export_class::<JsEventEmitter>("RustChannel")?;
+    m.export_class::<JsCancellationToken>("CancellationToken")?;
+    Ok(())
+}
+
+// The module built from this crate, running against the fake core.
+#[cfg(feature = "mock-core")]
+neon::register_module!(mut m, { register(&mut m) });
@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use neon::prelude::*;
+
+use crate::server::Server;
+
+// Servers started by `startServer`, keyed by the directory they listen in.
+static SERVERS: Mutex<BTreeMap<PathBuf, Server>> = Mutex::new(BTreeMap::new());
+
+// Starts an IPC server in the directory given as first argument, see
+// `server::Server`. Returns the `{ socketPath, tokenPath }` clients connect
+// and authenticate with.
+pub fn start_server<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsObject> {
+    let dir = PathBuf::from(cx.argument::<JsString>(0)?.value());
+
+    // Replacing a running server would remove the socket of the new one.
+    let mut servers = servers();
+    if servers.contains_key(&dir) {
+        return cx.throw_error(format!("A server is already running in {}", dir.display()));
+    }
+    let server = Server::start(&dir)
+        .or_else(|err| cx.throw_error(format!("Failed to start the server: {}", err)))?;
+
+    let paths = JsObject::new(cx);
+    let socket_path = cx.string(server.socket_path().to_string_lossy());
+    paths.set(cx, "socketPath", socket_path)?;
+    let token_path = cx.string(server.token_path().to_string_lossy());
+    paths.set(cx, "tokenPath", token_path)?;
+
+    servers.insert(dir, server);
+    Ok(paths)
+}
+
+// Stops the server running in the directory given as argument, if any.
+pub fn stop_server<'a>(cx: &mut FunctionContext<'a>) -> JsResult<'a, JsUndefined> {
+    let dir = PathBuf::from(cx.argument::<JsString>(0)?.value());
+    servers().remove(&dir);
+    Ok(JsUndefined::new())
+}
+
+fn servers() -> MutexGuard<'static, BTreeMap<PathBuf, Server>> {
+    SERVERS.lock().unwrap_or_else(PoisonError::into_inner)
+}
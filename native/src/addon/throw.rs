@@ -0,0 +1,51 @@
+use neon::prelude::*;
+
+use crate::guard::{catch_panic, Panic};
+
+// Error code of the JS `Error` thrown when the core panics. Matched on by
+// the JS side, so it must not change.
+pub const PANIC_ERROR_CODE: &str = "ERR_GIGANOTES_PANIC";
+
+// Error type of the neon `Task`s which call into the core.
+pub enum TaskError {
+    Failed(String),
+    Panic(Panic),
+}
+
+impl TaskError {
+    pub fn throw<'a, C: Context<'a>, T>(self, cx: &mut C) -> NeonResult<T> {
+        match self {
+            TaskError::Failed(message) => cx.throw_error(&message),
+            TaskError::Panic(panic) => throw_panic(cx, panic),
+        }
+    }
+}
+
+impl From<Panic> for TaskError {
+    fn from(panic: Panic) -> Self {
+        TaskError::Panic(panic)
+    }
+}
+
+// Runs the body of an exported function, rethrowing a panic as a JS `Error`.
+pub fn guard<'a, T, F>(mut cx: FunctionContext<'a>, f: F) -> JsResult<'a, T>
+where
+    T: Value,
+    F: FnOnce(&mut FunctionContext<'a>) -> JsResult<'a, T>,
+{
+    match catch_panic(|| f(&mut cx)) {
+        Ok(result) => result,
+        Err(panic) => throw_panic(&mut cx, panic),
+    }
+}
+
+// Throws a JS `Error` for `panic`. The error carries `PANIC_ERROR_CODE` as its
+// `code` and the Rust backtrace as `backtrace`.
+pub fn throw_panic<'a, C: Context<'a>, T>(cx: &mut C, panic: Panic) -> NeonResult<T> {
+    let err = cx.error(format!("Panic in giganotes core: {}", panic.message))?;
+    let code = cx.string(PANIC_ERROR_CODE);
+    let backtrace = cx.string(panic.backtrace);
+    err.set(cx, "code", code)?;
+    err.set(cx, "backtrace", backtrace)?;
+    cx.throw(err)
+}
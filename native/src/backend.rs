@@ -0,0 +1,103 @@
+use std::sync::mpsc::Receiver;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::dispatch::CoreEntry;
+
+// The core the bridge runs against. This crate doesn't link giganotes-core
+// itself, so it builds and tests without a checkout of it: the Node.js module
+// and command line client in native/core install the real core on startup,
+// and the `mock-core` feature falls back to the in-memory fake of
+// src/mock.rs when nothing was installed.
+pub struct Backend {
+    // Entry points of the core, its `handle_command` and
+    // `handle_async_command`.
+    pub handle_command: CoreEntry,
+    pub handle_async_command: CoreEntry,
+
+    // Receiving end of the channel the core pushes its events to, the
+    // `receiver` of its `WORKER`.
+    pub events: Arc<Mutex<Receiver<Vec<u8>>>>,
+
+    // Version of the core, reported by `getVersion`.
+    pub version: &'static str,
+
+    // Whether the core's events are encoded `CoreEvent`s, in which case a
+    // synchronization is only done once its `syncFinished` or `syncFailed`
+    // event has arrived, see `completion`. A core pushing other payloads
+    // answers `Synchronize` with its final response.
+    pub core_events: bool,
+
+    // Stops the worker the core runs async commands on, once shut down.
+    // `None` for a core which can't stop it.
+    pub stop: Option<fn()>,
+}
+
+static BACKEND: OnceLock<Backend> = OnceLock::new();
+
+// Makes the bridge run against `backend`. Has to happen before the first
+// command; a backend can't be replaced, so `backend` is handed back if one
+// is already in use.
+pub fn install(backend: Backend) -> Result<(), Backend> {
+    BACKEND.set(backend)
+}
+
+pub fn handle_command(index: i8, data: &[u8], len: usize) -> Vec<u8> {
+    (backend().handle_command)(index, data, len)
+}
+
+pub fn handle_async_command(index: i8, data: &[u8], len: usize) -> Vec<u8> {
+    (backend().handle_async_command)(index, data, len)
+}
+
+pub fn events() -> Arc<Mutex<Receiver<Vec<u8>>>> {
+    Arc::clone(&backend().events)
+}
+
+pub fn core_version() -> &'static str {
+    backend().version
+}
+
+pub fn core_events() -> bool {
+    backend().core_events
+}
+
+pub fn stop() {
+    if let Some(stop) = backend().stop {
+        stop();
+    }
+}
+
+fn backend() -> &'static Backend {
+    BACKEND.get_or_init(fallback)
+}
+
+#[cfg(feature = "mock-core")]
+fn fallback() -> Backend {
+    crate::mock::backend()
+}
+
+// Without a core every command fails, and there are never any events.
+#[cfg(not(feature = "mock-core"))]
+fn fallback() -> Backend {
+    let (_, events) = std::sync::mpsc::channel();
+    Backend {
+        handle_command: no_core,
+        handle_async_command: no_core,
+        events: Arc::new(Mutex::new(events)),
+        version: "none",
+        core_events: false,
+        stop: None,
+    }
+}
+
+#[cfg(not(feature = "mock-core"))]
+fn no_core(_index: i8, _data: &[u8], _len: usize) -> Vec<u8> {
+    use crate::messages::{Error, ErrorCode};
+
+    let mut error = Error {
+        message: "No core is installed, see backend::install".to_string(),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::UnknownError);
+    crate::dispatch::failure_response(error)
+}
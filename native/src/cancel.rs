@@ -0,0 +1,202 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::Instant;
+
+use crate::command::Command;
+use crate::dispatch::failure_response;
+use crate::guard::{catch_panic, Panic};
+use crate::messages::{Error, ErrorCode};
+
+// Most reads left to finish in the background at a time, after being given
+// up on. Past it, reads which can be given up on run like writes do.
+pub const MAX_BACKGROUND_READS: usize = 4;
+
+static BACKGROUND_READS: AtomicUsize = AtomicUsize::new(0);
+
+static NEXT_WAITER_ID: AtomicU64 = AtomicU64::new(0);
+
+// Lets JS cancel a command it has scheduled. Cloned into the task running
+// the command; cancelling any clone cancels them all. A handle may be passed
+// to several commands, cancelling it cancels every one of them.
+#[derive(Clone, Default)]
+pub struct CancelHandle {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    cancelled: AtomicBool,
+
+    // Wakes up the tasks waiting for a command run with the handle, keyed by
+    // waiter id.
+    waiters: Mutex<HashMap<u64, Wake>>,
+}
+
+// Called when a handle is cancelled, see `CancelHandle::on_cancel`.
+type Wake = Box<dyn Fn() + Send>;
+
+enum Outcome {
+    Done(Vec<u8>),
+    Panicked(Panic),
+    Cancelled,
+}
+
+impl CancelHandle {
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for wake in self.waiters().values() {
+            wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    // Calls `wake` when the handle is cancelled, until the returned `Waiter`
+    // is dropped. Check `is_cancelled` after registering, the handle may have
+    // been cancelled before.
+    pub fn on_cancel<F: Fn() + Send + 'static>(&self, wake: F) -> Waiter<'_> {
+        let id = NEXT_WAITER_ID.fetch_add(1, Ordering::Relaxed);
+        self.waiters().insert(id, Box::new(wake));
+        Waiter { cancel: self, id }
+    }
+
+    fn waiters(&self) -> MutexGuard<'_, HashMap<u64, Wake>> {
+        self.inner.waiters.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// A task's registration with a `CancelHandle`, removed on drop.
+pub struct Waiter<'a> {
+    cancel: &'a CancelHandle,
+    id: u64,
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        self.cancel.waiters().remove(&self.id);
+    }
+}
+
+// The cancellation handle and deadline of a command.
+type Scope = (Option<CancelHandle>, Option<Instant>);
+
+// The scope of the command running on this thread, see `current`.
+thread_local! {
+    static CURRENT: RefCell<Scope> = const { RefCell::new((None, None)) };
+}
+
+// The cancellation handle and deadline of the command `run` is running on
+// this thread, for a command which waits on the bridge once the core has
+// answered, like `Synchronize` does, see `completion`.
+pub fn current() -> Scope {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+// Runs `f` with `cancel` and `deadline` as the `current` ones.
+fn with_current<F, R>(cancel: Option<&CancelHandle>, deadline: Option<Instant>, f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    CURRENT.with(|current| *current.borrow_mut() = (cancel.cloned(), deadline));
+    let result = f();
+    CURRENT.with(|current| *current.borrow_mut() = (None, None));
+    result
+}
+
+// Runs `command` through `f`, unless `cancel` is cancelled or `deadline` has
+// passed first. The response is then a failure with a `CANCELLED` or
+// `TIMED_OUT` error.
+//
+// The core has no way to interrupt a command, so only a command which
+// hasn't reached it yet can be stopped. Once a command has started, a write
+// always runs to the end and its response is returned, whatever happens to
+// `cancel` or `deadline` meanwhile: its changes are committed, so reporting it
+// as cancelled would be a lie. A read, which changes nothing, is given up on
+// instead: it is left to finish on a thread of its own and its response is
+// discarded. Until it has finished it still counts as in-flight, e.g. for
+// `shutdown`. With `MAX_BACKGROUND_READS` of them still running, reads are no
+// longer given up on either. A synchronization stops being waited for, see
+// `completion::synchronize`.
+pub fn run<F>(
+    command: Command,
+    cancel: Option<&CancelHandle>,
+    deadline: Option<Instant>,
+    f: F,
+) -> Result<Vec<u8>, Panic>
+where
+    F: FnOnce() -> Vec<u8> + Send + 'static,
+{
+    if cancel.is_some_and(CancelHandle::is_cancelled) {
+        return Ok(failure_response(cancelled(command)));
+    }
+    if deadline.is_some_and(|deadline| deadline <= Instant::now()) {
+        return Ok(failure_response(timed_out(command)));
+    }
+    if !command.is_read_only() || (cancel.is_none() && deadline.is_none()) {
+        return with_current(cancel, deadline, || catch_panic(f));
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let _waiter = cancel.map(|cancel| {
+        let tx = tx.clone();
+        cancel.on_cancel(move || {
+            let _ = tx.send(Outcome::Cancelled);
+        })
+    });
+    // Cancelled while the waiter was being registered.
+    if cancel.is_some_and(CancelHandle::is_cancelled) {
+        return Ok(failure_response(cancelled(command)));
+    }
+
+    if BACKGROUND_READS.fetch_add(1, Ordering::SeqCst) >= MAX_BACKGROUND_READS {
+        BACKGROUND_READS.fetch_sub(1, Ordering::SeqCst);
+        return catch_panic(f);
+    }
+    thread::spawn(move || {
+        let outcome = match catch_panic(f) {
+            Ok(response) => Outcome::Done(response),
+            Err(panic) => Outcome::Panicked(panic),
+        };
+        BACKGROUND_READS.fetch_sub(1, Ordering::SeqCst);
+        let _ = tx.send(outcome);
+    });
+
+    let outcome = match deadline {
+        Some(deadline) => rx.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+        // The reading thread sends an outcome before it exits.
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+    match outcome {
+        Ok(Outcome::Done(response)) => Ok(response),
+        Ok(Outcome::Panicked(panic)) => Err(panic),
+        Ok(Outcome::Cancelled) | Err(RecvTimeoutError::Disconnected) => {
+            Ok(failure_response(cancelled(command)))
+        }
+        Err(RecvTimeoutError::Timeout) => Ok(failure_response(timed_out(command))),
+    }
+}
+
+pub fn cancelled(command: Command) -> Error {
+    let mut error = Error {
+        message: format!("{} was cancelled", command),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::Cancelled);
+    error
+}
+
+pub fn timed_out(command: Command) -> Error {
+    let mut error = Error {
+        message: format!("{} did not finish before its deadline", command),
+        retryable: true,
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::TimedOut);
+    error
+}
@@ -0,0 +1,444 @@
+// Command line client for inspecting and repairing a local Giganotes store
+// without the app. Commands run through the same dispatch as
+// `handleCommand`, so they are validated and fail the same way. The
+// `giganotes` executable of native/core calls `main` once it has installed
+// the core.
+
+use std::io::{self, Read};
+#[cfg(all(unix, feature = "server"))]
+use std::path::Path;
+use std::process;
+#[cfg(all(unix, feature = "server"))]
+use std::thread;
+
+use clap::{App, AppSettings, Arg, ArgMatches, SubCommand};
+use prost::Message;
+
+use crate::backend::{handle_async_command, handle_command};
+use crate::command::Command;
+use crate::dispatch::{self, encode, CoreEntry};
+use crate::events::{self, Event};
+use crate::messages::core_event::Payload;
+use crate::messages::*;
+#[cfg(all(unix, feature = "server"))]
+use crate::server::Server;
+
+mod output;
+
+use output::Output;
+
+fn app() -> App<'static, 'static> {
+    let note_id = || Arg::with_name("id").required(true).help("Id of the note");
+    let folder = || {
+        Arg::with_name("folder")
+            .long("folder")
+            .short("f")
+            .takes_value(true)
+            .help("Id of the folder")
+    };
+    let text = || {
+        Arg::with_name("text")
+            .long("text")
+            .short("t")
+            .takes_value(true)
+            .help("Text of the note, read from stdin if it is \"-\"")
+    };
+
+    let app = App::new("giganotes")
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Inspects and repairs a local Giganotes store")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .arg(
+            Arg::with_name("data-path")
+                .long("data-path")
+                .short("d")
+                .env("GIGANOTES_DATA_PATH")
+                .required(true)
+                .help("Data directory of the store"),
+        )
+        .arg(
+            Arg::with_name("api-path")
+                .long("api-path")
+                .env("GIGANOTES_API_PATH")
+                .default_value("")
+                .help("URL of the Giganotes API, needed to log in and synchronize"),
+        )
+        .arg(Arg::with_name("json").long("json").help("Print JSON instead of text"))
+        .subcommand(SubCommand::with_name("init").about("Creates or opens the store"))
+        .subcommand(
+            SubCommand::with_name("login")
+                .about("Logs in, reading the password from stdin")
+                .arg(Arg::with_name("email").required(true)),
+        )
+        .subcommand(SubCommand::with_name("logout").about("Logs out"))
+        .subcommand(SubCommand::with_name("whoami").about("Shows the logged in user"))
+        .subcommand(SubCommand::with_name("folders").about("Lists all folders as a tree"))
+        .subcommand(
+            SubCommand::with_name("notes")
+                .about("Lists the notes of a folder, or all notes")
+                .arg(folder())
+                .arg(Arg::with_name("offset").long("offset").takes_value(true))
+                .arg(Arg::with_name("limit").long("limit").takes_value(true)),
+        )
+        .subcommand(
+            SubCommand::with_name("show")
+                .about("Shows a note with its details")
+                .arg(note_id()),
+        )
+        .subcommand(
+            SubCommand::with_name("cat")
+                .about("Prints the text of a note")
+                .arg(note_id()),
+        )
+        .subcommand(
+            SubCommand::with_name("create-note")
+                .about("Creates a note, in the root folder by default")
+                .arg(Arg::with_name("title").required(true))
+                .arg(folder())
+                .arg(text()),
+        )
+        .subcommand(
+            SubCommand::with_name("create-folder")
+                .about("Creates a folder, in the root folder by default")
+                .arg(Arg::with_name("title").required(true))
+                .arg(folder().help("Id of the parent folder")),
+        )
+        .subcommand(
+            SubCommand::with_name("update-note")
+                .about("Changes the title, text or folder of a note")
+                .arg(note_id())
+                .arg(Arg::with_name("title").long("title").takes_value(true))
+                .arg(folder().help("Id of the folder to move the note to"))
+                .arg(text()),
+        )
+        .subcommand(
+            SubCommand::with_name("update-folder")
+                .about("Renames or moves a folder")
+                .arg(Arg::with_name("id").required(true).help("Id of the folder"))
+                .arg(Arg::with_name("title").long("title").takes_value(true))
+                .arg(folder().help("Id of the folder to move the folder to")),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-note")
+                .about("Removes a note")
+                .arg(note_id()),
+        )
+        .subcommand(
+            SubCommand::with_name("remove-folder")
+                .about("Removes a folder with everything in it")
+                .arg(Arg::with_name("id").required(true).help("Id of the folder")),
+        )
+        .subcommand(
+            SubCommand::with_name("search")
+                .about("Searches the titles and texts of notes")
+                .arg(Arg::with_name("query").required(true))
+                .arg(folder()),
+        )
+        .subcommand(SubCommand::with_name("favorites").about("Lists the favorite notes"))
+        .subcommand(
+            SubCommand::with_name("favorite")
+                .about("Adds a note to the favorites")
+                .arg(note_id()),
+        )
+        .subcommand(
+            SubCommand::with_name("unfavorite")
+                .about("Removes a note from the favorites")
+                .arg(note_id()),
+        )
+        .subcommand(SubCommand::with_name("sync").about("Synchronizes with the server"));
+
+    if cfg!(all(unix, feature = "server")) {
+        app.subcommand(
+            SubCommand::with_name("serve")
+                .about("Serves the store to other processes on a socket in the data directory"),
+        )
+    } else {
+        app
+    }
+}
+
+pub fn main() {
+    let matches = app().get_matches();
+    let output = Output::new(matches.is_present("json"));
+
+    let init = InitData {
+        data_path: matches.value_of("data-path").unwrap().to_string(),
+        api_path: matches.value_of("api-path").unwrap().to_string(),
+    };
+    let result = exec(Command::InitData, &init).and_then(|_| match matches.subcommand() {
+        #[cfg(all(unix, feature = "server"))]
+        ("serve", _) => serve(&init.data_path, &output),
+        (name, Some(args)) => subcommand(name, args, &output),
+        _ => unreachable!("a subcommand is required"),
+    });
+
+    if let Err(error) = result {
+        output.error(&error);
+        process::exit(1);
+    }
+}
+
+fn subcommand(name: &str, args: &ArgMatches, output: &Output) -> Result<(), Error> {
+    match name {
+        "init" => {
+            let root: GetRootFolderResponse = run(Command::GetRootFolder, &GetRootFolder {})?;
+            output.folder_id(&root.folder_id);
+        }
+        "login" => {
+            let login = Login {
+                email: args.value_of("email").unwrap().to_string(),
+                password: read_line()?,
+            };
+            let response: LoginResponse = run(Command::Login, &login)?;
+            output.login(&login.email, response.user_id);
+        }
+        "logout" => {
+            exec(Command::Logout, &Logout {})?;
+            output.done();
+        }
+        "whoami" => output.login_data(&run(Command::GetLastLoginData, &GetLastLoginData {})?),
+        "folders" => {
+            let folders: GetFoldersListResponse = run(Command::GetAllFolders, &GetAllFolders {})?;
+            output.folders(&folders.folders);
+        }
+        "notes" => {
+            let notes: GetNotesListResponse = match args.value_of("folder") {
+                Some(folder_id) => run(
+                    Command::GetNotesByFolder,
+                    &GetNotesList {
+                        folder_id: folder_id.to_string(),
+                    },
+                )?,
+                None => run(
+                    Command::GetAllNotes,
+                    &GetAllNotes {
+                        offset: number(args, "offset")?,
+                        limit: number(args, "limit")?,
+                    },
+                )?,
+            };
+            output.notes(&notes.notes);
+        }
+        "show" => output.note(&note(args.value_of("id").unwrap())?),
+        "cat" => output.text(&note(args.value_of("id").unwrap())?.text),
+        "create-note" => {
+            let request = CreateNote {
+                title: args.value_of("title").unwrap().to_string(),
+                text: text(args)?.unwrap_or_default(),
+                folder_id: args.value_of("folder").unwrap_or_default().to_string(),
+            };
+            let response: CreateNoteResponse = run(Command::CreateNote, &request)?;
+            output.note_id(&response.note_id);
+        }
+        "create-folder" => {
+            let request = CreateFolder {
+                parent_id: args.value_of("folder").unwrap_or_default().to_string(),
+                title: args.value_of("title").unwrap().to_string(),
+            };
+            let response: CreateFolderResponse = run(Command::CreateFolder, &request)?;
+            output.folder_id(&response.folder_id);
+        }
+        "update-note" => {
+            // Updates replace every field, so unchanged ones are read first.
+            let note = note(args.value_of("id").unwrap())?;
+            let request = UpdateNote {
+                title: args.value_of("title").map_or(note.title, str::to_string),
+                text: text(args)?.unwrap_or(note.text),
+                folder_id: args.value_of("folder").map_or(note.folder_id, str::to_string),
+                id: note.id,
+            };
+            exec(Command::UpdateNote, &request)?;
+            output.done();
+        }
+        "update-folder" => {
+            let folder_id = args.value_of("id").unwrap().to_string();
+            let folder: GetFolderByIdResponse =
+                run(Command::GetFolderById, &GetFolderById { folder_id })?;
+            let parent_id = args.value_of("folder").map_or(folder.parent_id, str::to_string);
+            let level = if parent_id.is_empty() {
+                folder.level
+            } else {
+                let parent: GetFolderByIdResponse = run(
+                    Command::GetFolderById,
+                    &GetFolderById {
+                        folder_id: parent_id.clone(),
+                    },
+                )?;
+                parent.level + 1
+            };
+            let request = UpdateFolder {
+                id: folder.id,
+                parent_id,
+                title: args.value_of("title").map_or(folder.title, str::to_string),
+                level,
+            };
+            exec(Command::UpdateFolder, &request)?;
+            output.done();
+        }
+        "remove-note" => {
+            let request = RemoveNote {
+                note_id: args.value_of("id").unwrap().to_string(),
+            };
+            exec(Command::RemoveNote, &request)?;
+            output.done();
+        }
+        "remove-folder" => {
+            let request = RemoveFolder {
+                folder_id: args.value_of("id").unwrap().to_string(),
+            };
+            exec(Command::RemoveFolder, &request)?;
+            output.done();
+        }
+        "search" => {
+            let request = SearchNotes {
+                query: args.value_of("query").unwrap().to_string(),
+                folder_id: args.value_of("folder").unwrap_or_default().to_string(),
+            };
+            let notes: GetNotesListResponse = run(Command::SearchNotes, &request)?;
+            output.notes(&notes.notes);
+        }
+        "favorites" => {
+            let notes: GetNotesListResponse = run(Command::GetFavorites, &GetFavorites {})?;
+            output.notes(&notes.notes);
+        }
+        "favorite" => {
+            let request = AddToFavorites {
+                note_id: args.value_of("id").unwrap().to_string(),
+            };
+            exec(Command::AddToFavorites, &request)?;
+            output.done();
+        }
+        "unfavorite" => {
+            let request = RemoveFromFavorites {
+                note_id: args.value_of("id").unwrap().to_string(),
+            };
+            exec(Command::RemoveFromFavorites, &request)?;
+            output.done();
+        }
+        "sync" => {
+            let progress = output.clone();
+            let _subscription = events::subscribe(
+                Box::new(move |event: Event| {
+                    if let Ok(CoreEvent {
+                        payload: Some(Payload::SyncProgress(sync)),
+                    }) = CoreEvent::decode(&event.data[..])
+                    {
+                        progress.sync_progress(&sync);
+                    }
+                }),
+                None,
+                usize::MAX,
+            );
+            // Returns once the synchronization has finished, with the error
+            // of its `syncFailed` event if it failed, which exits with 1. A
+            // core which doesn't report these events only says whether it
+            // started, see `completion::synchronize`.
+            exec(Command::Synchronize, &Synchronize {})?;
+            output.done();
+        }
+        _ => unreachable!("every subcommand is handled"),
+    }
+    Ok(())
+}
+
+// Serves the store until the process is killed, which leaves the socket and
+// the token file behind. The next server replaces them.
+#[cfg(all(unix, feature = "server"))]
+fn serve(data_path: &str, output: &Output) -> Result<(), Error> {
+    let server = Server::start(Path::new(data_path)).map_err(|err| {
+        let mut error = Error {
+            message: format!("Failed to start the server: {}", err),
+            ..Default::default()
+        };
+        error.set_code(ErrorCode::UnknownError);
+        error
+    })?;
+    output.serving(server.socket_path(), server.token_path());
+
+    loop {
+        thread::park();
+    }
+}
+
+// Runs `command` like `handleCommand` does and decodes the response as `R`,
+// or returns its error if it failed.
+fn run<R: Message + Default>(command: Command, request: &impl Message) -> Result<R, Error> {
+    let entry: CoreEntry = match command {
+        Command::Synchronize => handle_async_command,
+        _ => handle_command,
+    };
+    let response = dispatch::run(command, &encode(request), entry);
+
+    let status = ResponseStatus::decode(&response[..]).map_err(malformed)?;
+    if !status.success {
+        return Err(status.error.unwrap_or_default());
+    }
+    R::decode(&response[..]).map_err(malformed)
+}
+
+// Runs a command answered by an `EmptyResultResponse`.
+fn exec(command: Command, request: &impl Message) -> Result<(), Error> {
+    run::<EmptyResultResponse>(command, request).map(drop)
+}
+
+fn note(id: &str) -> Result<GetNoteByIdResponse, Error> {
+    let request = GetNoteById {
+        note_id: id.to_string(),
+    };
+    run(Command::GetNoteById, &request)
+}
+
+// The `--text` of a note, read from stdin if it is "-".
+fn text(args: &ArgMatches) -> Result<Option<String>, Error> {
+    match args.value_of("text") {
+        Some("-") => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).map_err(io_error)?;
+            Ok(Some(text))
+        }
+        text => Ok(text.map(str::to_string)),
+    }
+}
+
+fn read_line() -> Result<String, Error> {
+    let mut line = String::new();
+    io::stdin().read_line(&mut line).map_err(io_error)?;
+    Ok(line.trim_end_matches(&['\r', '\n'][..]).to_string())
+}
+
+fn number(args: &ArgMatches, name: &str) -> Result<i32, Error> {
+    match args.value_of(name) {
+        Some(value) => value.parse().map_err(|_| {
+            usage_error(&format!("--{} must be a number, not \"{}\"", name, value), name)
+        }),
+        None => Ok(0),
+    }
+}
+
+fn usage_error(message: &str, field: &str) -> Error {
+    let mut error = Error {
+        message: message.to_string(),
+        field: field.to_string(),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::ValidationFailed);
+    error
+}
+
+fn io_error(err: io::Error) -> Error {
+    let mut error = Error {
+        message: format!("Failed to read stdin: {}", err),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::UnknownError);
+    error
+}
+
+fn malformed(err: prost::DecodeError) -> Error {
+    let mut error = Error {
+        message: format!("Malformed response: {}", err),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::UnknownError);
+    error
+}
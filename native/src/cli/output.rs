@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+#[cfg(all(unix, feature = "server"))]
+use std::path::Path;
+
+use chrono::{TimeZone, Utc};
+use serde_json::{json, Value};
+
+use crate::messages::{
+    Error, Folder, GetLastLoginDataResponse, GetNoteByIdResponse, NoteShortInfo, SyncProgress,
+};
+
+// Prints results as text for people or, with `--json`, as one JSON value per
+// command for scripts. Field names in JSON match protos/messages.proto.
+#[derive(Clone)]
+pub struct Output {
+    json: bool,
+}
+
+impl Output {
+    pub fn new(json: bool) -> Output {
+        Output { json }
+    }
+
+    pub fn done(&self) {
+        if self.json {
+            print_json(json!({ "success": true }));
+        }
+    }
+
+    pub fn note_id(&self, id: &str) {
+        self.id("noteId", id);
+    }
+
+    pub fn folder_id(&self, id: &str) {
+        self.id("folderId", id);
+    }
+
+    pub fn login(&self, email: &str, user_id: i32) {
+        if self.json {
+            print_json(json!({ "email": email, "userId": user_id }));
+        } else {
+            println!("Logged in as {} (user {})", email, user_id);
+        }
+    }
+
+    // The token is a credential, so it is never printed.
+    pub fn login_data(&self, data: &GetLastLoginDataResponse) {
+        if self.json {
+            print_json(json!({
+                "email": data.email,
+                "userId": data.user_id,
+                "isTokenValid": data.is_token_valid,
+            }));
+        } else {
+            let expired = if data.is_token_valid { "" } else { ", login expired" };
+            println!("{} (user {}{})", data.email, data.user_id, expired);
+        }
+    }
+
+    // Prints the folders as a tree, each below its parent.
+    pub fn folders(&self, folders: &[Folder]) {
+        if self.json {
+            print_json(Value::Array(folders.iter().map(folder_json).collect()));
+            return;
+        }
+
+        let mut children: HashMap<&str, Vec<&Folder>> = HashMap::new();
+        for folder in folders {
+            children.entry(&folder.parent_id).or_default().push(folder);
+        }
+        // Folders whose parent is missing, e.g. in a damaged store, are
+        // printed at the top level along with the root folder.
+        let mut stack: Vec<(&Folder, usize)> = folders
+            .iter()
+            .filter(|folder| !folders.iter().any(|parent| parent.id == folder.parent_id))
+            .rev()
+            .map(|folder| (folder, 0))
+            .collect();
+        while let Some((folder, depth)) = stack.pop() {
+            println!("{}{}  {}", "  ".repeat(depth), folder.title, folder.id);
+            if let Some(children) = children.get(folder.id.as_str()) {
+                stack.extend(children.iter().rev().map(|child| (*child, depth + 1)));
+            }
+        }
+    }
+
+    pub fn notes(&self, notes: &[NoteShortInfo]) {
+        if self.json {
+            print_json(Value::Array(notes.iter().map(note_json).collect()));
+            return;
+        }
+        for note in notes {
+            println!("{}  {}  {}", note.id, timestamp(note.updated_at), note.title);
+        }
+    }
+
+    pub fn note(&self, note: &GetNoteByIdResponse) {
+        if self.json {
+            print_json(json!({
+                "id": note.id,
+                "folderId": note.folder_id,
+                "title": note.title,
+                "text": note.text,
+            }));
+        } else {
+            println!("Id:     {}", note.id);
+            println!("Folder: {}", note.folder_id);
+            println!("Title:  {}", note.title);
+            println!();
+            println!("{}", note.text);
+        }
+    }
+
+    // Prints the text as is, so it can be piped into a file.
+    pub fn text(&self, text: &str) {
+        if self.json {
+            print_json(json!({ "text": text }));
+        } else {
+            print!("{}", text);
+            let _ = io::stdout().flush();
+        }
+    }
+
+    // Reports progress on stderr, keeping stdout for the result.
+    pub fn sync_progress(&self, progress: &SyncProgress) {
+        if !self.json {
+            eprintln!("Synchronized {} of {}", progress.done, progress.total);
+        }
+    }
+
+    #[cfg(all(unix, feature = "server"))]
+    pub fn serving(&self, socket_path: &Path, token_path: &Path) {
+        if self.json {
+            print_json(json!({
+                "socketPath": socket_path.to_string_lossy(),
+                "tokenPath": token_path.to_string_lossy(),
+            }));
+        } else {
+            println!("Listening on {}", socket_path.display());
+            println!("Token in {}", token_path.display());
+        }
+    }
+
+    pub fn error(&self, error: &Error) {
+        if self.json {
+            print_json(json!({
+                "error": {
+                    "code": format!("{:?}", error.code()),
+                    "message": error.message,
+                    "field": error.field,
+                    "retryable": error.retryable,
+                    "coreCode": error.core_code,
+                }
+            }));
+        } else {
+            eprintln!("giganotes: {}", error.message);
+        }
+    }
+
+    fn id(&self, key: &str, id: &str) {
+        if self.json {
+            print_json(json!({ key: id }));
+        } else {
+            println!("{}", id);
+        }
+    }
+}
+
+fn folder_json(folder: &Folder) -> Value {
+    json!({
+        "id": folder.id,
+        "parentId": folder.parent_id,
+        "title": folder.title,
+        "level": folder.level,
+        "createdAt": folder.created_at,
+        "updatedAt": folder.updated_at,
+    })
+}
+
+fn note_json(note: &NoteShortInfo) -> Value {
+    json!({
+        "id": note.id,
+        "folderId": note.folder_id,
+        "title": note.title,
+        "createdAt": note.created_at,
+        "updatedAt": note.updated_at,
+    })
+}
+
+// Formats milliseconds since the Unix epoch, or prints them as is if they
+// are out of range.
+fn timestamp(millis: i64) -> String {
+    match Utc.timestamp_millis_opt(millis).single() {
+        Some(time) => time.format("%Y-%m-%d %H:%M").to_string(),
+        None => millis.to_string(),
+    }
+}
+
+fn print_json(value: Value) {
+    println!("{}", value);
+}
@@ -0,0 +1,161 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+// Commands understood by the core. The discriminants are the command indices
+// used on the JS side and must be kept in sync with lib/index.js. Index 4 is
+// not used by the JS layer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Command {
+    InitData = 1,
+    CreateNote = 2,
+    GetNotesByFolder = 3,
+    GetNoteById = 5,
+    GetFolderById = 6,
+    Synchronize = 7,
+    Login = 8,
+    GetLastLoginData = 9,
+    GetRootFolder = 10,
+    GetAllFolders = 11,
+    GetAllNotes = 12,
+    CreateFolder = 13,
+    UpdateNote = 14,
+    UpdateFolder = 15,
+    RemoveNote = 16,
+    RemoveFolder = 17,
+    SearchNotes = 18,
+    Register = 19,
+    AddToFavorites = 20,
+    RemoveFromFavorites = 21,
+    GetFavorites = 22,
+    Logout = 23,
+    LoginSocial = 24,
+}
+
+impl Command {
+    pub const ALL: [Command; 23] = [
+        Command::InitData,
+        Command::CreateNote,
+        Command::GetNotesByFolder,
+        Command::GetNoteById,
+        Command::GetFolderById,
+        Command::Synchronize,
+        Command::Login,
+        Command::GetLastLoginData,
+        Command::GetRootFolder,
+        Command::GetAllFolders,
+        Command::GetAllNotes,
+        Command::CreateFolder,
+        Command::UpdateNote,
+        Command::UpdateFolder,
+        Command::RemoveNote,
+        Command::RemoveFolder,
+        Command::SearchNotes,
+        Command::Register,
+        Command::AddToFavorites,
+        Command::RemoveFromFavorites,
+        Command::GetFavorites,
+        Command::Logout,
+        Command::LoginSocial,
+    ];
+
+    // Parses a command index coming from JS, where every number is a double.
+    pub fn from_index(index: f64) -> Result<Command, CommandError> {
+        if !index.is_finite() || index.fract() != 0.0 {
+            return Err(CommandError::NotAnInteger(index));
+        }
+        if index < i64::MIN as f64 || index > i64::MAX as f64 {
+            return Err(CommandError::Unknown(index as i64));
+        }
+        Command::try_from(index as i64)
+    }
+
+    pub fn index(self) -> i64 {
+        self as i64
+    }
+
+    // The index passed to the core entry points, which still take an `i8`.
+    // Every variant fits, so the cast can't truncate.
+    pub fn core_index(self) -> i8 {
+        self as i8
+    }
+
+    // Whether the command only reads, leaving the local store, the session
+    // and the server untouched.
+    pub fn is_read_only(self) -> bool {
+        matches!(
+            self,
+            Command::GetNotesByFolder
+                | Command::GetNoteById
+                | Command::GetFolderById
+                | Command::GetLastLoginData
+                | Command::GetRootFolder
+                | Command::GetAllFolders
+                | Command::GetAllNotes
+                | Command::SearchNotes
+                | Command::GetFavorites
+        )
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Command::InitData => "InitData",
+            Command::CreateNote => "CreateNote",
+            Command::GetNotesByFolder => "GetNotesByFolder",
+            Command::GetNoteById => "GetNoteById",
+            Command::GetFolderById => "GetFolderById",
+            Command::Synchronize => "Synchronize",
+            Command::Login => "Login",
+            Command::GetLastLoginData => "GetLastLoginData",
+            Command::GetRootFolder => "GetRootFolder",
+            Command::GetAllFolders => "GetAllFolders",
+            Command::GetAllNotes => "GetAllNotes",
+            Command::CreateFolder => "CreateFolder",
+            Command::UpdateNote => "UpdateNote",
+            Command::UpdateFolder => "UpdateFolder",
+            Command::RemoveNote => "RemoveNote",
+            Command::RemoveFolder => "RemoveFolder",
+            Command::SearchNotes => "SearchNotes",
+            Command::Register => "Register",
+            Command::AddToFavorites => "AddToFavorites",
+            Command::RemoveFromFavorites => "RemoveFromFavorites",
+            Command::GetFavorites => "GetFavorites",
+            Command::Logout => "Logout",
+            Command::LoginSocial => "LoginSocial",
+        }
+    }
+}
+
+impl TryFrom<i64> for Command {
+    type Error = CommandError;
+
+    fn try_from(index: i64) -> Result<Self, Self::Error> {
+        Command::ALL
+            .iter()
+            .copied()
+            .find(|command| command.index() == index)
+            .ok_or(CommandError::Unknown(index))
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.name(), self.index())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommandError {
+    NotAnInteger(f64),
+    Unknown(i64),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommandError::NotAnInteger(index) => {
+                write!(f, "Command index must be an integer, got {}", index)
+            }
+            CommandError::Unknown(index) => write!(f, "Unknown command index {}", index),
+        }
+    }
+}
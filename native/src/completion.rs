@@ -0,0 +1,126 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use prost::Message;
+
+use crate::backend;
+use crate::cancel;
+use crate::command::Command;
+use crate::dispatch::{failure_response, succeeded};
+use crate::events;
+use crate::messages::core_event::Payload;
+use crate::messages::{CoreEvent, Error, ErrorCode};
+
+// The core only starts a synchronization on its own runtime and answers
+// right away; whether it worked is reported later by a `syncFinished` or
+// `syncFailed` event. Synchronizations run through the bridge one at a time,
+// so the first of those events seen by a waiter is the outcome of its own
+// synchronization: the events of the previous one have all been delivered
+// before the next waiter subscribes.
+static SYNC: Mutex<()> = Mutex::new(());
+
+// Longest a synchronization is waited for. It holds up the synchronizations
+// after it and `shutdown` meanwhile.
+pub const SYNC_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+// Runs a synchronization through `start` and waits for its outcome. Returns
+// the response of `start` once the synchronization has finished, or a
+// failure with the error of its `syncFailed` event, or a `TIMED_OUT` one
+// after `SYNC_TIMEOUT`. Stops waiting with a `CANCELLED` or `TIMED_OUT` error
+// as well once the command is cancelled or its deadline has passed, see
+// `cancel::current`; the core can't be stopped, so the synchronization may
+// carry on. A synchronization the core refuses to start fails with the
+// core's response. With a core which doesn't report its events as
+// `CoreEvent`s, see `backend::Backend`, the response of `start` is returned
+// right away.
+pub fn synchronize<F: FnOnce() -> Vec<u8>>(start: F) -> Vec<u8> {
+    if !backend::core_events() {
+        return start();
+    }
+    let _sync = SYNC.lock().unwrap_or_else(PoisonError::into_inner);
+
+    // Subscribed before starting, so the outcome can't be missed.
+    let (tx, rx) = mpsc::channel();
+    let events_tx = tx.clone();
+    let _subscription = events::subscribe(
+        Box::new(move |event| {
+            let _ = events_tx.send(Wake::Event(event.data));
+        }),
+        None,
+        usize::MAX,
+    );
+
+    let response = start();
+    if !succeeded(&response) {
+        return response;
+    }
+
+    let (cancel, deadline) = cancel::current();
+    let _waiter = cancel.as_ref().map(|cancel| {
+        cancel.on_cancel(move || {
+            let _ = tx.send(Wake::Cancelled);
+        })
+    });
+    let timeout = Instant::now() + SYNC_TIMEOUT;
+    let wait_until = deadline.map_or(timeout, |deadline| deadline.min(timeout));
+    loop {
+        if cancel.as_ref().is_some_and(cancel::CancelHandle::is_cancelled) {
+            return failure_response(still_running(cancel::cancelled(Command::Synchronize)));
+        }
+        let data = match rx.recv_timeout(wait_until.saturating_duration_since(Instant::now())) {
+            Ok(Wake::Event(data)) => data,
+            Ok(Wake::Cancelled) => continue,
+            Err(RecvTimeoutError::Timeout) if wait_until < timeout => {
+                return failure_response(still_running(cancel::timed_out(Command::Synchronize)))
+            }
+            Err(RecvTimeoutError::Timeout) => return failure_response(sync_timed_out()),
+            // The subscription keeps the channel open.
+            Err(RecvTimeoutError::Disconnected) => return response,
+        };
+        match CoreEvent::decode(&data[..]).ok().and_then(|event| event.payload) {
+            Some(Payload::SyncFinished(_)) => return response,
+            Some(Payload::SyncFailed(failed)) => {
+                return failure_response(failed.error.unwrap_or_else(sync_failed))
+            }
+            _ => {}
+        }
+    }
+}
+
+// What wakes up a synchronization waiting for its outcome.
+enum Wake {
+    Event(Vec<u8>),
+    Cancelled,
+}
+
+// Notes on `error` of a synchronization given up on that the core carries on
+// with it.
+fn still_running(mut error: Error) -> Error {
+    error.message.push_str(", the synchronization may still be running in the core");
+    error
+}
+
+fn sync_timed_out() -> Error {
+    let mut error = Error {
+        message: format!(
+            "Synchronization did not finish within {} seconds, it may still be running in the core",
+            SYNC_TIMEOUT.as_secs()
+        ),
+        retryable: true,
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::TimedOut);
+    error
+}
+
+// Error of a `syncFailed` event which doesn't carry one.
+fn sync_failed() -> Error {
+    let mut error = Error {
+        message: "Synchronization failed".to_string(),
+        retryable: true,
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::NetworkError);
+    error
+}
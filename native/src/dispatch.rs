@@ -0,0 +1,238 @@
+use std::time::Instant;
+
+use prost::Message;
+
+use crate::command::Command;
+use crate::completion;
+use crate::lifecycle;
+use crate::metrics;
+use crate::messages::{
+    AddToFavorites, CreateFolder, CreateNote, Error, ErrorCode, GetAllFolders, GetAllNotes,
+    GetFavorites, GetFolderById, GetLastLoginData, GetNoteById, GetNotesList, GetRootFolder,
+    InitData, Login, LoginSocial, Logout, RemoveFolder, RemoveFromFavorites, RemoveNote,
+    ResponseStatus, SearchNotes, Synchronize, UpdateFolder, UpdateNote,
+};
+
+// Signature of the core entry points, `handle_command` and
+// `handle_async_command`.
+pub type CoreEntry = fn(i8, &[u8], usize) -> Vec<u8>;
+
+// Runs `command` through the core. Requests failing validation or arriving
+// after a shutdown are answered by the bridge itself, and failed responses
+// get a structured `error` filled in. `Synchronize` returns once the
+// synchronization has finished, see `completion`.
+pub fn run(command: Command, data: &[u8], entry: CoreEntry) -> Vec<u8> {
+    match lifecycle::enter(command) {
+        Ok(_permit) => run_admitted(command, data, entry),
+        Err(error) => record(command, data, Instant::now(), failure_response(error)),
+    }
+}
+
+// Runs `command` through the core once `run` has admitted it.
+fn run_admitted(command: Command, data: &[u8], entry: CoreEntry) -> Vec<u8> {
+    let start = Instant::now();
+    let response = match validate(command, data) {
+        Ok(payload) => {
+            let run = || entry(command.core_index(), &payload, payload.len());
+            let response = match command {
+                Command::Synchronize => completion::synchronize(run),
+                _ => run(),
+            };
+            annotate(command, response)
+        }
+        Err(error) => failure_response(error),
+    };
+    record(command, data, start, response)
+}
+
+// Whether an encoded response of any type reports success.
+pub fn succeeded(response: &[u8]) -> bool {
+    ResponseStatus::decode(response).is_ok_and(|status| status.success)
+}
+
+// Encodes a response for a request which never reached the core. Thanks to
+// the layout shared by all responses it decodes as any response type.
+pub fn failure_response(error: Error) -> Vec<u8> {
+    let status = ResponseStatus {
+        success: false,
+        error_code: error.code,
+        error: Some(error),
+    };
+    encode(&status)
+}
+
+// Fills in the `error` of a failed core response from the numeric
+// `errorCode` the core reported, see `core_error`.
+fn annotate(command: Command, mut response: Vec<u8>) -> Vec<u8> {
+    let status = match ResponseStatus::decode(&response[..]) {
+        Ok(status) => status,
+        Err(_) => return response,
+    };
+    if status.success || status.error.is_some() {
+        return response;
+    }
+
+    // Appending a field to an encoded message merges it into the message, so
+    // the concrete response type doesn't need to be known here.
+    let error = ResponseStatus {
+        error: Some(core_error(command, status.error_code)),
+        ..Default::default()
+    };
+    response.extend(encode(&error));
+    response
+}
+
+// Categorizes a failure by the kind of command that failed. The numeric code
+// is passed on as `core_code`, but isn't interpreted: giganotes-core doesn't
+// document the codes it reports.
+fn core_error(command: Command, core_code: i32) -> Error {
+    let (code, message, retryable) = command_error(command);
+
+    let mut error = Error {
+        message: format!("{}: {} failed with core error {}", message, command, core_code),
+        retryable,
+        core_code,
+        ..Default::default()
+    };
+    error.set_code(code);
+    error
+}
+
+fn command_error(command: Command) -> (ErrorCode, &'static str, bool) {
+    match command {
+        Command::Login | Command::LoginSocial | Command::Register | Command::Logout => (
+            ErrorCode::AuthFailed,
+            "Authentication failed",
+            false,
+        ),
+        Command::GetLastLoginData => (ErrorCode::AuthFailed, "No valid login data", false),
+        Command::Synchronize => (ErrorCode::NetworkError, "Synchronization failed", true),
+        Command::GetNoteById => (ErrorCode::NotFound, "Note not found", false),
+        Command::GetFolderById => (ErrorCode::NotFound, "Folder not found", false),
+        Command::GetRootFolder => (ErrorCode::NotFound, "Root folder not found", false),
+        Command::InitData => (ErrorCode::StorageError, "Failed to open the data path", false),
+        Command::CreateNote
+        | Command::CreateFolder
+        | Command::UpdateNote
+        | Command::UpdateFolder
+        | Command::RemoveNote
+        | Command::RemoveFolder
+        | Command::AddToFavorites
+        | Command::RemoveFromFavorites => {
+            (ErrorCode::StorageError, "Failed to write to the local store", true)
+        }
+        Command::GetNotesByFolder
+        | Command::GetAllFolders
+        | Command::GetAllNotes
+        | Command::SearchNotes
+        | Command::GetFavorites => {
+            (ErrorCode::StorageError, "Failed to read from the local store", true)
+        }
+    }
+}
+
+pub fn validation_error(message: &str, field: &str) -> Error {
+    let mut error = Error {
+        message: message.to_string(),
+        field: field.to_string(),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::ValidationFailed);
+    error
+}
+
+// Checks the payload of `command` before it is handed to the core, and
+// returns it re-encoded. Every payload has to decode as the request of its
+// command, so malformed bytes never reach the core, and the core sees the
+// request exactly as it was checked: unknown fields are dropped and a field
+// repeated in the payload keeps its last value, whatever the core's own
+// decoder would make of it. Ids of the entities a command addresses are
+// required, and numbers used as counts or levels can't be negative.
+fn validate(command: Command, data: &[u8]) -> Result<Vec<u8>, Error> {
+    match command {
+        Command::InitData => checked(data, |r: &InitData| require(&r.data_path, "dataPath")),
+        Command::CreateNote => canonical::<CreateNote>(data),
+        Command::GetNotesByFolder => canonical::<GetNotesList>(data),
+        Command::GetNoteById => checked(data, |r: &GetNoteById| require(&r.note_id, "noteId")),
+        Command::GetFolderById => {
+            checked(data, |r: &GetFolderById| require(&r.folder_id, "folderId"))
+        }
+        Command::Synchronize => canonical::<Synchronize>(data),
+        Command::Login | Command::Register => canonical::<Login>(data),
+        Command::GetLastLoginData => canonical::<GetLastLoginData>(data),
+        Command::GetRootFolder => canonical::<GetRootFolder>(data),
+        Command::GetAllFolders => canonical::<GetAllFolders>(data),
+        Command::GetAllNotes => checked(data, |r: &GetAllNotes| {
+            non_negative(r.offset, "offset")?;
+            non_negative(r.limit, "limit")
+        }),
+        Command::CreateFolder => canonical::<CreateFolder>(data),
+        Command::UpdateNote => checked(data, |r: &UpdateNote| require(&r.id, "id")),
+        Command::UpdateFolder => checked(data, |r: &UpdateFolder| {
+            require(&r.id, "id")?;
+            non_negative(r.level, "level")
+        }),
+        Command::RemoveNote => checked(data, |r: &RemoveNote| require(&r.note_id, "noteId")),
+        Command::RemoveFolder => {
+            checked(data, |r: &RemoveFolder| require(&r.folder_id, "folderId"))
+        }
+        Command::SearchNotes => canonical::<SearchNotes>(data),
+        Command::AddToFavorites => {
+            checked(data, |r: &AddToFavorites| require(&r.note_id, "noteId"))
+        }
+        Command::RemoveFromFavorites => {
+            checked(data, |r: &RemoveFromFavorites| require(&r.note_id, "noteId"))
+        }
+        Command::GetFavorites => canonical::<GetFavorites>(data),
+        Command::Logout => canonical::<Logout>(data),
+        Command::LoginSocial => canonical::<LoginSocial>(data),
+    }
+}
+
+// Decodes `data` as `M`, checks it with `check` and re-encodes it.
+fn checked<M, F>(data: &[u8], check: F) -> Result<Vec<u8>, Error>
+where
+    M: Message + Default,
+    F: FnOnce(&M) -> Result<(), Error>,
+{
+    let request = decode::<M>(data)?;
+    check(&request)?;
+    Ok(encode(&request))
+}
+
+fn canonical<M: Message + Default>(data: &[u8]) -> Result<Vec<u8>, Error> {
+    checked(data, |_: &M| Ok(()))
+}
+
+fn decode<M: Message + Default>(data: &[u8]) -> Result<M, Error> {
+    M::decode(data).map_err(|err| validation_error(&format!("Malformed request: {}", err), ""))
+}
+
+fn require(value: &str, field: &str) -> Result<(), Error> {
+    if value.is_empty() {
+        Err(validation_error(&format!("{} is required", field), field))
+    } else {
+        Ok(())
+    }
+}
+
+fn non_negative(value: i32, field: &str) -> Result<(), Error> {
+    if value < 0 {
+        Err(validation_error(&format!("{} can't be negative", field), field))
+    } else {
+        Ok(())
+    }
+}
+
+pub fn encode<M: Message>(message: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(message.encoded_len());
+    message
+        .encode(&mut buf)
+        .expect("Vec<u8> has enough capacity for any message");
+    buf
+}
+
+fn record(command: Command, data: &[u8], start: Instant, response: Vec<u8>) -> Vec<u8> {
+    metrics::record_command(command, data, &response, start.elapsed());
+    response
+}
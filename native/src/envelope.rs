@@ -0,0 +1,150 @@
+use prost::encoding::{encode_key, encode_varint, WireType};
+use prost::Message;
+
+use crate::backend::{handle_async_command, handle_command};
+use crate::command::Command;
+use crate::dispatch::{self, encode, succeeded, validation_error, CoreEntry};
+use crate::messages::request::Command as RequestCommand;
+use crate::messages::{
+    Error, ErrorCode, Request, RequestSequence, RequestSequenceResponse, Response,
+};
+
+// Runs an encoded `Request` and returns the encoded `Response`.
+pub fn handle(data: &[u8]) -> Vec<u8> {
+    match Request::decode(data) {
+        Ok(request) => handle_request(request, dispatch::run),
+        Err(err) => failure(0, malformed(err)),
+    }
+}
+
+// Runs the requests of an encoded `RequestSequence` in order and returns an
+// encoded `RequestSequenceResponse`. Each request is admitted on its own, so
+// other commands may run in between, and nothing is rolled back when one
+// fails.
+pub fn handle_sequence(data: &[u8]) -> Vec<u8> {
+    let sequence = match RequestSequence::decode(data) {
+        Ok(sequence) => sequence,
+        Err(err) => return sequence_failure(malformed(err)),
+    };
+
+    let mut response = Vec::new();
+    let mut failed = false;
+    for request in sequence.requests {
+        let result = if failed {
+            failure(request.request_id, aborted())
+        } else {
+            let mut ok = false;
+            let result = handle_request(request, |command, payload, entry| {
+                let result = dispatch::run(command, payload, entry);
+                ok = succeeded(&result);
+                result
+            });
+            failed = sequence.stop_on_error && !ok;
+            result
+        };
+
+        // Appends `result` to the `responses` of the `RequestSequenceResponse`.
+        encode_key(1, WireType::LengthDelimited, &mut response);
+        encode_varint(result.len() as u64, &mut response);
+        response.extend_from_slice(&result);
+    }
+    response
+}
+
+// Runs the command of `request` through `run` and returns the encoded
+// `Response`.
+fn handle_request<F>(request: Request, run: F) -> Vec<u8>
+where
+    F: FnOnce(Command, &[u8], CoreEntry) -> Vec<u8>,
+{
+    let (command, payload, tag) = match request.command {
+        Some(command) => route(command),
+        None => {
+            return failure(
+                request.request_id,
+                validation_error("command is required", "command"),
+            );
+        }
+    };
+
+    let entry: CoreEntry = match command {
+        Command::Synchronize => handle_async_command,
+        _ => handle_command,
+    };
+    let result = run(command, &payload, entry);
+    respond(request.request_id, tag, &result)
+}
+
+// Maps the command of a request to the command it runs, its encoded payload
+// and the tag it has in both `Request.command` and `Response.body`.
+fn route(command: RequestCommand) -> (Command, Vec<u8>, u32) {
+    match command {
+        RequestCommand::InitData(m) => (Command::InitData, encode(&m), 2),
+        RequestCommand::CreateNote(m) => (Command::CreateNote, encode(&m), 3),
+        RequestCommand::GetNotesByFolder(m) => (Command::GetNotesByFolder, encode(&m), 4),
+        RequestCommand::GetNoteById(m) => (Command::GetNoteById, encode(&m), 5),
+        RequestCommand::GetFolderById(m) => (Command::GetFolderById, encode(&m), 6),
+        RequestCommand::Synchronize(m) => (Command::Synchronize, encode(&m), 7),
+        RequestCommand::Login(m) => (Command::Login, encode(&m), 8),
+        RequestCommand::GetLastLoginData(m) => (Command::GetLastLoginData, encode(&m), 9),
+        RequestCommand::GetRootFolder(m) => (Command::GetRootFolder, encode(&m), 10),
+        RequestCommand::GetAllFolders(m) => (Command::GetAllFolders, encode(&m), 11),
+        RequestCommand::GetAllNotes(m) => (Command::GetAllNotes, encode(&m), 12),
+        RequestCommand::CreateFolder(m) => (Command::CreateFolder, encode(&m), 13),
+        RequestCommand::UpdateNote(m) => (Command::UpdateNote, encode(&m), 14),
+        RequestCommand::UpdateFolder(m) => (Command::UpdateFolder, encode(&m), 15),
+        RequestCommand::RemoveNote(m) => (Command::RemoveNote, encode(&m), 16),
+        RequestCommand::RemoveFolder(m) => (Command::RemoveFolder, encode(&m), 17),
+        RequestCommand::SearchNotes(m) => (Command::SearchNotes, encode(&m), 18),
+        RequestCommand::Register(m) => (Command::Register, encode(&m), 19),
+        RequestCommand::AddToFavorites(m) => (Command::AddToFavorites, encode(&m), 20),
+        RequestCommand::RemoveFromFavorites(m) => {
+            (Command::RemoveFromFavorites, encode(&m), 21)
+        }
+        RequestCommand::GetFavorites(m) => (Command::GetFavorites, encode(&m), 22),
+        RequestCommand::Logout(m) => (Command::Logout, encode(&m), 23),
+        RequestCommand::LoginSocial(m) => (Command::LoginSocial, encode(&m), 24),
+    }
+}
+
+// Wraps the encoded response of a command in a `Response`. The response is
+// appended as the `body` field with tag `tag`, so it never needs to be
+// decoded into its concrete type.
+fn respond(request_id: u64, tag: u32, result: &[u8]) -> Vec<u8> {
+    let mut response = encode(&Response {
+        request_id,
+        ..Default::default()
+    });
+    encode_key(tag, WireType::LengthDelimited, &mut response);
+    encode_varint(result.len() as u64, &mut response);
+    response.extend_from_slice(result);
+    response
+}
+
+fn failure(request_id: u64, error: Error) -> Vec<u8> {
+    encode(&Response {
+        request_id,
+        body: None,
+        error: Some(error),
+    })
+}
+
+fn sequence_failure(error: Error) -> Vec<u8> {
+    encode(&RequestSequenceResponse {
+        responses: Vec::new(),
+        error: Some(error),
+    })
+}
+
+fn malformed(err: prost::DecodeError) -> Error {
+    validation_error(&format!("Malformed request: {}", err), "")
+}
+
+fn aborted() -> Error {
+    let mut error = Error {
+        message: "Not run, an earlier request of the sequence failed".to_string(),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::Aborted);
+    error
+}
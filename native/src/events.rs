@@ -0,0 +1,317 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use prost::Message;
+
+use crate::backend;
+use crate::dispatch::encode;
+use crate::messages::core_event::Payload;
+use crate::messages::{CoreEvent, ResyncRequired};
+use crate::metrics;
+
+// Name of events which don't decode as a typed `CoreEvent`. The payload is
+// passed to JS as is.
+pub const UNTYPED_EVENT: &str = "coreEvent";
+
+// Names of every event emitted to JS.
+pub const EVENT_NAMES: [&str; 11] = [
+    "syncStarted",
+    "syncProgress",
+    "syncFinished",
+    "syncFailed",
+    "noteChanged",
+    "folderChanged",
+    "authExpired",
+    "log",
+    "eventsDropped",
+    "resyncRequired",
+    UNTYPED_EVENT,
+];
+
+// Name under which an encoded core event is emitted to JS. Typed events are
+// named after the `payload` field of `CoreEvent` that is set.
+pub fn event_name(data: &[u8]) -> &'static str {
+    match CoreEvent::decode(data) {
+        Ok(CoreEvent {
+            payload: Some(payload),
+        }) => payload_name(&payload),
+        _ => UNTYPED_EVENT,
+    }
+}
+
+fn payload_name(payload: &Payload) -> &'static str {
+    match payload {
+        Payload::SyncStarted(_) => "syncStarted",
+        Payload::SyncProgress(_) => "syncProgress",
+        Payload::SyncFinished(_) => "syncFinished",
+        Payload::SyncFailed(_) => "syncFailed",
+        Payload::NoteChanged(_) => "noteChanged",
+        Payload::FolderChanged(_) => "folderChanged",
+        Payload::AuthExpired(_) => "authExpired",
+        Payload::Log(_) => "log",
+        Payload::EventsDropped(_) => "eventsDropped",
+        Payload::ResyncRequired(_) => "resyncRequired",
+    }
+}
+
+// Number of events kept for subscribers catching up on missed events.
+pub const REPLAY_LOG_CAPACITY: usize = 4096;
+
+// An event as delivered to a subscriber.
+pub struct Event {
+    // Sequence number of the event. Numbers increase by one with every
+    // event of the process, starting at 1. 0 for events which are never
+    // replayed: forwarded log records, and events addressed to a single
+    // subscriber, like `resyncRequired`.
+    pub seq: u64,
+
+    pub data: Vec<u8>,
+
+    // Whether the event is replayed from the replay log while subscribing,
+    // rather than delivered by the pump thread.
+    pub replayed: bool,
+}
+
+// Delivers an event to one subscriber. Called on the pump thread, the log
+// thread, or on the subscribing thread for replayed events, so it should
+// hand the event off instead of waiting for the subscriber.
+pub type Sink = Box<dyn Fn(Event) + Send + Sync>;
+
+type SharedSink = Arc<dyn Fn(Event) + Send + Sync>;
+
+struct Hub {
+    // Sinks of every live subscription, keyed by subscription id. Events are
+    // handed to a snapshot of them taken under the lock, but pushed after
+    // releasing it, so a sink blocking the pump, e.g. on a full queue with
+    // `Overflow::Block`, can't hold up subscribing or unsubscribing.
+    subscribers: Vec<(u64, SharedSink)>,
+
+    // The latest events, oldest first.
+    log: VecDeque<(u64, Vec<u8>)>,
+
+    // Sequence number of the latest event, 0 before the first one.
+    seq: u64,
+
+    // Sequence number of the latest event evicted from `log`.
+    evicted: u64,
+}
+
+static HUB: Mutex<Hub> = Mutex::new(Hub {
+    subscribers: Vec::new(),
+    log: VecDeque::new(),
+    seq: 0,
+    evicted: 0,
+});
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(0);
+
+// A channel's registration for core events. Dropping it unsubscribes. An
+// event whose delivery was already under way may still reach the sink after
+// that.
+pub struct Subscription {
+    id: u64,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        hub().subscribers.retain(|(id, _)| *id != self.id);
+    }
+}
+
+// Subscribes `sink` to every event of the core. Each subscription gets its
+// own copy of every event delivered after it was created.
+//
+// With `since`, the sequence number of the last event a subscriber has seen,
+// the events it missed are replayed first. If some of them are no longer in
+// the replay log, `since` is from before a restart of the process, or there
+// are more of them than `capacity`, the number of events the subscriber
+// queues before dropping any, it gets a `resyncRequired` event instead and
+// has to reload everything.
+pub fn subscribe(sink: Sink, since: Option<u64>, capacity: usize) -> Subscription {
+    start_pump();
+
+    let mut hub = hub();
+    if let Some(since) = since {
+        let missed = hub.log.iter().filter(|(seq, _)| *seq > since).count();
+        if since < hub.evicted || since > hub.seq || missed > capacity {
+            sink(Event {
+                seq: 0,
+                data: resync_required(hub.seq),
+                replayed: true,
+            });
+        } else {
+            for (seq, data) in hub.log.iter().filter(|(seq, _)| *seq > since) {
+                sink(Event {
+                    seq: *seq,
+                    data: data.clone(),
+                    replayed: true,
+                });
+            }
+        }
+    }
+
+    let id = NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed);
+    hub.subscribers.push((id, Arc::from(sink)));
+
+    Subscription { id }
+}
+
+// Channel of the log records forwarded by `logging`, drained by a thread of
+// its own. Log records bypass the pump, so they neither take up sequence
+// numbers nor are kept for replay, and publishing one takes no lock held
+// while events are delivered, as a panic hook logging a panic might.
+static LOGS: OnceLock<mpsc::Sender<Vec<u8>>> = OnceLock::new();
+
+// Publishes a forwarded log record, an encoded "log" `CoreEvent`, to every
+// subscriber. Its sequence number is 0.
+pub fn publish_log(event: Vec<u8>) {
+    let logs = LOGS.get_or_init(|| {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for event in rx {
+                let sinks = hub().sinks();
+                deliver(&sinks, 0, event);
+            }
+        });
+        tx
+    });
+    let _ = logs.send(event);
+}
+
+// How long the forwarding thread waits for an event of the core before
+// checking whether the pump has been stopped. The thread can't just block in
+// `recv`: the channel belongs to the core, so nothing could be sent to wake
+// it when the pump is stopped, and it would hold the receiver, keeping a
+// restarted pump from reading the events pushed meanwhile. Waking ten times
+// a second while idle is the accepted cost.
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// The threads of the running pump, `None` while stopped.
+static PUMP: Mutex<Option<Pump>> = Mutex::new(None);
+
+struct Pump {
+    stop: Arc<AtomicBool>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+// Starts the pump thread, which pushes each event to the subscribers as soon
+// as it arrives, and a thread forwarding the events of `backend::events` to
+// it, unless they are running already. The latter is the only reader of the
+// core's channel, so subscribers no longer compete for events. While idle,
+// the pump thread sits blocked in `recv`, and the forwarding thread wakes up
+// every `STOP_POLL_INTERVAL`.
+pub fn start_pump() {
+    let mut pump = PUMP.lock().unwrap_or_else(PoisonError::into_inner);
+    if pump.is_some() {
+        return;
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let receiver = backend::events();
+    let stopped = Arc::clone(&stop);
+    let forward = thread::spawn(move || {
+        let rx = match receiver.lock() {
+            Ok(rx) => rx,
+            Err(_) => return,
+        };
+        while !stopped.load(Ordering::SeqCst) {
+            match rx.recv_timeout(STOP_POLL_INTERVAL) {
+                Ok(event) => {
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        // Events pushed before the pump was stopped are still delivered.
+        for event in rx.try_iter() {
+            let _ = tx.send(event);
+        }
+    });
+
+    let pump_events = thread::spawn(move || {
+        for event in rx {
+            broadcast(event);
+        }
+    });
+
+    *pump = Some(Pump {
+        stop,
+        threads: vec![forward, pump_events],
+    });
+}
+
+// Stops the pump threads once they have delivered the events the core has
+// pushed so far. Events the core pushes later wait in its channel until the
+// pump is started again, by the next subscription or by `InitData`
+// restarting the bridge. Called by `lifecycle::shutdown`.
+pub fn stop_pump() {
+    let pump = PUMP.lock().unwrap_or_else(PoisonError::into_inner).take();
+    if let Some(pump) = pump {
+        pump.stop.store(true, Ordering::SeqCst);
+        // The pump thread exits once the forwarding thread has dropped its
+        // end of their channel.
+        for thread in pump.threads {
+            let _ = thread.join();
+        }
+    }
+}
+
+// Stamps `event` with the next sequence number, records it in the replay log
+// and hands it to every subscriber. Recording the event and taking the
+// snapshot of the subscribers happen at once, so a subscription created
+// meanwhile gets the event either replayed or delivered, never both.
+fn broadcast(event: Vec<u8>) {
+    let (seq, sinks) = {
+        let mut hub = hub();
+        hub.seq += 1;
+        let seq = hub.seq;
+
+        if hub.log.len() == REPLAY_LOG_CAPACITY {
+            if let Some((evicted, _)) = hub.log.pop_front() {
+                hub.evicted = evicted;
+            }
+        }
+        hub.log.push_back((seq, event.clone()));
+        (seq, hub.sinks())
+    };
+
+    deliver(&sinks, seq, event);
+}
+
+// Hands `event` to every sink of `sinks`.
+fn deliver(sinks: &[SharedSink], seq: u64, event: Vec<u8>) {
+    metrics::record_event_published(&event);
+
+    for sink in sinks {
+        sink(Event {
+            seq,
+            data: event.clone(),
+            replayed: false,
+        });
+    }
+}
+
+impl Hub {
+    fn sinks(&self) -> Vec<SharedSink> {
+        self.subscribers.iter().map(|(_, sink)| Arc::clone(sink)).collect()
+    }
+}
+
+fn resync_required(latest_seq: u64) -> Vec<u8> {
+    encode(&CoreEvent {
+        payload: Some(Payload::ResyncRequired(ResyncRequired { latest_seq })),
+    })
+}
+
+fn hub() -> MutexGuard<'static, Hub> {
+    HUB.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,60 @@
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use log::{error, log_enabled, Level};
+
+thread_local! {
+    // Backtrace captured by the panic hook for the panic that is currently
+    // unwinding on this thread. Picked up by `catch_panic`.
+    static BACKTRACE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// A panic caught at the boundary between the core and JS.
+pub struct Panic {
+    pub message: String,
+    pub backtrace: String,
+}
+
+// Installs a panic hook which logs every panic with its backtrace, falling
+// back to the default hook while logging is off. Panics on threads owned by
+// the core are logged too, even though they can't be rethrown to JS.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let backtrace = Backtrace::force_capture().to_string();
+            if log_enabled!(Level::Error) {
+                error!("Panic in giganotes core: {}\n{}", info, backtrace);
+            } else {
+                default_hook(info);
+            }
+            BACKTRACE.with(|cell| *cell.borrow_mut() = Some(backtrace));
+        }));
+    });
+}
+
+// Runs `f`, turning a panic into a `Panic` instead of unwinding into the
+// JS engine, which would abort the process.
+pub fn catch_panic<F: FnOnce() -> R, R>(f: F) -> Result<R, Panic> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| Panic {
+        message: panic_message(&*payload),
+        backtrace: BACKTRACE
+            .with(|cell| cell.borrow_mut().take())
+            .unwrap_or_default(),
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
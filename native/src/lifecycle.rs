@@ -0,0 +1,155 @@
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+use crate::backend;
+use crate::command::Command;
+use crate::events;
+use crate::messages::{Error, ErrorCode};
+
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+    Running,
+
+    // Waiting for the in-flight commands to finish. New commands are
+    // rejected already.
+    ShuttingDown,
+
+    ShutDown,
+}
+
+struct Lifecycle {
+    state: State,
+
+    // Number of commands running in the core, including one running alone.
+    in_flight: usize,
+
+    // Whether a command which runs alone, `InitData`, is running. Other
+    // commands wait for it to finish before they are admitted.
+    exclusive: bool,
+}
+
+// Only taken to admit commands and to see them finish, never while a command
+// runs, so a command can't hold up others by running long, nor deadlock by
+// running a nested command.
+static LIFECYCLE: Mutex<Lifecycle> = Mutex::new(Lifecycle {
+    state: State::Running,
+    in_flight: 0,
+    exclusive: false,
+});
+
+// Notified whenever a command finishes or the state changes.
+static CHANGED: Condvar = Condvar::new();
+
+// Held while a command runs in the core.
+pub struct Permit {
+    exclusive: bool,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut lifecycle = lifecycle();
+        lifecycle.in_flight -= 1;
+        if self.exclusive {
+            lifecycle.exclusive = false;
+        }
+        CHANGED.notify_all();
+    }
+}
+
+// Admits `command` into the core. `InitData` runs alone and restarts the
+// bridge after a shutdown. Rather than wait, maybe on the main thread, it is
+// rejected while other commands or a shutdown are running. Everything else
+// is rejected once shutting down.
+pub fn enter(command: Command) -> Result<Permit, Error> {
+    if command == Command::InitData {
+        let mut lifecycle = lifecycle();
+        if lifecycle.in_flight > 0 || lifecycle.state == State::ShuttingDown {
+            return Err(busy_error(command));
+        }
+        let restart = lifecycle.state == State::ShutDown;
+        lifecycle.state = State::Running;
+        let permit = admit_alone(lifecycle);
+        if restart {
+            events::start_pump();
+        }
+        return Ok(permit);
+    }
+
+    let mut lifecycle = lifecycle();
+    while lifecycle.exclusive {
+        lifecycle = wait(lifecycle);
+    }
+    if lifecycle.state != State::Running {
+        return Err(shut_down_error(command));
+    }
+    lifecycle.in_flight += 1;
+    Ok(Permit { exclusive: false })
+}
+
+fn admit_alone(mut lifecycle: MutexGuard<'_, Lifecycle>) -> Permit {
+    lifecycle.in_flight += 1;
+    lifecycle.exclusive = true;
+    Permit { exclusive: true }
+}
+
+fn shut_down_error(command: Command) -> Error {
+    let mut error = Error {
+        message: format!(
+            "{} rejected: the core has been shut down, call initData to restart it",
+            command
+        ),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::ShutDown);
+    error
+}
+
+fn busy_error(command: Command) -> Error {
+    let mut error = Error {
+        message: format!(
+            "{} rejected: other commands or a shutdown are still running, retry once they \
+             have finished",
+            command
+        ),
+        retryable: true,
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::Busy);
+    error
+}
+
+// Stops accepting commands and waits for the in-flight ones to finish, which
+// includes their writes to the local store and running synchronizations (see
+// `completion`). Then stops the event pump, once it has delivered the events
+// of those commands, and the core's worker, see `backend::stop`, if the
+// core can stop it; giganotes-core can't, its worker keeps running. Returns
+// right away if already shut down, or once another shutdown under way has
+// finished.
+pub fn shutdown() {
+    let mut current = lifecycle();
+    while current.state == State::ShuttingDown {
+        current = wait(current);
+    }
+    if current.state == State::ShutDown {
+        return;
+    }
+
+    current.state = State::ShuttingDown;
+    while current.in_flight > 0 {
+        current = wait(current);
+    }
+    drop(current);
+
+    events::stop_pump();
+    backend::stop();
+
+    lifecycle().state = State::ShutDown;
+    CHANGED.notify_all();
+}
+
+fn lifecycle() -> MutexGuard<'static, Lifecycle> {
+    LIFECYCLE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn wait(lifecycle: MutexGuard<'static, Lifecycle>) -> MutexGuard<'static, Lifecycle> {
+    CHANGED.wait(lifecycle).unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,263 @@
+use std::cmp;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError, RwLock};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::dispatch::encode;
+use crate::events;
+use crate::messages::core_event::Payload;
+use crate::messages::{CoreEvent, LogLevel, LogRecord};
+
+pub struct LogConfig {
+    // Level of records whose target matches none of the `filters`.
+    pub level: LevelFilter,
+
+    // Per-module levels as `(target prefix, level)`, e.g.
+    // `("giganotes_core::sync", LevelFilter::Trace)`. The longest matching
+    // prefix wins.
+    pub filters: Vec<(String, LevelFilter)>,
+
+    // Whether records are written to stdout.
+    pub console: bool,
+
+    pub file: Option<FileConfig>,
+
+    // Level up to which records are forwarded to JS as "log" events. Applies
+    // regardless of `level` and `filters`.
+    pub forward_level: LevelFilter,
+}
+
+pub struct FileConfig {
+    // Path of the log file, see `log_file_path`.
+    pub path: PathBuf,
+
+    // Size in bytes after which the file is rotated.
+    pub max_size: u64,
+
+    // Number of rotated files kept next to the current one, named
+    // `<path>.1` (the most recent) to `<path>.<max_files>`.
+    pub max_files: usize,
+}
+
+#[derive(Debug)]
+pub enum LogError {
+    // The log file isn't a relative path staying inside the data directory.
+    InvalidFile(String),
+
+    // The log file couldn't be opened.
+    File(io::Error),
+
+    // Another logger was installed for the `log` crate before this one,
+    // e.g. by the core, so records would never reach this one.
+    LoggerInstalled,
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LogError::InvalidFile(file) => write!(
+                f,
+                "Log file \"{}\" must be a relative path inside the data directory",
+                file
+            ),
+            LogError::File(err) => write!(f, "Failed to open log file: {}", err),
+            LogError::LoggerInstalled => write!(f, "Another logger is already installed"),
+        }
+    }
+}
+
+// Resolves the log file `file` inside `data_path`. `file` may name a
+// subdirectory, but can't be absolute or step out of `data_path` with `..`.
+pub fn log_file_path(data_path: &Path, file: &str) -> Result<PathBuf, LogError> {
+    let relative = Path::new(file);
+    let inside = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+    if file.is_empty() || !inside {
+        return Err(LogError::InvalidFile(file.to_string()));
+    }
+    Ok(data_path.join(relative))
+}
+
+impl LogConfig {
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.filters
+            .iter()
+            .filter(|(prefix, _)| target.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, level)| *level)
+            .unwrap_or(self.level)
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.filters
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(cmp::max(self.level, self.forward_level), cmp::max)
+    }
+}
+
+struct RotatingFile {
+    config: FileConfig,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFile {
+    fn open(config: FileConfig) -> io::Result<Self> {
+        if let Some(dir) = config.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.path)?;
+        let size = file.metadata()?.len();
+        Ok(RotatingFile { config, file, size })
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if self.size > 0 && self.size + line.len() as u64 > self.config.max_size {
+            self.rotate()?;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        let rotated = |i: usize| {
+            let mut path = self.config.path.clone().into_os_string();
+            path.push(format!(".{}", i));
+            PathBuf::from(path)
+        };
+
+        if self.config.max_files == 0 {
+            fs::remove_file(&self.config.path)?;
+        } else {
+            let _ = fs::remove_file(rotated(self.config.max_files));
+            for i in (1..self.config.max_files).rev() {
+                let _ = fs::rename(rotated(i), rotated(i + 1));
+            }
+            fs::rename(&self.config.path, rotated(1))?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.config.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+// The bridge's logger. Unlike the `log` crate's global logger it can be
+// reconfigured any number of times.
+struct BridgeLogger {
+    config: RwLock<Option<LogConfig>>,
+    file: Mutex<Option<RotatingFile>>,
+}
+
+static LOGGER: BridgeLogger = BridgeLogger {
+    config: RwLock::new(None),
+    file: Mutex::new(None),
+};
+
+impl Log for BridgeLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        match &*self.config.read().unwrap_or_else(PoisonError::into_inner) {
+            Some(config) => {
+                metadata.level() <= config.level_for(metadata.target())
+                    || metadata.level() <= config.forward_level
+            }
+            None => false,
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        let config = self.config.read().unwrap_or_else(PoisonError::into_inner);
+        let config = match &*config {
+            Some(config) => config,
+            None => return,
+        };
+
+        if record.level() <= config.forward_level {
+            events::publish_log(log_event(record));
+        }
+        if record.level() > config.level_for(record.target()) {
+            return;
+        }
+
+        let line = format!(
+            "{} {:<5} [{}] {}\n",
+            chrono::Local::now().format("%Y-%m-%d %H:%M:%S,%3f"),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        if config.console {
+            // Like a failing log file, a closed stdout can't be reported.
+            let _ = write!(io::stdout(), "{}", line);
+        }
+        if let Some(file) = &mut *self.file.lock().unwrap_or_else(PoisonError::into_inner) {
+            // There is nowhere left to report a failing log file to.
+            let _ = file.write_line(&line);
+        }
+    }
+
+    fn flush(&self) {
+        let _ = io::stdout().flush();
+        if let Some(file) = &mut *self.file.lock().unwrap_or_else(PoisonError::into_inner) {
+            let _ = file.file.flush();
+        }
+    }
+}
+
+// Encodes `record` as a "log" core event.
+fn log_event(record: &Record) -> Vec<u8> {
+    let mut log = LogRecord {
+        target: record.target().to_string(),
+        message: record.args().to_string(),
+        timestamp: chrono::Utc::now().timestamp_millis(),
+        ..Default::default()
+    };
+    log.set_level(match record.level() {
+        Level::Error => LogLevel::Error,
+        Level::Warn => LogLevel::Warn,
+        Level::Info => LogLevel::Info,
+        Level::Debug => LogLevel::Debug,
+        Level::Trace => LogLevel::Trace,
+    });
+
+    encode(&CoreEvent {
+        payload: Some(Payload::Log(log)),
+    })
+}
+
+// Whether `log::set_logger` accepted the bridge's logger, decided on first
+// use.
+static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+// Installs the bridge's logger on first use and applies `config`. May be
+// called again to reconfigure logging, e.g. once the data path is known.
+// Fails if another logger was installed first.
+pub fn configure(mut config: LogConfig) -> Result<(), LogError> {
+    if !*INSTALLED.get_or_init(|| log::set_logger(&LOGGER).is_ok()) {
+        return Err(LogError::LoggerInstalled);
+    }
+
+    let file = match config.file.take() {
+        Some(file) => Some(RotatingFile::open(file).map_err(LogError::File)?),
+        None => None,
+    };
+
+    log::set_max_level(config.max_level());
+    *LOGGER.file.lock().unwrap_or_else(PoisonError::into_inner) = file;
+    *LOGGER.config.write().unwrap_or_else(PoisonError::into_inner) = Some(config);
+    Ok(())
+}
@@ -0,0 +1,2 @@
+// Types generated by `prost` from protos/messages.proto, see build.rs.
+include!(concat!(env!("OUT_DIR"), "/gigamessages.rs"));
@@ -0,0 +1,278 @@
+use std::fmt::Write;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use crate::command::Command;
+use crate::dispatch::succeeded;
+use crate::messages;
+
+// Upper bounds of the latency histogram buckets, in seconds. Commands range
+// from sub-millisecond lookups to synchronizations taking many seconds.
+const LATENCY_BUCKETS: [f64; 14] = [
+    0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 10.0, 30.0,
+];
+
+#[derive(Clone)]
+struct Histogram {
+    // Observations per bucket, not cumulative. The last one counts the
+    // observations above the largest bound.
+    counts: [u64; LATENCY_BUCKETS.len() + 1],
+
+    // Sum of all observations in seconds.
+    sum: f64,
+}
+
+impl Histogram {
+    const fn new() -> Histogram {
+        Histogram {
+            counts: [0; LATENCY_BUCKETS.len() + 1],
+            sum: 0.0,
+        }
+    }
+
+    fn observe(&mut self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.counts[bucket] += 1;
+        self.sum += seconds;
+    }
+
+    fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    // Cumulative counts of the bounded buckets, as `(bound, count)`.
+    fn cumulative(&self) -> impl Iterator<Item = (f64, u64)> + '_ {
+        LATENCY_BUCKETS
+            .iter()
+            .zip(self.counts.iter())
+            .scan(0, |total, (bound, count)| {
+                *total += count;
+                Some((*bound, *total))
+            })
+    }
+
+    fn to_message(&self) -> messages::Histogram {
+        messages::Histogram {
+            buckets: self
+                .cumulative()
+                .map(|(le, count)| messages::HistogramBucket { le, count })
+                .collect(),
+            count: self.count(),
+            sum: self.sum,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct CommandStats {
+    calls: u64,
+    errors: u64,
+    request_bytes: u64,
+    response_bytes: u64,
+    latency: Histogram,
+}
+
+impl CommandStats {
+    const fn new() -> CommandStats {
+        CommandStats {
+            calls: 0,
+            errors: 0,
+            request_bytes: 0,
+            response_bytes: 0,
+            latency: Histogram::new(),
+        }
+    }
+}
+
+struct EventStats {
+    published: u64,
+    delivered: u64,
+    dropped: u64,
+    published_bytes: u64,
+    delivery_latency: Histogram,
+}
+
+struct Registry {
+    // Stats of every command, in the order of `Command::ALL`. Empty until
+    // the first command is recorded.
+    commands: Vec<CommandStats>,
+
+    events: EventStats,
+}
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+    commands: Vec::new(),
+    events: EventStats {
+        published: 0,
+        delivered: 0,
+        dropped: 0,
+        published_bytes: 0,
+        delivery_latency: Histogram::new(),
+    },
+});
+
+// Records a command run through the bridge, including commands answered by
+// the bridge itself, e.g. because they failed validation.
+pub fn record_command(command: Command, request: &[u8], response: &[u8], latency: Duration) {
+    let mut registry = registry();
+    if registry.commands.is_empty() {
+        registry.commands = vec![CommandStats::new(); Command::ALL.len()];
+    }
+    let i = Command::ALL
+        .iter()
+        .position(|known| *known == command)
+        .expect("every command is in Command::ALL");
+
+    let stats = &mut registry.commands[i];
+    stats.calls += 1;
+    if !succeeded(response) {
+        stats.errors += 1;
+    }
+    stats.request_bytes += request.len() as u64;
+    stats.response_bytes += response.len() as u64;
+    stats.latency.observe(latency);
+}
+
+pub fn record_event_published(event: &[u8]) {
+    let mut registry = registry();
+    registry.events.published += 1;
+    registry.events.published_bytes += event.len() as u64;
+}
+
+pub fn record_event_delivered(latency: Duration) {
+    let mut registry = registry();
+    registry.events.delivered += 1;
+    registry.events.delivery_latency.observe(latency);
+}
+
+pub fn record_events_dropped(count: u64) {
+    registry().events.dropped += count;
+}
+
+pub fn snapshot() -> messages::Metrics {
+    let registry = registry();
+    let empty = CommandStats::new();
+
+    let commands = Command::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, command)| {
+            let stats = registry.commands.get(i).unwrap_or(&empty);
+            messages::CommandMetrics {
+                name: command.name().to_string(),
+                index: command.index() as i32,
+                calls: stats.calls,
+                errors: stats.errors,
+                request_bytes: stats.request_bytes,
+                response_bytes: stats.response_bytes,
+                latency: Some(stats.latency.to_message()),
+            }
+        })
+        .collect();
+
+    let events = &registry.events;
+    messages::Metrics {
+        commands,
+        events: Some(messages::EventMetrics {
+            published: events.published,
+            delivered: events.delivered,
+            dropped: events.dropped,
+            published_bytes: events.published_bytes,
+            delivery_latency: Some(events.delivery_latency.to_message()),
+        }),
+    }
+}
+
+// Renders the metrics in the Prometheus text exposition format.
+pub fn prometheus() -> String {
+    let metrics = snapshot();
+    let mut out = String::new();
+
+    let commands = &metrics.commands;
+    let calls = "Commands run through the bridge.";
+    command_counter(&mut out, commands, "giganotes_command_calls_total", calls, |c| c.calls);
+    let errors = "Commands which failed.";
+    command_counter(&mut out, commands, "giganotes_command_errors_total", errors, |c| c.errors);
+    let name = "giganotes_command_request_bytes_total";
+    command_counter(&mut out, commands, name, "Bytes of command requests.", |c| {
+        c.request_bytes
+    });
+    let name = "giganotes_command_response_bytes_total";
+    command_counter(&mut out, commands, name, "Bytes of command responses.", |c| {
+        c.response_bytes
+    });
+
+    let name = "giganotes_command_duration_seconds";
+    header(&mut out, name, "Time taken by commands.", "histogram");
+    for command in &metrics.commands {
+        let labels = format!("command=\"{}\",", command.name);
+        histogram(&mut out, name, &labels, command.latency.as_ref());
+    }
+
+    let events = metrics.events.unwrap_or_default();
+    let counters = [
+        ("giganotes_events_published_total", "Events published to subscribers.", events.published),
+        ("giganotes_events_delivered_total", "Events handed to JS callbacks.", events.delivered),
+        ("giganotes_events_dropped_total", "Events dropped by full queues.", events.dropped),
+        ("giganotes_event_bytes_total", "Bytes of published events.", events.published_bytes),
+    ];
+    for (name, help, value) in counters.iter() {
+        header(&mut out, name, help, "counter");
+        let _ = writeln!(out, "{} {}", name, value);
+    }
+
+    let name = "giganotes_event_delivery_seconds";
+    header(&mut out, name, "Time from publishing an event to its delivery to JS.", "histogram");
+    histogram(&mut out, name, "", events.delivery_latency.as_ref());
+
+    out
+}
+
+fn command_counter<F>(
+    out: &mut String,
+    commands: &[messages::CommandMetrics],
+    name: &str,
+    help: &str,
+    value: F,
+) where
+    F: Fn(&messages::CommandMetrics) -> u64,
+{
+    header(out, name, help, "counter");
+    for command in commands {
+        let _ = writeln!(out, "{}{{command=\"{}\"}} {}", name, command.name, value(command));
+    }
+}
+
+fn header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+// Writes the series of a histogram. `labels` are prepended to the `le` label
+// of the buckets, and must end with a comma if not empty.
+fn histogram(out: &mut String, name: &str, labels: &str, histogram: Option<&messages::Histogram>) {
+    let empty = messages::Histogram::default();
+    let histogram = histogram.unwrap_or(&empty);
+
+    for bucket in &histogram.buckets {
+        let _ = writeln!(out, "{}_bucket{{{}le=\"{}\"}} {}", name, labels, bucket.le, bucket.count);
+    }
+    let _ = writeln!(out, "{}_bucket{{{}le=\"+Inf\"}} {}", name, labels, histogram.count);
+
+    let labels = labels.trim_end_matches(',');
+    let labels = if labels.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", labels)
+    };
+    let _ = writeln!(out, "{}_sum{} {}", name, labels, histogram.sum);
+    let _ = writeln!(out, "{}_count{} {}", name, labels, histogram.count);
+}
+
+fn registry() -> MutexGuard<'static, Registry> {
+    REGISTRY.lock().unwrap_or_else(PoisonError::into_inner)
+}
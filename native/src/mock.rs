@@ -0,0 +1,632 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::TryFrom;
+use std::env;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use chrono::Utc;
+use lazy_static::lazy_static;
+use prost::Message;
+
+use crate::backend::Backend;
+use crate::command::Command;
+use crate::dispatch::encode;
+use crate::messages::core_event::Payload;
+use crate::messages::*;
+
+// In-memory fake of `giganotes_core`, built with the `mock-core` feature. It
+// implements the entry points the bridge uses over a note store kept in
+// memory, one per data path, so the bridge and lib/index.js can be tested
+// without the real core, its database or a server.
+//
+// Users registered through `Register` or `LoginSocial` exist only in the
+// store they were registered in. `Synchronize` has nothing to synchronize
+// with; it succeeds for a logged in user and emits the same events as the
+// real core. It takes `SYNC_DELAY_VAR` milliseconds if that is set, so tests
+// can give up on a synchronization under way. The command named by
+// `PANIC_VAR` panics, so tests can see how a panic of the core reaches JS.
+
+// Environment variable with the duration of a synchronization.
+const SYNC_DELAY_VAR: &str = "GIGANOTES_MOCK_SYNC_DELAY_MS";
+
+// Environment variable with the name of a command which panics, e.g.
+// "GetRootFolder".
+const PANIC_VAR: &str = "GIGANOTES_MOCK_PANIC";
+
+const ROOT_FOLDER_ID: &str = "root";
+
+// Codes reported in the `errorCode` of failed responses. They are the fake's
+// own: the bridge doesn't interpret the codes of the real core, and neither
+// does it interpret these.
+const NOT_INITIALIZED: i32 = 1;
+const NOT_FOUND: i32 = 2;
+const INVALID_REQUEST: i32 = 3;
+const AUTH_FAILED: i32 = 4;
+const NOT_LOGGED_IN: i32 = 5;
+
+// Channel of the events pushed by the core, mirroring the `WORKER` of
+// `giganotes_core`.
+struct Worker {
+    sender: Mutex<Sender<Vec<u8>>>,
+    receiver: Arc<Mutex<Receiver<Vec<u8>>>>,
+
+    // Threads running async commands, see `handle_async_command`.
+    threads: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl Worker {
+    fn new() -> Worker {
+        let (sender, receiver) = mpsc::channel();
+        Worker {
+            sender: Mutex::new(sender),
+            receiver: Arc::new(Mutex::new(receiver)),
+            threads: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn publish(&self, payload: Payload) {
+        let event = encode(&CoreEvent {
+            payload: Some(payload),
+        });
+        let sender = self.sender.lock().unwrap_or_else(PoisonError::into_inner);
+        let _ = sender.send(event);
+    }
+
+    fn threads(&self) -> MutexGuard<'_, Vec<JoinHandle<()>>> {
+        self.threads.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+lazy_static! {
+    static ref WORKER: Worker = Worker::new();
+    static ref STATE: Mutex<State> = Mutex::new(State::default());
+}
+
+#[derive(Default)]
+struct State {
+    // Data path of the store commands run against, set by `InitData`.
+    data_path: Option<String>,
+
+    stores: HashMap<String, Store>,
+}
+
+struct Store {
+    folders: BTreeMap<String, Folder>,
+    notes: BTreeMap<String, Note>,
+    favorites: BTreeSet<String>,
+
+    // Registered users by email, with their password and user id.
+    users: HashMap<String, (String, i32)>,
+    session: Option<Session>,
+
+    next_id: u64,
+}
+
+struct Note {
+    info: NoteShortInfo,
+    text: String,
+}
+
+struct Session {
+    email: String,
+    user_id: i32,
+    token: String,
+}
+
+// The fake as the bridge's backend, see `backend::Backend`.
+pub fn backend() -> Backend {
+    Backend {
+        handle_command,
+        handle_async_command,
+        events: Arc::clone(&WORKER.receiver),
+        version: "mock",
+        core_events: true,
+        stop: Some(stop),
+    }
+}
+
+fn handle_command(index: i8, data: &[u8], len: usize) -> Vec<u8> {
+    let data = &data[..len.min(data.len())];
+    let command = Command::try_from(i64::from(index));
+    if let Ok(command) = command {
+        if env::var(PANIC_VAR).is_ok_and(|name| name == command.name()) {
+            panic!("{} asked to panic through {}", command, PANIC_VAR);
+        }
+    }
+    let result = match command {
+        Ok(Command::InitData) => init_data(data),
+        Ok(command) => {
+            let mut state = state();
+            let State { data_path, stores } = &mut *state;
+            match data_path.as_ref().and_then(|path| stores.get_mut(path)) {
+                Some(store) => store.run(command, data),
+                None => Err(NOT_INITIALIZED),
+            }
+        }
+        Err(_) => Err(INVALID_REQUEST),
+    };
+
+    result.unwrap_or_else(|error_code| {
+        encode(&EmptyResultResponse {
+            success: false,
+            error_code,
+            error: None,
+        })
+    })
+}
+
+// The real core runs these commands on its own runtime and answers right
+// away, reporting how they went through events. So does the fake.
+fn handle_async_command(index: i8, data: &[u8], len: usize) -> Vec<u8> {
+    let data = data[..len.min(data.len())].to_vec();
+    let thread = thread::spawn(move || {
+        if Command::try_from(i64::from(index)) == Ok(Command::Synchronize) {
+            thread::sleep(sync_delay());
+        }
+        handle_command(index, &data, data.len());
+    });
+
+    let mut threads = WORKER.threads();
+    threads.retain(|thread| !thread.is_finished());
+    threads.push(thread);
+    done()
+}
+
+fn sync_delay() -> Duration {
+    let delay = env::var(SYNC_DELAY_VAR).ok().and_then(|delay| delay.parse().ok());
+    Duration::from_millis(delay.unwrap_or(0))
+}
+
+// Waits for the async commands still running.
+fn stop() {
+    let threads: Vec<_> = WORKER.threads().drain(..).collect();
+    for thread in threads {
+        let _ = thread.join();
+    }
+}
+
+fn init_data(data: &[u8]) -> Result<Vec<u8>, i32> {
+    let request = decode::<InitData>(data)?;
+    let mut state = state();
+    state
+        .stores
+        .entry(request.data_path.clone())
+        .or_insert_with(Store::new);
+    state.data_path = Some(request.data_path);
+    Ok(done())
+}
+
+impl Store {
+    fn new() -> Store {
+        let now = now();
+        let root = Folder {
+            id: ROOT_FOLDER_ID.to_string(),
+            parent_id: String::new(),
+            title: "Root".to_string(),
+            level: 0,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let mut folders = BTreeMap::new();
+        folders.insert(root.id.clone(), root);
+        Store {
+            folders,
+            notes: BTreeMap::new(),
+            favorites: BTreeSet::new(),
+            users: HashMap::new(),
+            session: None,
+            next_id: 1,
+        }
+    }
+
+    fn run(&mut self, command: Command, data: &[u8]) -> Result<Vec<u8>, i32> {
+        match command {
+            Command::InitData => unreachable!("InitData is handled by handle_command"),
+            Command::CreateNote => self.create_note(decode(data)?),
+            Command::GetNotesByFolder => {
+                let request = decode::<GetNotesList>(data)?;
+                Ok(self.notes_list(|note| note.info.folder_id == request.folder_id))
+            }
+            Command::GetNoteById => self.get_note(decode(data)?),
+            Command::GetFolderById => self.get_folder(decode(data)?),
+            Command::Synchronize => self.synchronize(),
+            Command::Login => self.login(decode(data)?),
+            Command::GetLastLoginData => self.last_login_data(),
+            Command::GetRootFolder => Ok(encode(&GetRootFolderResponse {
+                success: true,
+                folder_id: ROOT_FOLDER_ID.to_string(),
+                title: self.folders[ROOT_FOLDER_ID].title.clone(),
+                ..Default::default()
+            })),
+            Command::GetAllFolders => Ok(encode(&GetFoldersListResponse {
+                success: true,
+                folders: self.folders.values().cloned().collect(),
+                ..Default::default()
+            })),
+            Command::GetAllNotes => self.get_all_notes(decode(data)?),
+            Command::CreateFolder => self.create_folder(decode(data)?),
+            Command::UpdateNote => self.update_note(decode(data)?),
+            Command::UpdateFolder => self.update_folder(decode(data)?),
+            Command::RemoveNote => {
+                let request = decode::<RemoveNote>(data)?;
+                self.remove_note(&request.note_id)?;
+                Ok(done())
+            }
+            Command::RemoveFolder => self.remove_folder(decode(data)?),
+            Command::SearchNotes => {
+                let request = decode::<SearchNotes>(data)?;
+                let query = request.query.to_lowercase();
+                Ok(self.notes_list(|note| {
+                    (request.folder_id.is_empty() || note.info.folder_id == request.folder_id)
+                        && (note.info.title.to_lowercase().contains(&query)
+                            || note.text.to_lowercase().contains(&query))
+                }))
+            }
+            Command::Register => self.register(decode(data)?),
+            Command::AddToFavorites => {
+                let request = decode::<AddToFavorites>(data)?;
+                self.note(&request.note_id)?;
+                self.favorites.insert(request.note_id);
+                Ok(done())
+            }
+            Command::RemoveFromFavorites => {
+                let request = decode::<RemoveFromFavorites>(data)?;
+                self.favorites.remove(&request.note_id);
+                Ok(done())
+            }
+            Command::GetFavorites => {
+                let favorites = &self.favorites;
+                Ok(self.notes_list(|note| favorites.contains(&note.info.id)))
+            }
+            Command::Logout => {
+                self.session = None;
+                Ok(done())
+            }
+            Command::LoginSocial => self.login_social(decode(data)?),
+        }
+    }
+
+    fn create_note(&mut self, request: CreateNote) -> Result<Vec<u8>, i32> {
+        let folder_id = self.folder_or_root(&request.folder_id)?;
+        let id = self.next_id("note");
+        let now = now();
+        let info = NoteShortInfo {
+            id: id.clone(),
+            folder_id: folder_id.clone(),
+            title: request.title,
+            created_at: now,
+            updated_at: now,
+        };
+        self.notes.insert(
+            id.clone(),
+            Note {
+                info,
+                text: request.text,
+            },
+        );
+
+        note_changed(&id, &folder_id, ChangeKind::Created);
+        Ok(encode(&CreateNoteResponse {
+            success: true,
+            note_id: id,
+            ..Default::default()
+        }))
+    }
+
+    fn get_note(&self, request: GetNoteById) -> Result<Vec<u8>, i32> {
+        let note = self.note(&request.note_id)?;
+        Ok(encode(&GetNoteByIdResponse {
+            success: true,
+            id: note.info.id.clone(),
+            folder_id: note.info.folder_id.clone(),
+            title: note.info.title.clone(),
+            text: note.text.clone(),
+            ..Default::default()
+        }))
+    }
+
+    fn get_folder(&self, request: GetFolderById) -> Result<Vec<u8>, i32> {
+        let folder = self.folder(&request.folder_id)?;
+        Ok(encode(&GetFolderByIdResponse {
+            success: true,
+            id: folder.id.clone(),
+            title: folder.title.clone(),
+            parent_id: folder.parent_id.clone(),
+            level: folder.level,
+            created_at: folder.created_at,
+            updated_at: folder.updated_at,
+            ..Default::default()
+        }))
+    }
+
+    fn get_all_notes(&self, request: GetAllNotes) -> Result<Vec<u8>, i32> {
+        if request.offset < 0 || request.limit < 0 {
+            return Err(INVALID_REQUEST);
+        }
+        // A limit of 0 means no limit.
+        let limit = match request.limit {
+            0 => usize::MAX,
+            limit => limit as usize,
+        };
+        Ok(encode(&GetNotesListResponse {
+            success: true,
+            notes: self
+                .notes
+                .values()
+                .skip(request.offset as usize)
+                .take(limit)
+                .map(|note| note.info.clone())
+                .collect(),
+            ..Default::default()
+        }))
+    }
+
+    fn create_folder(&mut self, request: CreateFolder) -> Result<Vec<u8>, i32> {
+        let parent_id = self.folder_or_root(&request.parent_id)?;
+        let id = self.next_id("folder");
+        let now = now();
+        let folder = Folder {
+            id: id.clone(),
+            parent_id: parent_id.clone(),
+            title: request.title,
+            level: self.folders[&parent_id].level + 1,
+            created_at: now,
+            updated_at: now,
+        };
+        self.folders.insert(id.clone(), folder);
+
+        folder_changed(&id, &parent_id, ChangeKind::Created);
+        Ok(encode(&CreateFolderResponse {
+            success: true,
+            folder_id: id,
+            ..Default::default()
+        }))
+    }
+
+    fn update_note(&mut self, request: UpdateNote) -> Result<Vec<u8>, i32> {
+        if !request.folder_id.is_empty() {
+            self.folder(&request.folder_id)?;
+        }
+        let note = self.notes.get_mut(&request.id).ok_or(NOT_FOUND)?;
+        if !request.folder_id.is_empty() {
+            note.info.folder_id = request.folder_id;
+        }
+        note.info.title = request.title;
+        note.info.updated_at = now();
+        note.text = request.text;
+
+        note_changed(&note.info.id, &note.info.folder_id, ChangeKind::Updated);
+        Ok(done())
+    }
+
+    fn update_folder(&mut self, request: UpdateFolder) -> Result<Vec<u8>, i32> {
+        if request.id == ROOT_FOLDER_ID && !request.parent_id.is_empty() {
+            return Err(INVALID_REQUEST);
+        }
+        if !request.parent_id.is_empty() {
+            self.folder(&request.parent_id)?;
+            if self.is_within(&request.parent_id, &request.id) {
+                return Err(INVALID_REQUEST);
+            }
+        }
+        let folder = self.folders.get_mut(&request.id).ok_or(NOT_FOUND)?;
+        if !request.parent_id.is_empty() {
+            folder.parent_id = request.parent_id;
+        }
+        folder.title = request.title;
+        folder.level = request.level;
+        folder.updated_at = now();
+
+        folder_changed(&folder.id, &folder.parent_id, ChangeKind::Updated);
+        Ok(done())
+    }
+
+    fn remove_note(&mut self, id: &str) -> Result<(), i32> {
+        let note = self.notes.remove(id).ok_or(NOT_FOUND)?;
+        self.favorites.remove(id);
+        note_changed(id, &note.info.folder_id, ChangeKind::Removed);
+        Ok(())
+    }
+
+    // Removes the folder with its subfolders and their notes.
+    fn remove_folder(&mut self, request: RemoveFolder) -> Result<Vec<u8>, i32> {
+        if request.folder_id == ROOT_FOLDER_ID {
+            return Err(INVALID_REQUEST);
+        }
+        self.folder(&request.folder_id)?;
+
+        let removed: Vec<String> = self
+            .folders
+            .keys()
+            .filter(|id| self.is_within(id, &request.folder_id))
+            .cloned()
+            .collect();
+        let notes: Vec<String> = self
+            .notes
+            .values()
+            .filter(|note| removed.contains(&note.info.folder_id))
+            .map(|note| note.info.id.clone())
+            .collect();
+        for id in notes {
+            self.remove_note(&id)?;
+        }
+        for id in removed {
+            if let Some(folder) = self.folders.remove(&id) {
+                folder_changed(&id, &folder.parent_id, ChangeKind::Removed);
+            }
+        }
+        Ok(done())
+    }
+
+    fn synchronize(&mut self) -> Result<Vec<u8>, i32> {
+        WORKER.publish(Payload::SyncStarted(SyncStarted {}));
+        if self.session.is_none() {
+            let mut error = Error {
+                message: "Not logged in".to_string(),
+                core_code: NOT_LOGGED_IN,
+                ..Default::default()
+            };
+            error.set_code(ErrorCode::AuthFailed);
+            WORKER.publish(Payload::SyncFailed(SyncFailed { error: Some(error) }));
+            return Err(NOT_LOGGED_IN);
+        }
+
+        let total = self.notes.len() as i32;
+        WORKER.publish(Payload::SyncProgress(SyncProgress { done: total, total }));
+        WORKER.publish(Payload::SyncFinished(SyncFinished {}));
+        Ok(done())
+    }
+
+    fn login(&mut self, request: Login) -> Result<Vec<u8>, i32> {
+        match self.users.get(&request.email) {
+            // Users of `LoginSocial` have no password.
+            Some((password, user_id)) if !password.is_empty() && *password == request.password => {
+                let user_id = *user_id;
+                Ok(self.start_session(request.email, user_id))
+            }
+            _ => Err(AUTH_FAILED),
+        }
+    }
+
+    fn login_social(&mut self, request: LoginSocial) -> Result<Vec<u8>, i32> {
+        if request.email.is_empty() || request.provider.is_empty() || request.token.is_empty() {
+            return Err(AUTH_FAILED);
+        }
+        let user_id = match self.users.get(&request.email) {
+            Some((_, user_id)) => *user_id,
+            None => self.add_user(&request.email, ""),
+        };
+        Ok(self.start_session(request.email, user_id))
+    }
+
+    fn register(&mut self, request: Login) -> Result<Vec<u8>, i32> {
+        if request.email.is_empty()
+            || request.password.is_empty()
+            || self.users.contains_key(&request.email)
+        {
+            return Err(AUTH_FAILED);
+        }
+        let user_id = self.add_user(&request.email, &request.password);
+        Ok(self.start_session(request.email, user_id))
+    }
+
+    fn last_login_data(&self) -> Result<Vec<u8>, i32> {
+        let session = self.session.as_ref().ok_or(NOT_LOGGED_IN)?;
+        Ok(encode(&GetLastLoginDataResponse {
+            success: true,
+            token: session.token.clone(),
+            user_id: session.user_id,
+            email: session.email.clone(),
+            is_token_valid: true,
+            ..Default::default()
+        }))
+    }
+
+    fn add_user(&mut self, email: &str, password: &str) -> i32 {
+        let user_id = self.users.len() as i32 + 1;
+        self.users
+            .insert(email.to_string(), (password.to_string(), user_id));
+        user_id
+    }
+
+    fn start_session(&mut self, email: String, user_id: i32) -> Vec<u8> {
+        let token = self.next_id("token");
+        self.session = Some(Session {
+            email,
+            user_id,
+            token: token.clone(),
+        });
+        encode(&LoginResponse {
+            success: true,
+            token,
+            user_id,
+            ..Default::default()
+        })
+    }
+
+    fn notes_list<F: Fn(&Note) -> bool>(&self, filter: F) -> Vec<u8> {
+        encode(&GetNotesListResponse {
+            success: true,
+            notes: self
+                .notes
+                .values()
+                .filter(|note| filter(note))
+                .map(|note| note.info.clone())
+                .collect(),
+            ..Default::default()
+        })
+    }
+
+    fn note(&self, id: &str) -> Result<&Note, i32> {
+        self.notes.get(id).ok_or(NOT_FOUND)
+    }
+
+    fn folder(&self, id: &str) -> Result<&Folder, i32> {
+        self.folders.get(id).ok_or(NOT_FOUND)
+    }
+
+    // Id of the folder `id`, or of the root folder if `id` is empty.
+    fn folder_or_root(&self, id: &str) -> Result<String, i32> {
+        if id.is_empty() {
+            return Ok(ROOT_FOLDER_ID.to_string());
+        }
+        self.folder(id).map(|folder| folder.id.clone())
+    }
+
+    // Whether the folder `id` is `ancestor` or one of its subfolders.
+    fn is_within(&self, id: &str, ancestor: &str) -> bool {
+        let mut id = id;
+        loop {
+            if id == ancestor {
+                return true;
+            }
+            match self.folders.get(id) {
+                Some(folder) if !folder.parent_id.is_empty() => id = &folder.parent_id,
+                _ => return false,
+            }
+        }
+    }
+
+    fn next_id(&mut self, kind: &str) -> String {
+        self.next_id += 1;
+        format!("{}-{}", kind, self.next_id)
+    }
+}
+
+fn note_changed(note_id: &str, folder_id: &str, kind: ChangeKind) {
+    WORKER.publish(Payload::NoteChanged(NoteChanged {
+        note_id: note_id.to_string(),
+        folder_id: folder_id.to_string(),
+        kind: kind as i32,
+    }));
+}
+
+fn folder_changed(folder_id: &str, parent_id: &str, kind: ChangeKind) {
+    WORKER.publish(Payload::FolderChanged(FolderChanged {
+        folder_id: folder_id.to_string(),
+        parent_id: parent_id.to_string(),
+        kind: kind as i32,
+    }));
+}
+
+fn decode<M: Message + Default>(data: &[u8]) -> Result<M, i32> {
+    M::decode(data).map_err(|_| INVALID_REQUEST)
+}
+
+fn done() -> Vec<u8> {
+    encode(&EmptyResultResponse {
+        success: true,
+        ..Default::default()
+    })
+}
+
+fn now() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+fn state() -> MutexGuard<'static, State> {
+    STATE.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,184 @@
+use std::collections::VecDeque;
+use std::mem;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+use prost::Message;
+
+use crate::dispatch::encode;
+use crate::events::Event;
+use crate::messages::core_event::Payload;
+use crate::messages::{CoreEvent, EventsDropped};
+use crate::metrics;
+
+// Default number of events a subscriber can fall behind by.
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+// What happens to an event arriving while a subscriber's queue is full.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Overflow {
+    // Drop the oldest queued event.
+    DropOldest,
+
+    // Remove a queued `noteChanged` event for the same note and change kind
+    // before queueing the new one, even if the queue isn't full, so sequence
+    // numbers keep increasing. Drops the oldest event if there is no
+    // duplicate.
+    Coalesce,
+
+    // Block the event pump until the subscriber has caught up. This holds up
+    // every other subscriber, and the core's own channel keeps growing
+    // meanwhile. Replayed events are dropped like with `DropOldest`, since
+    // replay runs on the main thread, which drains the queue.
+    Block,
+}
+
+impl Overflow {
+    pub fn from_name(name: &str) -> Option<Overflow> {
+        match name {
+            "dropOldest" => Some(Overflow::DropOldest),
+            "coalesce" => Some(Overflow::Coalesce),
+            "block" => Some(Overflow::Block),
+            _ => None,
+        }
+    }
+}
+
+// An event waiting in an `EventQueue`.
+pub struct Queued {
+    pub seq: u64,
+    pub data: Vec<u8>,
+    pub queued_at: Instant,
+}
+
+// Events waiting for a subscriber on the main thread. Filled by the event
+// pump and emptied by a drain scheduled on the main thread, of which there is
+// at most one at a time.
+pub struct EventQueue {
+    capacity: usize,
+    overflow: Overflow,
+    state: Mutex<State>,
+
+    // Signalled when events are drained or the queue is closed, to wake up a
+    // pump blocked by `Overflow::Block`.
+    drained: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    events: VecDeque<Queued>,
+
+    // Events dropped since the last drain.
+    dropped: u64,
+
+    // Whether a drain is scheduled. Always set while `events` isn't empty.
+    scheduled: bool,
+
+    // Set once the subscriber has gone away. Later events are discarded.
+    closed: bool,
+}
+
+impl EventQueue {
+    pub fn new(capacity: usize, overflow: Overflow) -> EventQueue {
+        EventQueue {
+            capacity: capacity.max(1),
+            overflow,
+            state: Mutex::new(State::default()),
+            drained: Condvar::new(),
+        }
+    }
+
+    // Number of events the queue holds before `overflow` applies.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    // Queues `event`. Returns `true` if the caller has to schedule a drain.
+    pub fn push(&self, event: Event) -> bool {
+        let mut state = self.state();
+
+        if self.overflow == Overflow::Coalesce {
+            if let Some(i) = find_duplicate(&state.events, &event.data) {
+                state.events.remove(i);
+            }
+        }
+        while state.events.len() >= self.capacity && !state.closed {
+            if self.overflow == Overflow::Block && !event.replayed {
+                state = self
+                    .drained
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner);
+            } else {
+                state.events.pop_front();
+                state.dropped += 1;
+                metrics::record_events_dropped(1);
+            }
+        }
+        if state.closed {
+            return false;
+        }
+
+        state.events.push_back(Queued {
+            seq: event.seq,
+            data: event.data,
+            queued_at: Instant::now(),
+        });
+        !mem::replace(&mut state.scheduled, true)
+    }
+
+    // Takes every queued event, preceded by an `eventsDropped` event if any
+    // were dropped since the last drain.
+    pub fn drain(&self) -> Vec<Queued> {
+        let mut state = self.state();
+        state.scheduled = false;
+
+        let mut events = Vec::with_capacity(state.events.len() + 1);
+        if state.dropped > 0 {
+            events.push(Queued {
+                seq: 0,
+                data: events_dropped(mem::replace(&mut state.dropped, 0)),
+                queued_at: Instant::now(),
+            });
+        }
+        events.extend(state.events.drain(..));
+        self.drained.notify_all();
+        events
+    }
+
+    // Discards queued and future events and releases a blocked pump. Called
+    // when the subscriber goes away, before its subscription is dropped.
+    pub fn close(&self) {
+        let mut state = self.state();
+        state.closed = true;
+        state.events.clear();
+        self.drained.notify_all();
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+// Index of a queued `noteChanged` event for the same note and kind as
+// `event`, if `event` is one.
+fn find_duplicate(events: &VecDeque<Queued>, event: &[u8]) -> Option<usize> {
+    let changed = match CoreEvent::decode(event) {
+        Ok(CoreEvent {
+            payload: Some(Payload::NoteChanged(changed)),
+        }) => changed,
+        _ => return None,
+    };
+
+    events.iter().position(|queued| match CoreEvent::decode(&queued.data[..]) {
+        Ok(CoreEvent {
+            payload: Some(Payload::NoteChanged(queued)),
+        }) => queued.note_id == changed.note_id && queued.kind == changed.kind,
+        _ => false,
+    })
+}
+
+fn events_dropped(count: u64) -> Vec<u8> {
+    encode(&CoreEvent {
+        payload: Some(Payload::EventsDropped(EventsDropped { count })),
+    })
+}
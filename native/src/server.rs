@@ -0,0 +1,545 @@
+use std::collections::HashMap;
+use std::fs::{self, DirBuilder, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use log::debug;
+use prost::Message;
+
+use crate::backend::{handle_async_command, handle_command};
+use crate::command::Command;
+use crate::dispatch::{self, encode, validation_error, CoreEntry};
+use crate::events::{self, Event};
+use crate::guard::catch_panic;
+use crate::messages::server_frame::Body;
+use crate::messages::server_message::Body as Outgoing;
+use crate::messages::{
+    Error, ErrorCode, ServerCommand, ServerEvent, ServerFrame, ServerMessage, ServerReply,
+};
+use crate::metrics;
+use crate::queue::{self, EventQueue, Overflow};
+
+// Names of the directory holding the socket and of the token file, inside
+// the directory the server is started in, and of the socket inside the
+// former. Only the current user can enter the socket's directory, so no one
+// else can connect even before the socket's own mode is set.
+pub const SOCKET_DIR: &str = "giganotes-server";
+pub const SOCKET_FILE: &str = "giganotes.sock";
+pub const TOKEN_FILE: &str = "giganotes.token";
+
+// Largest frame accepted from an authenticated client, 64 MiB. Connections
+// sending a larger one are closed.
+pub const MAX_FRAME_SIZE: usize = 64 * 1024 * 1024;
+
+// Largest frame accepted before a client has authenticated, 4 KiB, which
+// leaves plenty of room for an `auth` frame.
+pub const MAX_AUTH_FRAME_SIZE: usize = 4 * 1024;
+
+// Number of threads commands run on, shared by every connection. A
+// connection sending more commands than there are idle threads stops being
+// read until one has been taken on.
+pub const COMMAND_THREADS: usize = 4;
+
+// How long writing a frame to a client may take, e.g. while the client
+// doesn't read, before its connection is closed. Frames are written by the
+// command threads and the event writers, which would be held up until the
+// client read again otherwise.
+pub const WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// A command waiting for a command thread.
+type Job = Box<dyn FnOnce() + Send>;
+
+static NEXT_CONNECTION_ID: AtomicU64 = AtomicU64::new(0);
+
+// Serves the command protocol of `handleCommand` on a Unix domain socket, so
+// that processes other than the one which loaded the core, e.g. editor
+// plugins, can reach it. Clients exchange `ServerFrame`s and
+// `ServerMessage`s with it, and authenticate with the contents of a token
+// file which only the current user can read.
+pub struct Server {
+    socket_dir: PathBuf,
+    socket_path: PathBuf,
+    token_path: PathBuf,
+    shared: Arc<Shared>,
+    accept: Option<JoinHandle<()>>,
+}
+
+// State shared by the accept loop and the connections.
+struct Shared {
+    token: String,
+
+    stopped: AtomicBool,
+
+    // A handle of every open connection, keyed by connection id, to close
+    // them when the server stops.
+    connections: Mutex<HashMap<u64, UnixStream>>,
+
+    // Hands commands to the command threads, which exit once it has been
+    // dropped along with the server and its connections.
+    jobs: SyncSender<Job>,
+}
+
+impl Shared {
+    fn connections(&self) -> MutexGuard<'_, HashMap<u64, UnixStream>> {
+        self.connections.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Server {
+    // Listens on `SOCKET_FILE` in `SOCKET_DIR` in `dir` and writes a new
+    // token to `TOKEN_FILE` in `dir`. A socket left behind by a server which
+    // didn't stop cleanly is replaced; starting fails if a server is still
+    // listening.
+    pub fn start(dir: &Path) -> io::Result<Server> {
+        let socket_dir = dir.join(SOCKET_DIR);
+        let socket_path = socket_dir.join(SOCKET_FILE);
+        let token_path = dir.join(TOKEN_FILE);
+
+        create_private_dir(&socket_dir)?;
+        remove_stale_socket(&socket_path)?;
+        let listener = UnixListener::bind(&socket_path)?;
+        fs::set_permissions(&socket_path, fs::Permissions::from_mode(0o600))?;
+        let token = new_token()?;
+        write_token(&token_path, &token)?;
+
+        let (jobs, queued) = mpsc::sync_channel(0);
+        let queued = Arc::new(Mutex::new(queued));
+        for _ in 0..COMMAND_THREADS {
+            let queued = Arc::clone(&queued);
+            thread::spawn(move || run_jobs(&queued));
+        }
+
+        let shared = Arc::new(Shared {
+            token,
+            stopped: AtomicBool::new(false),
+            connections: Mutex::new(HashMap::new()),
+            jobs,
+        });
+        let accepting = Arc::clone(&shared);
+        let accept = thread::spawn(move || accept_loop(listener, accepting));
+
+        Ok(Server {
+            socket_dir,
+            socket_path,
+            token_path,
+            shared,
+            accept: Some(accept),
+        })
+    }
+
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    pub fn token_path(&self) -> &Path {
+        &self.token_path
+    }
+
+    // Stops accepting connections, closes the open ones and removes the
+    // socket and the token file. Commands which are still running finish in
+    // the background, their responses are discarded. Called on drop.
+    pub fn stop(&mut self) {
+        if self.shared.stopped.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        // Wakes up the accept loop, so it sees it has been stopped.
+        let _ = UnixStream::connect(&self.socket_path);
+        if let Some(accept) = self.accept.take() {
+            let _ = accept.join();
+        }
+
+        for (_, stream) in self.shared.connections().drain() {
+            let _ = stream.shutdown(Shutdown::Both);
+        }
+        let _ = fs::remove_file(&self.socket_path);
+        let _ = fs::remove_dir(&self.socket_dir);
+        let _ = fs::remove_file(&self.token_path);
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+// Runs the commands handed to the command threads until the server is gone.
+fn run_jobs(queued: &Mutex<Receiver<Job>>) {
+    loop {
+        // Only held while waiting for a command, not while running it.
+        let job = queued.lock().unwrap_or_else(PoisonError::into_inner).recv();
+        match job {
+            Ok(job) => job(),
+            Err(_) => return,
+        }
+    }
+}
+
+fn accept_loop(listener: UnixListener, shared: Arc<Shared>) {
+    for stream in listener.incoming() {
+        if shared.stopped.load(Ordering::SeqCst) {
+            break;
+        }
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                debug!("Failed to accept a connection: {}", err);
+                continue;
+            }
+        };
+        let connection = match Connection::new(stream, Arc::clone(&shared)) {
+            Ok(connection) => connection,
+            Err(err) => {
+                debug!("Failed to set up a connection: {}", err);
+                continue;
+            }
+        };
+
+        let id = NEXT_CONNECTION_ID.fetch_add(1, Ordering::Relaxed);
+        match connection.reader.try_clone() {
+            Ok(stream) => shared.connections().insert(id, stream),
+            Err(_) => continue,
+        };
+        thread::spawn(move || {
+            let shared = Arc::clone(&connection.shared);
+            if let Err(err) = connection.serve() {
+                debug!("Closed connection {}: {}", id, err);
+            }
+            shared.connections().remove(&id);
+        });
+    }
+}
+
+// Writes of a connection, shared by its command threads and its event
+// writer. Each frame is written in one go while holding the lock.
+type Writer = Arc<Mutex<UnixStream>>;
+
+struct Connection {
+    reader: UnixStream,
+    writer: Writer,
+    shared: Arc<Shared>,
+    authenticated: bool,
+
+    // The connection's registration for core events, if it subscribed.
+    subscriber: Option<Subscriber>,
+}
+
+impl Connection {
+    fn new(stream: UnixStream, shared: Arc<Shared>) -> io::Result<Connection> {
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        Ok(Connection {
+            writer: Arc::new(Mutex::new(stream.try_clone()?)),
+            reader: stream,
+            shared,
+            authenticated: false,
+            subscriber: None,
+        })
+    }
+
+    // Answers the frames of the client until it disconnects. A client which
+    // sends anything but a valid `auth` frame first is disconnected.
+    fn serve(mut self) -> io::Result<()> {
+        loop {
+            let limit = if self.authenticated {
+                MAX_FRAME_SIZE
+            } else {
+                MAX_AUTH_FRAME_SIZE
+            };
+            let frame = match read_frame(&mut self.reader, limit)? {
+                Some(frame) => frame,
+                None => return Ok(()),
+            };
+            let frame = match ServerFrame::decode(&frame[..]) {
+                Ok(frame) => frame,
+                Err(err) => {
+                    let error = validation_error(&format!("Malformed frame: {}", err), "");
+                    self.reply(0, Vec::new(), Some(error))?;
+                    if self.authenticated {
+                        continue;
+                    }
+                    return Ok(());
+                }
+            };
+
+            let request_id = frame.request_id;
+            match frame.body {
+                Some(Body::Auth(auth)) => {
+                    self.authenticated = tokens_match(auth.token.trim(), &self.shared.token);
+                    if !self.authenticated {
+                        self.reply(request_id, Vec::new(), Some(auth_error("Invalid token")))?;
+                        return Ok(());
+                    }
+                    self.reply(request_id, Vec::new(), None)?;
+                }
+                _ if !self.authenticated => {
+                    let error = auth_error("Authenticate with the token file first");
+                    self.reply(request_id, Vec::new(), Some(error))?;
+                    return Ok(());
+                }
+                Some(Body::Command(command)) => self.run(request_id, command)?,
+                Some(Body::Subscribe(subscribe)) => {
+                    let since = Some(subscribe.since_seq).filter(|&seq| seq != 0);
+                    self.subscribe(since);
+                    self.reply(request_id, Vec::new(), None)?;
+                }
+                Some(Body::Unsubscribe(_)) => {
+                    self.subscriber = None;
+                    self.reply(request_id, Vec::new(), None)?;
+                }
+                None => {
+                    let error = validation_error("body is required", "body");
+                    self.reply(request_id, Vec::new(), Some(error))?;
+                }
+            }
+        }
+    }
+
+    // Runs `command` on one of the command threads, so that a long-running
+    // command, e.g. a synchronization, doesn't hold up the connection's other
+    // frames. Waits for a thread to take the command on.
+    fn run(&self, request_id: u64, command: ServerCommand) -> io::Result<()> {
+        let index = command.index;
+        let command_data = command.data;
+        let command = match Command::from_index(f64::from(index)) {
+            Ok(command) => command,
+            Err(err) => {
+                let error = validation_error(&err.to_string(), "index");
+                return self.reply(request_id, Vec::new(), Some(error));
+            }
+        };
+        // Clients share the data path of the process serving the core.
+        if command == Command::InitData {
+            let error = validation_error("InitData isn't accepted by the server", "index");
+            return self.reply(request_id, Vec::new(), Some(error));
+        }
+
+        let entry: CoreEntry = match command {
+            Command::Synchronize => handle_async_command,
+            _ => handle_command,
+        };
+        let writer = Arc::clone(&self.writer);
+        let job = Box::new(move || {
+            let run = || dispatch::run(command, &command_data, entry);
+            let reply = match catch_panic(run) {
+                Ok(response) => ServerReply {
+                    request_id,
+                    response,
+                    error: None,
+                },
+                Err(panic) => {
+                    let mut error = Error {
+                        message: format!("Panic in giganotes core: {}", panic.message),
+                        ..Default::default()
+                    };
+                    error.set_code(ErrorCode::UnknownError);
+                    ServerReply {
+                        request_id,
+                        response: Vec::new(),
+                        error: Some(error),
+                    }
+                }
+            };
+            let _ = send(&writer, Outgoing::Reply(reply));
+        });
+        self.shared.jobs.send(job).map_err(|_| {
+            io::Error::new(io::ErrorKind::BrokenPipe, "The command threads have exited")
+        })
+    }
+
+    // Streams core events to the client, replaying the events after `since`.
+    // Replaces an earlier subscription of the connection.
+    fn subscribe(&mut self, since: Option<u64>) {
+        self.subscriber = None;
+
+        let queue = Arc::new(EventQueue::new(queue::DEFAULT_CAPACITY, Overflow::DropOldest));
+        let (scheduled, drains) = mpsc::channel::<()>();
+
+        // Writes the queued events on a thread of its own, so that a slow
+        // client can't hold up the event pump. Exits once the subscription
+        // is dropped, which drops `scheduled`, or the client has gone away.
+        let drained = Arc::clone(&queue);
+        let writer = Arc::clone(&self.writer);
+        thread::spawn(move || {
+            for () in drains {
+                for event in drained.drain() {
+                    metrics::record_event_delivered(event.queued_at.elapsed());
+
+                    let event = ServerEvent {
+                        name: events::event_name(&event.data).to_string(),
+                        data: event.data,
+                        seq: event.seq,
+                    };
+                    if send(&writer, Outgoing::Event(event)).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+
+        let pushed = Arc::clone(&queue);
+        let sink = move |event: Event| {
+            if pushed.push(event) {
+                let _ = scheduled.send(());
+            }
+        };
+        let subscription = events::subscribe(Box::new(sink), since, queue.capacity());
+
+        self.subscriber = Some(Subscriber {
+            queue,
+            _subscription: subscription,
+        });
+    }
+
+    fn reply(&self, request_id: u64, response: Vec<u8>, error: Option<Error>) -> io::Result<()> {
+        let reply = ServerReply {
+            request_id,
+            response,
+            error,
+        };
+        send(&self.writer, Outgoing::Reply(reply))
+    }
+}
+
+// A connection's registration for core events. Events reach the client
+// through a bounded `EventQueue`, like they reach JS listeners.
+struct Subscriber {
+    queue: Arc<EventQueue>,
+    _subscription: events::Subscription,
+}
+
+impl Drop for Subscriber {
+    // Releases a pump blocked on the full queue, which would otherwise wait
+    // for a drain that never comes.
+    fn drop(&mut self) {
+        self.queue.close();
+    }
+}
+
+// Reads a frame, a 4-byte big-endian length followed by as many bytes, of at
+// most `limit` bytes. Returns `None` if the stream ends before a new frame.
+pub fn read_frame<R: Read>(reader: &mut R, limit: usize) -> io::Result<Option<Vec<u8>>> {
+    let mut len = [0; 4];
+    match reader.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    let len = u32::from_be_bytes(len) as usize;
+    if len > limit {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Frame of {} bytes exceeds the limit of {} bytes", len, limit),
+        ));
+    }
+    let mut frame = vec![0; len];
+    reader.read_exact(&mut frame)?;
+    Ok(Some(frame))
+}
+
+// Writes `message` as a frame, see `read_frame`.
+pub fn write_frame<W: Write, M: Message>(writer: &mut W, message: &M) -> io::Result<()> {
+    let message = encode(message);
+    let mut frame = Vec::with_capacity(4 + message.len());
+    frame.extend_from_slice(&(message.len() as u32).to_be_bytes());
+    frame.extend_from_slice(&message);
+    writer.write_all(&frame)
+}
+
+// Writes a frame to the client. Closes the connection if that fails, since
+// the frame may have been written in part, which leaves the client unable to
+// read any later frame.
+fn send(writer: &Mutex<UnixStream>, body: Outgoing) -> io::Result<()> {
+    let message = ServerMessage { body: Some(body) };
+    let mut stream = writer.lock().unwrap_or_else(PoisonError::into_inner);
+    let result = write_frame(&mut *stream, &message);
+    if result.is_err() {
+        let _ = stream.shutdown(Shutdown::Both);
+    }
+    result
+}
+
+// Creates the directory `path` which only the current user can enter, or
+// restricts an existing one to the current user. Fails if `path` is a
+// symbolic link, or a directory of another user.
+fn create_private_dir(path: &Path) -> io::Result<()> {
+    match DirBuilder::new().mode(0o700).create(path) {
+        Err(err) if err.kind() != io::ErrorKind::AlreadyExists => return Err(err),
+        _ => {}
+    }
+    if !fs::symlink_metadata(path)?.is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} exists and isn't a directory", path.display()),
+        ));
+    }
+    // The mode of a new directory is masked by the umask; another user's
+    // directory can't be changed.
+    fs::set_permissions(path, fs::Permissions::from_mode(0o700))
+}
+
+// Removes a socket left behind by a server which didn't stop cleanly. Fails
+// if a server is still listening on it.
+fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    if UnixStream::connect(path).is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("A server is already listening on {}", path.display()),
+        ));
+    }
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+// 32 random bytes from the OS, hex encoded.
+fn new_token() -> io::Result<String> {
+    let mut bytes = [0; 32];
+    File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+    Ok(bytes.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+// Writes `token` to a new file at `path` which only the current user can
+// read. An existing file is replaced rather than truncated, since its mode
+// would be kept.
+fn write_token(path: &Path, token: &str) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+        _ => {}
+    }
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)?;
+    file.write_all(token.as_bytes())
+}
+
+// Compares in constant time, so that response times don't tell how much of
+// a guessed token is right.
+fn tokens_match(given: &str, token: &str) -> bool {
+    given.len() == token.len()
+        && given
+            .bytes()
+            .zip(token.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn auth_error(message: &str) -> Error {
+    let mut error = Error {
+        message: message.to_string(),
+        ..Default::default()
+    };
+    error.set_code(ErrorCode::AuthFailed);
+    error
+}
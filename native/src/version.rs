@@ -0,0 +1,88 @@
+use prost::Message;
+
+use crate::backend;
+use crate::command::Command;
+use crate::dispatch::encode;
+use crate::events::EVENT_NAMES;
+use crate::messages::{CommandInfo, Error, ErrorCode, Handshake, HandshakeResponse, VersionInfo};
+
+// Version of the protocol spoken by the bridge. Bumped whenever
+// protos/messages.proto or the exports of the native module change in a way
+// clients can tell apart.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+// Oldest protocol version of clients which still work with this bridge.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+pub fn version_info() -> VersionInfo {
+    VersionInfo {
+        bridge_version: env!("CARGO_PKG_VERSION").to_string(),
+        core_version: backend::core_version().to_string(),
+        protocol_version: PROTOCOL_VERSION,
+        min_protocol_version: MIN_PROTOCOL_VERSION,
+        build_profile: env!("BUILD_PROFILE").to_string(),
+        commands: Command::ALL
+            .iter()
+            .map(|command| CommandInfo {
+                name: command.name().to_string(),
+                index: command.index() as i32,
+            })
+            .collect(),
+        events: EVENT_NAMES.iter().map(|name| name.to_string()).collect(),
+    }
+}
+
+// Answers an encoded `Handshake` with an encoded `HandshakeResponse`.
+pub fn handshake(data: &[u8]) -> Vec<u8> {
+    let version = version_info();
+    let error = match Handshake::decode(data) {
+        Ok(handshake) => check(handshake.protocol_version, &version.bridge_version).err(),
+        Err(err) => Some(error(
+            ErrorCode::ValidationFailed,
+            format!("Malformed handshake: {}", err),
+        )),
+    };
+
+    encode(&HandshakeResponse {
+        success: error.is_none(),
+        error_code: error.as_ref().map_or(0, |error| error.code),
+        version: Some(version),
+        error,
+    })
+}
+
+// Rejects clients speaking a protocol version outside of
+// `MIN_PROTOCOL_VERSION..=PROTOCOL_VERSION`, telling which side needs to be
+// updated.
+fn check(protocol_version: u32, bridge_version: &str) -> Result<(), Error> {
+    if protocol_version > PROTOCOL_VERSION {
+        Err(error(
+            ErrorCode::IncompatibleVersion,
+            format!(
+                "Client protocol version {} is newer than version {} of the native module {}, \
+                 rebuild the native module",
+                protocol_version, PROTOCOL_VERSION, bridge_version
+            ),
+        ))
+    } else if protocol_version < MIN_PROTOCOL_VERSION {
+        Err(error(
+            ErrorCode::IncompatibleVersion,
+            format!(
+                "Client protocol version {} is no longer supported by the native module {}, \
+                 which requires at least version {}",
+                protocol_version, bridge_version, MIN_PROTOCOL_VERSION
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn error(code: ErrorCode, message: String) -> Error {
+    let mut error = Error {
+        message,
+        ..Default::default()
+    };
+    error.set_code(code);
+    error
+}
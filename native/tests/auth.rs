@@ -0,0 +1,65 @@
+mod common;
+
+use common::{error, Core};
+use giganotescore::command::Command;
+use giganotescore::messages::*;
+
+#[test]
+fn rejects_an_unknown_login() {
+    let core = Core::new();
+    let login = Login {
+        email: "nobody@example.com".to_string(),
+        password: "secret".to_string(),
+    };
+    let response: LoginResponse = core.run(Command::Login, &login);
+
+    let error = error(&response);
+    assert_eq!(error.code(), ErrorCode::AuthFailed);
+    assert!(!error.retryable);
+    assert_ne!(error.core_code, 0);
+}
+
+#[test]
+fn rejects_a_social_login_without_a_token() {
+    let core = Core::new();
+    let login = LoginSocial {
+        email: "nobody@example.com".to_string(),
+        provider: "google".to_string(),
+        token: String::new(),
+    };
+    let response: LoginResponse = core.run(Command::LoginSocial, &login);
+    assert_eq!(error(&response).code(), ErrorCode::AuthFailed);
+}
+
+#[test]
+fn has_no_login_data_before_a_login() {
+    let core = Core::new();
+    let response: GetLastLoginDataResponse =
+        core.run(Command::GetLastLoginData, &GetLastLoginData {});
+    assert_eq!(error(&response).code(), ErrorCode::AuthFailed);
+}
+
+#[test]
+fn fails_to_synchronize_without_a_login() {
+    let core = Core::new();
+    let response: EmptyResultResponse = core.run(Command::Synchronize, &Synchronize {});
+
+    // The error of the `syncFailed` event.
+    let error = error(&response);
+    assert_eq!(error.code(), ErrorCode::AuthFailed);
+    assert_ne!(error.core_code, 0);
+}
+
+#[test]
+fn synchronizes_once_logged_in() {
+    let core = Core::new();
+    let register = Login {
+        email: "sync@example.com".to_string(),
+        password: "secret".to_string(),
+    };
+    let response: LoginResponse = core.run(Command::Register, &register);
+    assert!(response.success, "{:?}", response.error);
+
+    let response: EmptyResultResponse = core.run(Command::Synchronize, &Synchronize {});
+    assert!(response.success, "{:?}", response.error);
+}
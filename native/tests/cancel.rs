@@ -0,0 +1,215 @@
+mod common;
+
+use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::Core;
+use giganotescore::backend::handle_async_command;
+use giganotescore::cancel::{self, CancelHandle};
+use giganotescore::command::Command;
+use giganotescore::dispatch::{self, encode};
+use giganotescore::messages::*;
+use prost::Message;
+
+// Reads given up on keep running in the background, and only so many may.
+static SERIAL: Mutex<()> = Mutex::new(());
+
+fn serial() -> MutexGuard<'static, ()> {
+    SERIAL.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn run<F>(
+    command: Command,
+    cancel: Option<&CancelHandle>,
+    deadline: Option<Instant>,
+    f: F,
+) -> Vec<u8>
+where
+    F: FnOnce() -> Vec<u8> + Send + 'static,
+{
+    cancel::run(command, cancel, deadline, f).ok().expect("the command doesn't panic")
+}
+
+fn done() -> Vec<u8> {
+    encode(&EmptyResultResponse {
+        success: true,
+        ..Default::default()
+    })
+}
+
+fn status(response: &[u8]) -> ResponseStatus {
+    ResponseStatus::decode(response).unwrap()
+}
+
+fn error_code(response: &[u8]) -> ErrorCode {
+    let status = status(response);
+    assert!(!status.success, "the command succeeded");
+    status.error.unwrap().code()
+}
+
+// A command which doesn't finish before `release` is sent to, and which sends
+// to `started` once it runs.
+fn blocked() -> (impl FnOnce() -> Vec<u8> + Send + 'static, mpsc::Receiver<()>, mpsc::Sender<()>) {
+    let (started_tx, started) = mpsc::channel();
+    let (release, released) = mpsc::channel::<()>();
+    let command = move || {
+        let _ = started_tx.send(());
+        let _ = released.recv();
+        done()
+    };
+    (command, started, release)
+}
+
+#[test]
+fn never_starts_a_cancelled_command() {
+    let _serial = serial();
+    let cancel = CancelHandle::default();
+    cancel.cancel();
+
+    let ran = Arc::new(AtomicBool::new(false));
+    let running = Arc::clone(&ran);
+    let response = run(Command::CreateNote, Some(&cancel), None, move || {
+        running.store(true, Ordering::SeqCst);
+        done()
+    });
+
+    assert_eq!(error_code(&response), ErrorCode::Cancelled);
+    assert!(!ran.load(Ordering::SeqCst));
+}
+
+#[test]
+fn never_starts_a_command_past_its_deadline() {
+    let _serial = serial();
+    let response =
+        run(Command::CreateNote, None, Some(Instant::now()), || panic!("the command started"));
+    assert_eq!(error_code(&response), ErrorCode::TimedOut);
+}
+
+#[test]
+fn gives_up_on_a_cancelled_read() {
+    let _serial = serial();
+    let cancel = CancelHandle::default();
+    let (command, started, release) = blocked();
+
+    let cancelling = cancel.clone();
+    let canceller = thread::spawn(move || {
+        started.recv().unwrap();
+        cancelling.cancel();
+    });
+    let response = run(Command::GetAllNotes, Some(&cancel), None, command);
+
+    assert_eq!(error_code(&response), ErrorCode::Cancelled);
+    canceller.join().unwrap();
+    release.send(()).unwrap();
+}
+
+#[test]
+fn gives_up_on_a_read_past_its_deadline() {
+    let _serial = serial();
+    let (command, _started, release) = blocked();
+    let deadline = Instant::now() + Duration::from_millis(50);
+
+    let response = run(Command::SearchNotes, None, Some(deadline), command);
+
+    assert_eq!(error_code(&response), ErrorCode::TimedOut);
+    release.send(()).unwrap();
+}
+
+#[test]
+fn finishes_a_started_write() {
+    let _serial = serial();
+    let cancel = CancelHandle::default();
+    let (command, started, release) = blocked();
+
+    let cancelling = cancel.clone();
+    let canceller = thread::spawn(move || {
+        started.recv().unwrap();
+        cancelling.cancel();
+        release.send(()).unwrap();
+    });
+    let deadline = Instant::now() + Duration::from_millis(10);
+    let response = run(Command::UpdateNote, Some(&cancel), Some(deadline), command);
+
+    assert!(status(&response).success);
+    canceller.join().unwrap();
+}
+
+#[test]
+fn cancels_every_read_sharing_a_token() {
+    let _serial = serial();
+    let cancel = CancelHandle::default();
+    let (first, first_started, first_release) = blocked();
+    let (second, second_started, second_release) = blocked();
+
+    let reader = |command, read| {
+        let cancel = cancel.clone();
+        thread::spawn(move || run(command, Some(&cancel), None, read))
+    };
+    let readers = [reader(Command::GetNoteById, first), reader(Command::GetFavorites, second)];
+    first_started.recv().unwrap();
+    second_started.recv().unwrap();
+    cancel.cancel();
+
+    for reader in readers {
+        assert_eq!(error_code(&reader.join().unwrap()), ErrorCode::Cancelled);
+    }
+    first_release.send(()).unwrap();
+    second_release.send(()).unwrap();
+}
+
+// Runs a synchronization of the fake core which takes 10 seconds, see
+// src/mock.rs, through `cancel::run`.
+fn slow_sync(core: &Core, cancel: Option<&CancelHandle>, deadline: Option<Instant>) -> Vec<u8> {
+    let register = Login {
+        email: "slow@example.com".to_string(),
+        password: "secret".to_string(),
+    };
+    let response: LoginResponse = core.run(Command::Register, &register);
+    assert!(response.success);
+
+    env::set_var("GIGANOTES_MOCK_SYNC_DELAY_MS", "10000");
+    let sync = || dispatch::run(Command::Synchronize, &encode(&Synchronize {}), handle_async_command);
+    let response = run(Command::Synchronize, cancel, deadline, sync);
+    env::remove_var("GIGANOTES_MOCK_SYNC_DELAY_MS");
+    response
+}
+
+#[test]
+fn stops_waiting_for_a_cancelled_synchronization() {
+    let _serial = serial();
+    let core = Core::new();
+    let cancel = CancelHandle::default();
+    let canceller = {
+        let cancel = cancel.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            cancel.cancel();
+        })
+    };
+
+    let started = Instant::now();
+    let response = slow_sync(&core, Some(&cancel), None);
+    let error = status(&response).error.unwrap();
+    assert_eq!(error.code(), ErrorCode::Cancelled);
+    assert!(error.message.contains("may still be running"), "{}", error.message);
+    assert!(started.elapsed() < Duration::from_secs(5));
+    canceller.join().unwrap();
+}
+
+#[test]
+fn stops_waiting_for_a_synchronization_past_its_deadline() {
+    let _serial = serial();
+    let core = Core::new();
+
+    let started = Instant::now();
+    let deadline = started + Duration::from_millis(50);
+    let response = slow_sync(&core, None, Some(deadline));
+    let error = status(&response).error.unwrap();
+    assert_eq!(error.code(), ErrorCode::TimedOut);
+    assert!(error.message.contains("may still be running"), "{}", error.message);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,126 @@
+// Shared by the integration tests, each of which uses only some of it.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::{env, fs, process};
+
+use giganotescore::backend::{handle_async_command, handle_command};
+use giganotescore::command::Command;
+use giganotescore::dispatch::{self, encode, CoreEntry};
+use giganotescore::messages::*;
+use prost::Message;
+
+static NEXT_DATA_PATH: AtomicUsize = AtomicUsize::new(0);
+
+// The process has a single core, which serves one data path at a time.
+static CORE: Mutex<()> = Mutex::new(());
+
+// The fake core never contacts the API, so it is given an address nothing
+// listens on. No test covers the HTTP side of logins or synchronizations,
+// see the README.
+const API_PATH: &str = "http://127.0.0.1:9";
+
+// The core initialized with a data path of its own. Commands run through the
+// dispatch used by `handleCommand`. Tests run in parallel, so each one waits
+// for the core to be released by the others first.
+pub struct Core {
+    data_path: PathBuf,
+    _core: MutexGuard<'static, ()>,
+}
+
+impl Core {
+    pub fn new() -> Core {
+        let core = CORE.lock().unwrap_or_else(PoisonError::into_inner);
+        let data_path = env::temp_dir().join(format!(
+            "giganotes-test-{}-{}",
+            process::id(),
+            NEXT_DATA_PATH.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(&data_path).unwrap();
+
+        let core = Core {
+            data_path,
+            _core: core,
+        };
+        let init = InitData {
+            data_path: core.data_path.to_string_lossy().into_owned(),
+            api_path: API_PATH.to_string(),
+        };
+        let response: EmptyResultResponse = core.run(Command::InitData, &init);
+        assert!(response.success, "{:?}", response.error);
+        core
+    }
+
+    pub fn data_path(&self) -> &Path {
+        &self.data_path
+    }
+
+    // Runs `command` with the encoded `request` and decodes the response as
+    // `R`.
+    pub fn run<R: Message + Default>(&self, command: Command, request: &impl Message) -> R {
+        let entry: CoreEntry = match command {
+            Command::Synchronize => handle_async_command,
+            _ => handle_command,
+        };
+        let response = dispatch::run(command, &encode(request), entry);
+        R::decode(&response[..]).expect("the response decodes as its type")
+    }
+
+    pub fn root_folder_id(&self) -> String {
+        let root: GetRootFolderResponse = self.run(Command::GetRootFolder, &GetRootFolder {});
+        assert!(root.success);
+        root.folder_id
+    }
+
+    pub fn create_folder(&self, title: &str, parent_id: &str) -> String {
+        let request = CreateFolder {
+            parent_id: parent_id.to_string(),
+            title: title.to_string(),
+        };
+        let response: CreateFolderResponse = self.run(Command::CreateFolder, &request);
+        assert!(response.success, "{:?}", response.error);
+        response.folder_id
+    }
+
+    pub fn create_note(&self, title: &str, text: &str, folder_id: &str) -> String {
+        let request = CreateNote {
+            title: title.to_string(),
+            text: text.to_string(),
+            folder_id: folder_id.to_string(),
+        };
+        let response: CreateNoteResponse = self.run(Command::CreateNote, &request);
+        assert!(response.success, "{:?}", response.error);
+        response.note_id
+    }
+
+    pub fn notes_in(&self, folder_id: &str) -> Vec<NoteShortInfo> {
+        let request = GetNotesList {
+            folder_id: folder_id.to_string(),
+        };
+        let response: GetNotesListResponse = self.run(Command::GetNotesByFolder, &request);
+        assert!(response.success, "{:?}", response.error);
+        response.notes
+    }
+}
+
+impl Drop for Core {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.data_path);
+    }
+}
+
+// The error of a failed response of any type. Panics if the response
+// reports success.
+pub fn error<M: Message>(response: &M) -> Error {
+    let status = ResponseStatus::decode(&encode(response)[..]).unwrap();
+    assert!(!status.success, "the command succeeded");
+    status.error.expect("failed responses carry an error")
+}
+
+pub fn ids(notes: &[NoteShortInfo]) -> Vec<&str> {
+    let mut ids: Vec<&str> = notes.iter().map(|note| note.id.as_str()).collect();
+    ids.sort_unstable();
+    ids
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use common::Core;
+use giganotescore::dispatch::encode;
+use giganotescore::events::{self, Event, Subscription};
+use giganotescore::messages::core_event::Payload;
+use giganotescore::messages::*;
+use giganotescore::queue::{EventQueue, Overflow, Queued};
+use prost::Message;
+
+fn note_changed(seq: u64, note_id: &str) -> Event {
+    let mut changed = NoteChanged {
+        note_id: note_id.to_string(),
+        ..Default::default()
+    };
+    changed.set_kind(ChangeKind::Updated);
+    Event {
+        seq,
+        data: encode(&CoreEvent {
+            payload: Some(Payload::NoteChanged(changed)),
+        }),
+        replayed: false,
+    }
+}
+
+fn payload(data: &[u8]) -> Payload {
+    CoreEvent::decode(data).unwrap().payload.unwrap()
+}
+
+fn seqs(events: &[Queued]) -> Vec<u64> {
+    events.iter().map(|event| event.seq).collect()
+}
+
+// Subscribes to events, replaying those after `since`.
+fn subscribe(since: Option<u64>) -> (Subscription, Receiver<Event>) {
+    subscribe_with_capacity(since, usize::MAX)
+}
+
+// Like `subscribe`, for a subscriber queueing at most `capacity` events.
+fn subscribe_with_capacity(
+    since: Option<u64>,
+    capacity: usize,
+) -> (Subscription, Receiver<Event>) {
+    let (tx, rx) = mpsc::channel();
+    let sink = move |event| {
+        let _ = tx.send(event);
+    };
+    (events::subscribe(Box::new(sink), since, capacity), rx)
+}
+
+// Waits for the `folderChanged` event of the folder `id`.
+fn folder_changed(events: &Receiver<Event>, id: &str) -> Event {
+    loop {
+        let event = events.recv_timeout(Duration::from_secs(10)).unwrap();
+        match payload(&event.data) {
+            Payload::FolderChanged(changed) if changed.folder_id == id => return event,
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn drops_the_oldest_events_of_a_full_queue() {
+    let queue = EventQueue::new(2, Overflow::DropOldest);
+    assert!(queue.push(note_changed(1, "a")));
+    assert!(!queue.push(note_changed(2, "b")));
+    assert!(!queue.push(note_changed(3, "c")));
+
+    let drained = queue.drain();
+    assert_eq!(seqs(&drained), [0, 2, 3]);
+    match payload(&drained[0].data) {
+        Payload::EventsDropped(dropped) => assert_eq!(dropped.count, 1),
+        other => panic!("not eventsDropped: {:?}", other),
+    }
+
+    // Only drops since the last drain are reported.
+    assert!(queue.push(note_changed(4, "d")));
+    assert_eq!(seqs(&queue.drain()), [4]);
+}
+
+#[test]
+fn coalesces_changes_of_the_same_note() {
+    let queue = EventQueue::new(8, Overflow::Coalesce);
+    queue.push(note_changed(1, "a"));
+    queue.push(note_changed(2, "b"));
+    queue.push(note_changed(3, "a"));
+
+    // The change of "a" moves behind the change of "b".
+    assert_eq!(seqs(&queue.drain()), [2, 3]);
+}
+
+#[test]
+fn blocks_the_pump_until_a_full_queue_is_drained() {
+    let queue = Arc::new(EventQueue::new(1, Overflow::Block));
+    queue.push(note_changed(1, "a"));
+
+    let pushed = Arc::clone(&queue);
+    let (tx, rx) = mpsc::channel();
+    let pump = thread::spawn(move || {
+        pushed.push(note_changed(2, "b"));
+        tx.send(()).unwrap();
+    });
+    assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+
+    assert_eq!(seqs(&queue.drain()), [1]);
+    rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    pump.join().unwrap();
+    assert_eq!(seqs(&queue.drain()), [2]);
+}
+
+#[test]
+fn releases_a_blocked_pump_when_closed() {
+    let queue = Arc::new(EventQueue::new(1, Overflow::Block));
+    queue.push(note_changed(1, "a"));
+
+    let pushed = Arc::clone(&queue);
+    let pump = thread::spawn(move || pushed.push(note_changed(2, "b")));
+    thread::sleep(Duration::from_millis(50));
+    queue.close();
+
+    assert!(!pump.join().unwrap());
+    assert!(queue.drain().is_empty());
+}
+
+#[test]
+fn replays_missed_events() {
+    let core = Core::new();
+    let (_watch, watched) = subscribe(None);
+    let first = core.create_folder("First", "");
+    let seen = folder_changed(&watched, &first).seq;
+
+    // Once it has been delivered, the event is in the replay log.
+    let second = core.create_folder("Second", "");
+    let missed = folder_changed(&watched, &second).seq;
+
+    let (_subscription, events) = subscribe(Some(seen));
+    let replayed = folder_changed(&events, &second);
+    assert!(replayed.replayed);
+    assert_eq!(replayed.seq, missed);
+}
+
+#[test]
+fn asks_to_resync_when_missed_events_exceed_the_capacity() {
+    let core = Core::new();
+    let (_watch, watched) = subscribe(None);
+    let first = core.create_folder("First", "");
+    let seen = folder_changed(&watched, &first).seq;
+
+    let second = core.create_folder("Second", "");
+    let third = core.create_folder("Third", "");
+    folder_changed(&watched, &second);
+    folder_changed(&watched, &third);
+
+    let (_subscription, events) = subscribe_with_capacity(Some(seen), 1);
+    let event = events.recv_timeout(Duration::from_secs(10)).unwrap();
+    match payload(&event.data) {
+        Payload::ResyncRequired(_) => {}
+        other => panic!("not resyncRequired: {:?}", other),
+    }
+}
+
+#[test]
+fn asks_to_resync_when_events_cannot_be_replayed() {
+    let _core = Core::new();
+    let (_subscription, events) = subscribe(Some(u64::MAX));
+
+    let event = events.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(event.seq, 0);
+    match payload(&event.data) {
+        Payload::ResyncRequired(_) => {}
+        other => panic!("not resyncRequired: {:?}", other),
+    }
+}
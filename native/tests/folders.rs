@@ -0,0 +1,98 @@
+mod common;
+
+use common::{error, Core};
+use giganotescore::command::Command;
+use giganotescore::messages::*;
+
+#[test]
+fn starts_with_a_root_folder() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+
+    let folders: GetFoldersListResponse = core.run(Command::GetAllFolders, &GetAllFolders {});
+    assert!(folders.success);
+    assert!(folders.folders.iter().any(|folder| folder.id == root_id));
+}
+
+#[test]
+fn creates_and_reads_a_folder() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+    let id = core.create_folder("Projects", &root_id);
+
+    let request = GetFolderById {
+        folder_id: id.clone(),
+    };
+    let folder: GetFolderByIdResponse = core.run(Command::GetFolderById, &request);
+    assert!(folder.success);
+    assert_eq!(folder.id, id);
+    assert_eq!(folder.title, "Projects");
+    assert_eq!(folder.parent_id, root_id);
+
+    let folders: GetFoldersListResponse = core.run(Command::GetAllFolders, &GetAllFolders {});
+    assert!(folders.folders.iter().any(|folder| folder.id == id));
+}
+
+#[test]
+fn updates_a_folder() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+    let id = core.create_folder("Old", &root_id);
+
+    let update = UpdateFolder {
+        id: id.clone(),
+        parent_id: root_id,
+        title: "New".to_string(),
+        level: 1,
+    };
+    let response: EmptyResultResponse = core.run(Command::UpdateFolder, &update);
+    assert!(response.success);
+
+    let folder: GetFolderByIdResponse =
+        core.run(Command::GetFolderById, &GetFolderById { folder_id: id });
+    assert_eq!(folder.title, "New");
+}
+
+#[test]
+fn removes_a_folder_with_its_notes() {
+    let core = Core::new();
+    let id = core.create_folder("Doomed", &core.root_folder_id());
+    let note_id = core.create_note("Inside", "", &id);
+
+    let request = RemoveFolder {
+        folder_id: id.clone(),
+    };
+    let response: EmptyResultResponse = core.run(Command::RemoveFolder, &request);
+    assert!(response.success);
+
+    let folder: GetFolderByIdResponse =
+        core.run(Command::GetFolderById, &GetFolderById { folder_id: id });
+    assert_eq!(error(&folder).code(), ErrorCode::NotFound);
+
+    let note: GetNoteByIdResponse = core.run(Command::GetNoteById, &GetNoteById { note_id });
+    assert_eq!(error(&note).code(), ErrorCode::NotFound);
+}
+
+#[test]
+fn rejects_a_missing_folder_id() {
+    let core = Core::new();
+    let response: EmptyResultResponse = core.run(Command::RemoveFolder, &RemoveFolder::default());
+
+    let error = error(&response);
+    assert_eq!(error.code(), ErrorCode::ValidationFailed);
+    assert_eq!(error.field, "folderId");
+}
+
+#[test]
+fn refuses_to_remove_the_root_folder() {
+    let core = Core::new();
+    let request = RemoveFolder {
+        folder_id: core.root_folder_id(),
+    };
+    let response: EmptyResultResponse = core.run(Command::RemoveFolder, &request);
+
+    // Failures are categorized by command, the core's code is passed on as is.
+    let error = error(&response);
+    assert_eq!(error.code(), ErrorCode::StorageError);
+    assert_ne!(error.core_code, 0);
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use common::{error, Core};
+use giganotescore::backend::handle_command;
+use giganotescore::command::Command;
+use giganotescore::dispatch::{self, encode};
+use giganotescore::events::{self, event_name};
+use giganotescore::lifecycle;
+use giganotescore::messages::*;
+use prost::Message;
+
+static STARTED: AtomicBool = AtomicBool::new(false);
+static RELEASED: AtomicBool = AtomicBool::new(false);
+
+// A core entry point which doesn't hand the command to the core before
+// `RELEASED` is set.
+fn held_up(index: i8, data: &[u8], len: usize) -> Vec<u8> {
+    STARTED.store(true, Ordering::SeqCst);
+    while !RELEASED.load(Ordering::SeqCst) {
+        thread::sleep(Duration::from_millis(1));
+    }
+    handle_command(index, data, len)
+}
+
+fn init_data(core: &Core) -> EmptyResultResponse {
+    let init = InitData {
+        data_path: core.data_path().to_string_lossy().into_owned(),
+        api_path: String::new(),
+    };
+    core.run(Command::InitData, &init)
+}
+
+#[test]
+fn rejects_commands_until_restarted() {
+    let core = Core::new();
+    lifecycle::shutdown();
+
+    let root: GetRootFolderResponse = core.run(Command::GetRootFolder, &GetRootFolder {});
+    assert_eq!(error(&root).code(), ErrorCode::ShutDown);
+
+    assert!(init_data(&core).success);
+    core.root_folder_id();
+}
+
+#[test]
+fn waits_for_running_commands() {
+    let core = Core::new();
+    let running = thread::spawn(|| {
+        let response = dispatch::run(Command::GetRootFolder, &encode(&GetRootFolder {}), held_up);
+        GetRootFolderResponse::decode(&response[..]).unwrap()
+    });
+    while !STARTED.load(Ordering::SeqCst) {
+        thread::sleep(Duration::from_millis(1));
+    }
+    // Restarting doesn't wait for running commands either.
+    assert_eq!(error(&init_data(&core)).code(), ErrorCode::Busy);
+
+    let (done, shut_down) = mpsc::channel();
+    let shutdown = thread::spawn(move || {
+        lifecycle::shutdown();
+        done.send(()).unwrap();
+    });
+    thread::sleep(Duration::from_millis(50));
+    assert!(shut_down.try_recv().is_err(), "shut down with a command running");
+
+    // Shutting down already.
+    let folders: GetFoldersListResponse = core.run(Command::GetAllFolders, &GetAllFolders {});
+    assert_eq!(error(&folders).code(), ErrorCode::ShutDown);
+    assert_eq!(error(&init_data(&core)).code(), ErrorCode::Busy);
+
+    RELEASED.store(true, Ordering::SeqCst);
+    assert!(running.join().unwrap().success);
+    shutdown.join().unwrap();
+    assert!(init_data(&core).success);
+}
+
+#[test]
+fn delivers_pending_events_before_stopping() {
+    let core = Core::new();
+    let (tx, rx) = mpsc::channel();
+    let _subscription = events::subscribe(
+        Box::new(move |event| {
+            let _ = tx.send(event_name(&event.data));
+        }),
+        None,
+        usize::MAX,
+    );
+
+    core.create_note("Before", "", &core.root_folder_id());
+    lifecycle::shutdown();
+    assert!(rx.try_iter().any(|name| name == "noteChanged"));
+
+    // Restarting starts delivering events again.
+    assert!(init_data(&core).success);
+    core.create_note("After", "", &core.root_folder_id());
+    let name = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    assert_eq!(name, "noteChanged");
+}
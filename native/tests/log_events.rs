@@ -0,0 +1,48 @@
+// Apart from tests/logging.rs, which installs another logger in its process.
+use std::sync::mpsc;
+use std::time::Duration;
+
+use giganotescore::events;
+use giganotescore::logging::{self, LogConfig};
+use giganotescore::messages::core_event::Payload;
+use giganotescore::messages::*;
+use log::{debug, info, LevelFilter};
+use prost::Message;
+
+#[test]
+fn forwards_records_as_log_events() {
+    let config = LogConfig {
+        level: LevelFilter::Off,
+        filters: Vec::new(),
+        console: false,
+        file: None,
+        forward_level: LevelFilter::Info,
+    };
+    logging::configure(config).unwrap();
+
+    let (tx, rx) = mpsc::channel();
+    let _subscription = events::subscribe(
+        Box::new(move |event| {
+            let _ = tx.send(event);
+        }),
+        None,
+        usize::MAX,
+    );
+
+    let before = chrono::Utc::now().timestamp_millis();
+    debug!(target: "giganotes_test", "not forwarded");
+    info!(target: "giganotes_test", "forwarded {}", 42);
+
+    let (seq, record) = loop {
+        let event = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+        if let Some(Payload::Log(record)) = CoreEvent::decode(&event.data[..]).unwrap().payload {
+            break (event.seq, record);
+        }
+    };
+    assert_eq!(seq, 0);
+    assert_eq!(record.level(), LogLevel::Info);
+    assert_eq!(record.target, "giganotes_test");
+    assert_eq!(record.message, "forwarded 42");
+    assert!(record.timestamp >= before);
+    assert!(record.timestamp <= chrono::Utc::now().timestamp_millis());
+}
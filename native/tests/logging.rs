@@ -0,0 +1,51 @@
+use std::path::Path;
+
+use giganotescore::logging::{self, LogConfig, LogError};
+use log::{LevelFilter, Log, Metadata, Record};
+
+#[test]
+fn resolves_the_log_file_inside_the_data_path() {
+    let path = logging::log_file_path(Path::new("/data"), "logs/core.log").unwrap();
+    assert_eq!(path, Path::new("/data/logs/core.log"));
+}
+
+#[test]
+fn rejects_a_log_file_outside_the_data_path() {
+    for file in &["/etc/passwd", "../core.log", "logs/../../core.log", ""] {
+        match logging::log_file_path(Path::new("/data"), file) {
+            Err(LogError::InvalidFile(rejected)) => assert_eq!(&rejected, file),
+            other => panic!("{:?} was accepted: {:?}", file, other),
+        }
+    }
+}
+
+struct OtherLogger;
+
+impl Log for OtherLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        false
+    }
+
+    fn log(&self, _record: &Record) {}
+
+    fn flush(&self) {}
+}
+
+static OTHER_LOGGER: OtherLogger = OtherLogger;
+
+#[test]
+fn reports_another_logger_installed_first() {
+    log::set_logger(&OTHER_LOGGER).unwrap();
+
+    let config = LogConfig {
+        level: LevelFilter::Info,
+        filters: Vec::new(),
+        console: false,
+        file: None,
+        forward_level: LevelFilter::Off,
+    };
+    match logging::configure(config) {
+        Err(LogError::LoggerInstalled) => {}
+        other => panic!("configured logging over another logger: {:?}", other),
+    }
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use common::{error, ids, Core};
+use giganotescore::command::Command;
+use giganotescore::messages::*;
+
+#[test]
+fn creates_and_reads_a_note() {
+    let core = Core::new();
+    let folder_id = core.create_folder("Work", &core.root_folder_id());
+    let id = core.create_note("Plan", "Ship it", &folder_id);
+
+    let request = GetNoteById {
+        note_id: id.clone(),
+    };
+    let note: GetNoteByIdResponse = core.run(Command::GetNoteById, &request);
+    assert!(note.success);
+    assert_eq!(note.id, id);
+    assert_eq!(note.folder_id, folder_id);
+    assert_eq!(note.title, "Plan");
+    assert_eq!(note.text, "Ship it");
+}
+
+#[test]
+fn lists_the_notes_of_a_folder() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+    let folder_id = core.create_folder("Work", &root_id);
+    let first = core.create_note("First", "", &folder_id);
+    let second = core.create_note("Second", "", &folder_id);
+    core.create_note("Elsewhere", "", &root_id);
+
+    let mut expected = vec![first.as_str(), second.as_str()];
+    expected.sort_unstable();
+    assert_eq!(ids(&core.notes_in(&folder_id)), expected);
+}
+
+#[test]
+fn pages_through_all_notes() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+    for i in 0..5 {
+        core.create_note(&format!("Note {}", i), "", &root_id);
+    }
+
+    let page = |offset, limit| {
+        let response: GetNotesListResponse =
+            core.run(Command::GetAllNotes, &GetAllNotes { offset, limit });
+        assert!(response.success);
+        response.notes
+    };
+    assert_eq!(page(0, 3).len(), 3);
+    assert_eq!(page(3, 3).len(), 2);
+}
+
+#[test]
+fn updates_a_note() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+    let folder_id = core.create_folder("Archive", &root_id);
+    let id = core.create_note("Draft", "one", &root_id);
+
+    let update = UpdateNote {
+        id: id.clone(),
+        folder_id: folder_id.clone(),
+        title: "Final".to_string(),
+        text: "two".to_string(),
+    };
+    let response: EmptyResultResponse = core.run(Command::UpdateNote, &update);
+    assert!(response.success);
+
+    let note: GetNoteByIdResponse = core.run(Command::GetNoteById, &GetNoteById { note_id: id });
+    assert_eq!(note.folder_id, folder_id);
+    assert_eq!(note.title, "Final");
+    assert_eq!(note.text, "two");
+}
+
+#[test]
+fn removes_a_note() {
+    let core = Core::new();
+    let id = core.create_note("Doomed", "", &core.root_folder_id());
+
+    let response: EmptyResultResponse =
+        core.run(Command::RemoveNote, &RemoveNote { note_id: id.clone() });
+    assert!(response.success);
+
+    let note: GetNoteByIdResponse = core.run(Command::GetNoteById, &GetNoteById { note_id: id });
+    assert_eq!(error(&note).code(), ErrorCode::NotFound);
+}
+
+#[test]
+fn keeps_favorites() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+    let favorite = core.create_note("Favorite", "", &root_id);
+    core.create_note("Other", "", &root_id);
+
+    let add = AddToFavorites {
+        note_id: favorite.clone(),
+    };
+    let response: EmptyResultResponse = core.run(Command::AddToFavorites, &add);
+    assert!(response.success);
+
+    let favorites: GetNotesListResponse = core.run(Command::GetFavorites, &GetFavorites {});
+    assert_eq!(ids(&favorites.notes), vec![favorite.as_str()]);
+
+    let remove = RemoveFromFavorites { note_id: favorite };
+    let response: EmptyResultResponse = core.run(Command::RemoveFromFavorites, &remove);
+    assert!(response.success);
+
+    let favorites: GetNotesListResponse = core.run(Command::GetFavorites, &GetFavorites {});
+    assert!(favorites.notes.is_empty());
+}
+
+#[test]
+fn searches_titles_and_texts() {
+    let core = Core::new();
+    let root_id = core.root_folder_id();
+    let by_title = core.create_note("Groceries", "", &root_id);
+    let by_text = core.create_note("Weekend", "buy groceries", &root_id);
+    core.create_note("Unrelated", "", &root_id);
+
+    let search = SearchNotes {
+        query: "groceries".to_string(),
+        folder_id: String::new(),
+    };
+    let response: GetNotesListResponse = core.run(Command::SearchNotes, &search);
+    assert!(response.success);
+
+    let mut expected = vec![by_title.as_str(), by_text.as_str()];
+    expected.sort_unstable();
+    assert_eq!(ids(&response.notes), expected);
+}
+
+#[test]
+fn rejects_a_missing_note_id() {
+    let core = Core::new();
+    let note: GetNoteByIdResponse = core.run(Command::GetNoteById, &GetNoteById::default());
+
+    let error = error(&note);
+    assert_eq!(error.code(), ErrorCode::ValidationFailed);
+    assert_eq!(error.field, "noteId");
+}
+
+#[test]
+fn reports_an_unknown_note_as_not_found() {
+    let core = Core::new();
+    let request = GetNoteById {
+        note_id: "no-such-note".to_string(),
+    };
+    let note: GetNoteByIdResponse = core.run(Command::GetNoteById, &request);
+
+    let error = error(&note);
+    assert_eq!(error.code(), ErrorCode::NotFound);
+    assert!(!error.retryable);
+}
+
+#[test]
+fn reports_updating_an_unknown_note_as_a_failed_write() {
+    let core = Core::new();
+    let request = UpdateNote {
+        id: "no-such-note".to_string(),
+        title: "Lost".to_string(),
+        ..Default::default()
+    };
+    let response: EmptyResultResponse = core.run(Command::UpdateNote, &request);
+
+    let error = error(&response);
+    assert_eq!(error.code(), ErrorCode::StorageError);
+    assert_ne!(error.core_code, 0);
+}
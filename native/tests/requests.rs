@@ -0,0 +1,123 @@
+mod common;
+
+use common::Core;
+use giganotescore::dispatch::encode;
+use giganotescore::envelope;
+use giganotescore::messages::request::Command as RequestCommand;
+use giganotescore::messages::response::Body;
+use giganotescore::messages::*;
+use prost::Message;
+
+fn create_note(request_id: u64, title: &str) -> Request {
+    Request {
+        request_id,
+        command: Some(RequestCommand::CreateNote(CreateNote {
+            title: title.to_string(),
+            text: String::new(),
+            folder_id: String::new(),
+        })),
+    }
+}
+
+fn get_note_by_id(request_id: u64, note_id: &str) -> Request {
+    Request {
+        request_id,
+        command: Some(RequestCommand::GetNoteById(GetNoteById {
+            note_id: note_id.to_string(),
+        })),
+    }
+}
+
+// Runs `requests` as a `RequestSequence` and returns their responses.
+fn run_sequence(requests: Vec<Request>, stop_on_error: bool) -> Vec<Response> {
+    let sequence = RequestSequence {
+        requests,
+        stop_on_error,
+    };
+    let response = envelope::handle_sequence(&encode(&sequence));
+    let response = RequestSequenceResponse::decode(&response[..]).unwrap();
+    assert_eq!(response.error, None);
+    response.responses
+}
+
+fn created_note_id(response: &Response) -> &str {
+    match &response.body {
+        Some(Body::CreateNote(created)) if created.success => &created.note_id,
+        other => panic!("not a created note: {:?}", other),
+    }
+}
+
+#[test]
+fn runs_a_sequence_in_order() {
+    let core = Core::new();
+    let responses = run_sequence(vec![create_note(1, "First"), create_note(2, "Second")], false);
+
+    let request_ids: Vec<u64> = responses.iter().map(|response| response.request_id).collect();
+    assert_eq!(request_ids, [1, 2]);
+    let mut titles: Vec<String> =
+        core.notes_in(&core.root_folder_id()).into_iter().map(|note| note.title).collect();
+    titles.sort_unstable();
+    assert_eq!(titles, ["First", "Second"]);
+}
+
+#[test]
+fn runs_the_rest_of_a_sequence_after_a_failure() {
+    let _core = Core::new();
+    let responses =
+        run_sequence(vec![get_note_by_id(1, "no-such-note"), create_note(2, "Run")], false);
+
+    match &responses[0].body {
+        Some(Body::GetNoteById(note)) => {
+            assert_eq!(note.error.as_ref().unwrap().code(), ErrorCode::NotFound)
+        }
+        other => panic!("not a note: {:?}", other),
+    }
+    created_note_id(&responses[1]);
+}
+
+#[test]
+fn aborts_the_rest_of_a_sequence_after_a_failure() {
+    let core = Core::new();
+    let responses = run_sequence(
+        vec![create_note(1, "Kept"), get_note_by_id(2, "no-such-note"), create_note(3, "Skipped")],
+        true,
+    );
+
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[2].request_id, 3);
+    assert_eq!(responses[2].body, None);
+    assert_eq!(responses[2].error.as_ref().unwrap().code(), ErrorCode::Aborted);
+
+    // The sequence isn't atomic: the note created before the failure stays.
+    let id = created_note_id(&responses[0]);
+    let notes = core.notes_in(&core.root_folder_id());
+    assert_eq!(common::ids(&notes), [id]);
+}
+
+#[test]
+fn rejects_a_malformed_sequence() {
+    let response = envelope::handle_sequence(&[0xff]);
+    let response = RequestSequenceResponse::decode(&response[..]).unwrap();
+    assert!(response.responses.is_empty());
+    assert_eq!(response.error.unwrap().code(), ErrorCode::ValidationFailed);
+}
+
+#[test]
+fn answers_a_request_without_a_command() {
+    let _core = Core::new();
+    let responses = run_sequence(
+        vec![
+            Request {
+                request_id: 1,
+                command: None,
+            },
+            create_note(2, "Run"),
+        ],
+        true,
+    );
+
+    let error = responses[0].error.as_ref().unwrap();
+    assert_eq!(error.code(), ErrorCode::ValidationFailed);
+    assert_eq!(error.field, "command");
+    assert_eq!(responses[1].error.as_ref().unwrap().code(), ErrorCode::Aborted);
+}
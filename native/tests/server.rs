@@ -0,0 +1,334 @@
+#![cfg(all(unix, feature = "server"))]
+
+mod common;
+
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::net::UnixStream;
+use std::thread;
+use std::time::Duration;
+
+use common::Core;
+use giganotescore::command::Command;
+use giganotescore::dispatch::encode;
+use giganotescore::messages::core_event::Payload;
+use giganotescore::messages::server_frame::Body;
+use giganotescore::messages::server_message::Body as Incoming;
+use giganotescore::messages::*;
+use giganotescore::server::{
+    read_frame, write_frame, Server, COMMAND_THREADS, MAX_AUTH_FRAME_SIZE, MAX_FRAME_SIZE,
+    WRITE_TIMEOUT,
+};
+use prost::Message;
+
+// A client of the server, as e.g. an editor plugin would be.
+struct Client {
+    stream: UnixStream,
+    next_request_id: u64,
+}
+
+impl Client {
+    fn connect(server: &Server) -> Client {
+        let stream = UnixStream::connect(server.socket_path()).unwrap();
+        stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+        Client {
+            stream,
+            next_request_id: 1,
+        }
+    }
+
+    fn authenticate(server: &Server) -> Client {
+        let mut client = Client::connect(server);
+        let token = fs::read_to_string(server.token_path()).unwrap();
+        let reply = client.request(Body::Auth(ServerAuth { token }));
+        assert_eq!(reply.error, None);
+        client
+    }
+
+    // Sends `body` without waiting for its reply. Returns its request id.
+    fn send(&mut self, body: Body) -> u64 {
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+        let frame = ServerFrame {
+            request_id,
+            body: Some(body),
+        };
+        write_frame(&mut self.stream, &frame).unwrap();
+        request_id
+    }
+
+    // Sends `body` and waits for its reply, skipping events.
+    fn request(&mut self, body: Body) -> ServerReply {
+        let request_id = self.send(body);
+        loop {
+            match self.receive() {
+                Some(Incoming::Reply(reply)) if reply.request_id == request_id => return reply,
+                Some(_) => {}
+                None => panic!("the server closed the connection"),
+            }
+        }
+    }
+
+    // Runs `command` and decodes its response as `R`.
+    fn run<R: Message + Default>(&mut self, command: Command, request: &impl Message) -> R {
+        let command = ServerCommand {
+            index: command.index() as i32,
+            data: encode(request),
+        };
+        let reply = self.request(Body::Command(command));
+        assert_eq!(reply.error, None);
+        R::decode(&reply.response[..]).expect("the response decodes as its type")
+    }
+
+    fn event(&mut self) -> ServerEvent {
+        loop {
+            match self.receive() {
+                Some(Incoming::Event(event)) => return event,
+                Some(_) => {}
+                None => panic!("the server closed the connection"),
+            }
+        }
+    }
+
+    // The next message from the server, `None` once it closed the connection.
+    fn receive(&mut self) -> Option<Incoming> {
+        let frame = read_frame(&mut self.stream, MAX_FRAME_SIZE).unwrap()?;
+        ServerMessage::decode(&frame[..]).unwrap().body
+    }
+}
+
+#[test]
+fn runs_commands_against_the_core() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::authenticate(&server);
+
+    let root: GetRootFolderResponse = client.run(Command::GetRootFolder, &GetRootFolder {});
+    let request = CreateNote {
+        title: "Remote".to_string(),
+        text: "from a plugin".to_string(),
+        folder_id: root.folder_id,
+    };
+    let created: CreateNoteResponse = client.run(Command::CreateNote, &request);
+    assert!(created.success);
+
+    let note: GetNoteByIdResponse = core.run(
+        Command::GetNoteById,
+        &GetNoteById {
+            note_id: created.note_id,
+        },
+    );
+    assert_eq!(note.title, "Remote");
+}
+
+#[test]
+fn keeps_the_token_private() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+
+    let mode = fs::metadata(server.token_path()).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    let token = fs::read_to_string(server.token_path()).unwrap();
+    assert_eq!(token.len(), 64);
+}
+
+#[test]
+fn keeps_the_socket_private() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+
+    let dir = server.socket_path().parent().unwrap();
+    let mode = fs::metadata(dir).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o700);
+}
+
+#[test]
+fn disconnects_a_client_with_a_wrong_token() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::connect(&server);
+
+    let reply = client.request(Body::Auth(ServerAuth {
+        token: "guessed".to_string(),
+    }));
+    assert_eq!(reply.error.unwrap().code(), ErrorCode::AuthFailed);
+    assert!(client.receive().is_none());
+}
+
+#[test]
+fn disconnects_a_client_which_does_not_authenticate() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::connect(&server);
+
+    let command = ServerCommand {
+        index: Command::GetAllFolders.index() as i32,
+        data: Vec::new(),
+    };
+    let reply = client.request(Body::Command(command));
+    assert_eq!(reply.error.unwrap().code(), ErrorCode::AuthFailed);
+    assert!(reply.response.is_empty());
+    assert!(client.receive().is_none());
+}
+
+#[test]
+fn disconnects_a_client_sending_a_large_frame_before_authenticating() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::connect(&server);
+
+    let len = MAX_AUTH_FRAME_SIZE as u32 + 1;
+    client.stream.write_all(&len.to_be_bytes()).unwrap();
+    assert!(client.receive().is_none());
+}
+
+#[test]
+fn runs_more_commands_than_it_has_threads() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::authenticate(&server);
+
+    let mut pending = HashSet::new();
+    for _ in 0..COMMAND_THREADS * 4 {
+        let command = ServerCommand {
+            index: Command::GetAllFolders.index() as i32,
+            data: encode(&GetAllFolders {}),
+        };
+        pending.insert(client.send(Body::Command(command)));
+    }
+    while !pending.is_empty() {
+        match client.receive() {
+            Some(Incoming::Reply(reply)) => {
+                assert_eq!(reply.error, None);
+                assert!(pending.remove(&reply.request_id));
+            }
+            Some(_) => {}
+            None => panic!("the server closed the connection"),
+        }
+    }
+}
+
+#[test]
+fn drops_a_client_which_stops_reading() {
+    let core = Core::new();
+    let text = "x".repeat(256 * 1024);
+    let note_id = core.create_note("Large", &text, &core.root_folder_id());
+    let server = Server::start(core.data_path()).unwrap();
+
+    // Enough replies to fill the socket's buffers and hold up every command
+    // thread writing to it.
+    let mut stalled = Client::authenticate(&server);
+    for _ in 0..COMMAND_THREADS * 8 {
+        let command = ServerCommand {
+            index: Command::GetNoteById.index() as i32,
+            data: encode(&GetNoteById {
+                note_id: note_id.clone(),
+            }),
+        };
+        stalled.send(Body::Command(command));
+    }
+    thread::sleep(Duration::from_secs(1));
+
+    let mut client = Client::authenticate(&server);
+    let timeout = WRITE_TIMEOUT * 3;
+    client.stream.set_read_timeout(Some(timeout)).unwrap();
+    let root: GetRootFolderResponse = client.run(Command::GetRootFolder, &GetRootFolder {});
+    assert!(root.success);
+
+    stalled.stream.set_read_timeout(Some(timeout)).unwrap();
+    loop {
+        match read_frame(&mut stalled.stream, MAX_FRAME_SIZE) {
+            Ok(Some(_)) => {}
+            Ok(None) => break,
+            // Closed in the middle of a frame.
+            Err(err) => {
+                assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn rejects_an_unknown_command_index() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::authenticate(&server);
+
+    let reply = client.request(Body::Command(ServerCommand {
+        index: 4,
+        data: Vec::new(),
+    }));
+    let error = reply.error.unwrap();
+    assert_eq!(error.code(), ErrorCode::ValidationFailed);
+    assert_eq!(error.field, "index");
+
+    // The connection stays usable.
+    let folders: GetFoldersListResponse = client.run(Command::GetAllFolders, &GetAllFolders {});
+    assert!(folders.success);
+}
+
+#[test]
+fn rejects_init_data() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::authenticate(&server);
+
+    let command = ServerCommand {
+        index: Command::InitData.index() as i32,
+        data: encode(&InitData {
+            data_path: "/elsewhere".to_string(),
+            api_path: String::new(),
+        }),
+    };
+    let reply = client.request(Body::Command(command));
+    assert_eq!(reply.error.unwrap().code(), ErrorCode::ValidationFailed);
+}
+
+#[test]
+fn streams_events_to_subscribers() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::authenticate(&server);
+
+    let reply = client.request(Body::Subscribe(ServerSubscribe::default()));
+    assert_eq!(reply.error, None);
+    let id = core.create_note("Watched", "", &core.root_folder_id());
+
+    loop {
+        let event = client.event();
+        if event.name != "noteChanged" {
+            continue;
+        }
+        assert!(event.seq > 0);
+        match CoreEvent::decode(&event.data[..]).unwrap().payload {
+            Some(Payload::NoteChanged(changed)) if changed.note_id == id => break,
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn refuses_to_replace_a_running_server() {
+    let core = Core::new();
+    let _server = Server::start(core.data_path()).unwrap();
+
+    assert!(Server::start(core.data_path()).is_err());
+}
+
+#[test]
+fn removes_its_files_when_stopped() {
+    let core = Core::new();
+    let server = Server::start(core.data_path()).unwrap();
+    let mut client = Client::authenticate(&server);
+    let socket_path = server.socket_path().to_owned();
+    let token_path = server.token_path().to_owned();
+
+    drop(server);
+    assert!(client.receive().is_none());
+    assert!(!socket_path.exists());
+    assert!(!socket_path.parent().unwrap().exists());
+    assert!(!token_path.exists());
+}
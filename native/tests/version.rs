@@ -0,0 +1,47 @@
+use giganotescore::dispatch::encode;
+use giganotescore::messages::*;
+use giganotescore::version::{handshake, MIN_PROTOCOL_VERSION, PROTOCOL_VERSION};
+use prost::Message;
+
+fn shake(protocol_version: u32) -> HandshakeResponse {
+    let response = handshake(&encode(&Handshake { protocol_version }));
+    HandshakeResponse::decode(&response[..]).unwrap()
+}
+
+#[test]
+fn accepts_the_current_protocol_version() {
+    let response = shake(PROTOCOL_VERSION);
+    assert!(response.success);
+    assert_eq!(response.error, None);
+    assert_eq!(response.version.unwrap().protocol_version, PROTOCOL_VERSION);
+}
+
+#[test]
+fn rejects_a_newer_protocol_version() {
+    let response = shake(PROTOCOL_VERSION + 1);
+    assert!(!response.success);
+
+    let error = response.error.unwrap();
+    assert_eq!(error.code(), ErrorCode::IncompatibleVersion);
+    assert!(error.message.contains("rebuild the native module"), "{}", error.message);
+    // The client learns what the module supports either way.
+    assert_eq!(response.version.unwrap().protocol_version, PROTOCOL_VERSION);
+}
+
+#[test]
+fn rejects_a_protocol_version_no_longer_supported() {
+    let response = shake(MIN_PROTOCOL_VERSION - 1);
+    assert!(!response.success);
+
+    let error = response.error.unwrap();
+    assert_eq!(error.code(), ErrorCode::IncompatibleVersion);
+    assert!(error.message.contains("no longer supported"), "{}", error.message);
+    assert_eq!(response.version.unwrap().min_protocol_version, MIN_PROTOCOL_VERSION);
+}
+
+#[test]
+fn rejects_a_malformed_handshake() {
+    let response = HandshakeResponse::decode(&handshake(&[0xff])[..]).unwrap();
+    assert!(!response.success);
+    assert_eq!(response.error.unwrap().code(), ErrorCode::ValidationFailed);
+}
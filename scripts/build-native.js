@@ -0,0 +1,44 @@
+// Builds the neon module of the Rust package in the directory given as first
+// argument and copies it to native/index.node, where lib/index.js loads it
+// from. The remaining arguments are passed on to `cargo build`, e.g.
+//
+//     node scripts/build-native.js native/core --release
+//
+// Stands in for `neon build`, which only builds the package in native/
+// itself. The library is found through cargo's build messages, so this works
+// for any target and profile.
+const { spawnSync } = require('child_process');
+const fs = require('fs');
+const path = require('path');
+
+const dir = path.resolve(process.argv[2]);
+const args = ['build', '--message-format=json-render-diagnostics'].concat(process.argv.slice(3));
+
+const build = spawnSync('cargo', args, {
+  cwd: dir,
+  stdio: ['inherit', 'pipe', 'inherit'],
+  encoding: 'utf8',
+  maxBuffer: 64 * 1024 * 1024
+});
+if (build.error) throw build.error;
+if (build.status !== 0) process.exit(build.status || 1);
+
+var library;
+build.stdout.split('\n').forEach(function(line) {
+  if (!line.startsWith('{')) return;
+
+  var message = JSON.parse(line);
+  if (message.reason === 'compiler-artifact' &&
+      message.manifest_path === path.join(dir, 'Cargo.toml') &&
+      message.target.kind.indexOf('cdylib') !== -1) {
+    library = message.filenames.find(function(file) {
+      return /\.(so|dylib|dll)$/.test(file);
+    });
+  }
+});
+if (!library) {
+  console.error('cargo built no library in ' + dir);
+  process.exit(1);
+}
+
+fs.copyFileSync(library, path.join(__dirname, '..', 'native', 'index.node'));
@@ -0,0 +1,32 @@
+// Gives up on synchronizations of the fake core, which are made to take
+// long through `GIGANOTES_MOCK_SYNC_DELAY_MS`, see native/src/mock.rs.
+const assert = require('assert');
+const giganotes = require('../lib');
+const messages = require('../lib/messages_pb');
+
+describe('cancellation', function() {
+  before(function() {
+    assert.ok(giganotes.initData('http://localhost', '/mock/cancel').getSuccess());
+    assert.ok(giganotes.register('cancel@example.com', 'secret').getSuccess());
+    process.env.GIGANOTES_MOCK_SYNC_DELAY_MS = '10000';
+  });
+
+  after(function() {
+    delete process.env.GIGANOTES_MOCK_SYNC_DELAY_MS;
+  });
+
+  it('stops waiting for a cancelled synchronization', async function() {
+    var sync = giganotes.synchronize();
+    setTimeout(() => sync.cancel(), 50);
+
+    var error = (await sync).getError();
+    assert.strictEqual(error.getCode(), messages.ErrorCode.CANCELLED);
+    assert.match(error.getMessage(), /may still be running/);
+  });
+
+  it('stops waiting for a synchronization past its timeout', async function() {
+    var error = (await giganotes.synchronize({ timeoutMs: 50 })).getError();
+    assert.strictEqual(error.getCode(), messages.ErrorCode.TIMED_OUT);
+    assert.match(error.getMessage(), /may still be running/);
+  });
+});
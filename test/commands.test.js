@@ -0,0 +1,96 @@
+// Runs the command wrappers of lib/index.js against the fake core of the
+// `mock-core` feature, see README.md.
+const assert = require('assert');
+const addon = require('../native/index.node');
+const giganotes = require('../lib');
+const messages = require('../lib/messages_pb');
+
+describe('commands', function() {
+  before(function() {
+    assert.ok(giganotes.initData('http://localhost', '/mock/commands').getSuccess());
+  });
+
+  it('reports the mock core', function() {
+    var version = giganotes.getVersion();
+    assert.strictEqual(version.getCoreversion(), 'mock');
+    assert.strictEqual(version.getProtocolversion(), giganotes.PROTOCOL_VERSION);
+  });
+
+  it('creates and reads notes', function() {
+    var rootId = giganotes.getRootFolder().getFolderid();
+    var folder = giganotes.createFolder('Work', rootId);
+    assert.ok(folder.getSuccess());
+
+    var created = giganotes.createNote('Plan', 'Ship it', folder.getFolderid());
+    assert.ok(created.getSuccess());
+
+    var note = giganotes.getNoteById(created.getNoteid());
+    assert.strictEqual(note.getTitle(), 'Plan');
+    assert.strictEqual(note.getText(), 'Ship it');
+    assert.strictEqual(note.getFolderid(), folder.getFolderid());
+
+    var notes = giganotes.getNotesByFolder(folder.getFolderid()).getNotesList();
+    assert.deepStrictEqual(notes.map((n) => n.getId()), [created.getNoteid()]);
+  });
+
+  it('updates, searches and removes notes', function() {
+    var id = giganotes.createNote('Groceries', 'milk', '').getNoteid();
+    assert.ok(giganotes.updateNote(id, '', 'Groceries', 'milk, eggs').getSuccess());
+
+    var found = giganotes.searchNotes('EGGS').getNotesList();
+    assert.deepStrictEqual(found.map((n) => n.getId()), [id]);
+
+    assert.ok(giganotes.addToFavorites(id).getSuccess());
+    assert.strictEqual(giganotes.getFavorites().getNotesList().length, 1);
+
+    assert.ok(giganotes.removeNote(id).getSuccess());
+    assert.strictEqual(giganotes.getFavorites().getNotesList().length, 0);
+  });
+
+  it('reports missing entities as NOT_FOUND', function() {
+    var response = giganotes.getNoteById('no-such-note');
+    assert.strictEqual(response.getSuccess(), false);
+    assert.strictEqual(response.getError().getCode(), messages.ErrorCode.NOT_FOUND);
+  });
+
+  it('rejects invalid requests before they reach the core', function() {
+    var response = giganotes.getNoteById('');
+    assert.strictEqual(response.getError().getCode(), messages.ErrorCode.VALIDATION_FAILED);
+    assert.strictEqual(response.getError().getField(), 'noteId');
+  });
+
+  it('throws a TypeError for indices which name no command', function() {
+    var empty = new Uint8Array([]).buffer;
+    [0, 4, 25, 1.5, NaN, 'createNote'].forEach(function(index) {
+      assert.throws(() => addon.handleCommand(empty, index), TypeError, String(index));
+    });
+  });
+
+  it('logs in registered users', function() {
+    assert.ok(giganotes.register('ann@example.com', 'secret').getSuccess());
+    assert.strictEqual(giganotes.makeLogin('ann@example.com', 'wrong').getError().getCode(),
+      messages.ErrorCode.AUTH_FAILED);
+
+    var login = giganotes.makeLogin('ann@example.com', 'secret');
+    assert.ok(login.getSuccess());
+    assert.strictEqual(giganotes.getLastLoginData().getToken(), login.getToken());
+
+    assert.ok(giganotes.makeLogout().getSuccess());
+    assert.strictEqual(giganotes.getLastLoginData().getSuccess(), false);
+  });
+
+  it('runs commands asynchronously', async function() {
+    var created = await giganotes.createNoteAsync('Async', 'text', '');
+    assert.ok(created.getSuccess());
+
+    var note = await giganotes.getNoteByIdAsync(created.getNoteid());
+    assert.strictEqual(note.getTitle(), 'Async');
+  });
+
+  it('counts commands in the metrics', function() {
+    var metrics = giganotes.getMetrics('json');
+    var createNote = metrics.commandsList.find((c) => c.name === 'CreateNote');
+    assert.ok(createNote.calls >= 3);
+    assert.ok(/giganotes_command_calls_total/.test(giganotes.getMetrics('prometheus')));
+  });
+});
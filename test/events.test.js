@@ -0,0 +1,65 @@
+// Checks that events of the fake core reach `MyEventEmitter` subscribers.
+const assert = require('assert');
+const { once } = require('events');
+const giganotes = require('../lib');
+const messages = require('../lib/messages_pb');
+
+describe('events', function() {
+  var emitter;
+
+  before(function() {
+    assert.ok(giganotes.initData('http://localhost', '/mock/events').getSuccess());
+  });
+
+  beforeEach(function() {
+    emitter = new giganotes.MyEventEmitter();
+  });
+
+  afterEach(function() {
+    emitter.shutdown();
+  });
+
+  it('emits noteChanged for created notes', async function() {
+    var changed = once(emitter, 'noteChanged');
+    var id = giganotes.createNote('Event', '', '').getNoteid();
+
+    var [event, seq] = await changed;
+    assert.strictEqual(event.getNoteid(), id);
+    assert.strictEqual(event.getKind(), messages.ChangeKind.CREATED);
+    assert.strictEqual(emitter.lastSeq, seq);
+  });
+
+  it('emits the progress of a synchronization', async function() {
+    assert.ok(giganotes.register('sync@example.com', 'secret').getSuccess());
+
+    var finished = once(emitter, 'syncFinished');
+    var response = await giganotes.synchronize();
+    assert.ok(response.getSuccess());
+    await finished;
+  });
+
+  it('is subscribed only while it has listeners', function() {
+    var listener = function() {};
+    assert.strictEqual(emitter.channel, null);
+    emitter.on('noteChanged', listener);
+    assert.notStrictEqual(emitter.channel, null);
+    emitter.off('noteChanged', listener);
+    assert.strictEqual(emitter.channel, null);
+  });
+
+  it('replays missed events', async function() {
+    var seq = emitter.lastSeq || 0;
+    var first = once(emitter, 'folderChanged');
+    var id = giganotes.createFolder('Replayed', '').getFolderid();
+    [, seq] = await first;
+    giganotes.createNote('After', '', id);
+
+    var replay = new giganotes.MyEventEmitter({ sinceSeq: seq });
+    try {
+      var [event] = await once(replay, 'noteChanged');
+      assert.strictEqual(event.getFolderid(), id);
+    } finally {
+      replay.shutdown();
+    }
+  });
+});
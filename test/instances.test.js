@@ -0,0 +1,55 @@
+// Runs two `GiganotesCore`s, each in a child process with its own data path
+// and account.
+const assert = require('assert');
+const { once } = require('events');
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
+const giganotes = require('../lib');
+const messages = require('../lib/messages_pb');
+
+describe('GiganotesCore', function() {
+  var dirs = [];
+  var cores = [];
+
+  before(function() {
+    dirs = [0, 1].map(() => fs.mkdtempSync(path.join(os.tmpdir(), 'giganotes-core-')));
+    cores = dirs.map((dir) => new giganotes.GiganotesCore('http://localhost', dir));
+  });
+
+  after(async function() {
+    await Promise.all(cores.map((core) => core.shutdown()));
+    dirs.forEach((dir) => fs.rmSync(dir, { recursive: true }));
+  });
+
+  it('keeps the accounts and notes of each instance apart', async function() {
+    assert.ok((await cores[0].register('first@example.com', 'secret')).getSuccess());
+    assert.ok((await cores[1].register('second@example.com', 'secret')).getSuccess());
+
+    var root = (await cores[0].getRootFolder()).getFolderid();
+    assert.ok((await cores[0].createNote('Only here', '', root)).getSuccess());
+
+    var first = await cores[0].getAllNotes(0, 100);
+    var second = await cores[1].getAllNotes(0, 100);
+    assert.ok(first.getNotesList().some((note) => note.getTitle() === 'Only here'));
+    assert.ok(!second.getNotesList().some((note) => note.getTitle() === 'Only here'));
+
+    assert.strictEqual((await cores[0].getLastLoginData()).getEmail(), 'first@example.com');
+    assert.strictEqual((await cores[1].getLastLoginData()).getEmail(), 'second@example.com');
+  });
+
+  it('emits the events of its own core', async function() {
+    var changed = once(cores[1], 'noteChanged');
+    var root = (await cores[1].getRootFolder()).getFolderid();
+    var id = (await cores[1].createNote('Changed', '', root)).getNoteid();
+
+    var [event, seq] = await changed;
+    assert.strictEqual(event.getNoteid(), id);
+    assert.strictEqual(cores[1].lastSeq, seq);
+  });
+
+  it('rejects commands the server refuses', async function() {
+    await assert.rejects(cores[0].runCommand(new Uint8Array([]).buffer, 4, messages.EmptyResultResponse),
+      (err) => err.error.getCode() === messages.ErrorCode.VALIDATION_FAILED);
+  });
+});
@@ -0,0 +1,34 @@
+// Makes the fake core panic through `GIGANOTES_MOCK_PANIC`, see
+// native/src/mock.rs, and checks that the panic reaches JS as an `Error`.
+const assert = require('assert');
+const giganotes = require('../lib');
+
+// Whether `err` is the `Error` thrown for a panic of the fake core.
+const isPanic = (err) => err instanceof Error &&
+  err.code === 'ERR_GIGANOTES_PANIC' &&
+  /GetRootFolder/.test(err.message) &&
+  typeof err.backtrace === 'string' && err.backtrace.length > 0;
+
+describe('panics', function() {
+  before(function() {
+    assert.ok(giganotes.initData('http://localhost', '/mock/panic').getSuccess());
+    process.env.GIGANOTES_MOCK_PANIC = 'GetRootFolder';
+  });
+
+  after(function() {
+    delete process.env.GIGANOTES_MOCK_PANIC;
+  });
+
+  it('are thrown by commands run on the main thread', function() {
+    assert.throws(() => giganotes.getRootFolder(), isPanic);
+  });
+
+  it('reject commands run on the thread pool', async function() {
+    await assert.rejects(giganotes.getRootFolderAsync(), isPanic);
+  });
+
+  it('leave the core usable', function() {
+    delete process.env.GIGANOTES_MOCK_PANIC;
+    assert.ok(giganotes.getRootFolder().getSuccess());
+  });
+});
@@ -0,0 +1,56 @@
+// Covers the `Request` envelope and sequences of requests.
+const assert = require('assert');
+const giganotes = require('../lib');
+const messages = require('../lib/messages_pb');
+
+var createNote = function(requestId, title) {
+  var command = new messages.CreateNote();
+  command.setTitle(title);
+
+  var request = new messages.Request();
+  request.setRequestid(requestId);
+  request.setCreatenote(command);
+  return request;
+};
+
+var getNoteById = function(requestId, noteId) {
+  var command = new messages.GetNoteById();
+  command.setNoteid(noteId);
+
+  var request = new messages.Request();
+  request.setRequestid(requestId);
+  request.setGetnotebyid(command);
+  return request;
+};
+
+describe('requests', function() {
+  before(function() {
+    assert.ok(giganotes.initData('http://localhost', '/mock/requests').getSuccess());
+  });
+
+  it('answers a request with the same request id', async function() {
+    var response = await giganotes.sendRequest(createNote(7, 'Envelope'));
+    assert.strictEqual(response.getRequestid(), 7);
+    assert.ok(response.getCreatenote().getSuccess());
+  });
+
+  it('runs a sequence in order', async function() {
+    var responses = await giganotes.sendRequests([
+      createNote(1, 'First'),
+      createNote(2, 'Second'),
+    ]);
+    assert.deepStrictEqual(responses.map((r) => r.getRequestid()), [1, 2]);
+    assert.ok(responses.every((r) => r.getCreatenote().getSuccess()));
+  });
+
+  it('aborts the rest of a sequence after a failure', async function() {
+    var responses = await giganotes.sendRequests([
+      getNoteById(1, 'no-such-note'),
+      createNote(2, 'Skipped'),
+    ], { stopOnError: true });
+
+    assert.strictEqual(responses[0].getGetnotebyid().getError().getCode(),
+      messages.ErrorCode.NOT_FOUND);
+    assert.strictEqual(responses[1].getError().getCode(), messages.ErrorCode.ABORTED);
+  });
+});
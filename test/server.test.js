@@ -0,0 +1,101 @@
+// Talks to the local server of the `server` feature over its socket, like an
+// editor plugin would.
+const assert = require('assert');
+const fs = require('fs');
+const net = require('net');
+const os = require('os');
+const path = require('path');
+const giganotes = require('../lib');
+const messages = require('../lib/messages_pb');
+
+// Writes length-prefixed `ServerFrame`s and resolves the replies by request
+// id. Events are ignored.
+class Client {
+  constructor(socketPath) {
+    this.socket = net.connect(socketPath);
+    this.buffer = Buffer.alloc(0);
+    this.pending = new Map();
+    this.nextRequestId = 1;
+    this.closed = new Promise((resolve) => this.socket.on('close', resolve));
+    this.socket.on('data', (data) => this.receive(data));
+  }
+
+  receive(data) {
+    this.buffer = Buffer.concat([this.buffer, data]);
+    while (this.buffer.length >= 4) {
+      var length = this.buffer.readUInt32BE(0);
+      if (this.buffer.length < 4 + length) break;
+
+      var message = messages.ServerMessage.deserializeBinary(this.buffer.slice(4, 4 + length));
+      this.buffer = this.buffer.slice(4 + length);
+      if (message.hasReply()) {
+        var reply = message.getReply();
+        var resolve = this.pending.get(reply.getRequestid());
+        this.pending.delete(reply.getRequestid());
+        if (resolve) resolve(reply);
+      }
+    }
+  }
+
+  // Sends a frame whose body is set by `setBody` and resolves with its reply.
+  send(setBody) {
+    var requestId = this.nextRequestId++;
+    var frame = new messages.ServerFrame();
+    frame.setRequestid(requestId);
+    setBody(frame);
+
+    var body = Buffer.from(frame.serializeBinary());
+    var length = Buffer.alloc(4);
+    length.writeUInt32BE(body.length);
+    this.socket.write(Buffer.concat([length, body]));
+    return new Promise((resolve) => this.pending.set(requestId, resolve));
+  }
+
+  authenticate(token) {
+    var auth = new messages.ServerAuth();
+    auth.setToken(token);
+    return this.send((frame) => frame.setAuth(auth));
+  }
+}
+
+describe('server', function() {
+  var dir;
+  var paths;
+
+  before(function() {
+    assert.ok(giganotes.initData('http://localhost', '/mock/server').getSuccess());
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), 'giganotes-server-'));
+    paths = giganotes.startServer(dir);
+  });
+
+  after(function() {
+    giganotes.stopServer(dir);
+    fs.rmdirSync(dir);
+  });
+
+  it('runs commands for authenticated clients', async function() {
+    var client = new Client(paths.socketPath);
+    var reply = await client.authenticate(fs.readFileSync(paths.tokenPath, 'utf8'));
+    assert.strictEqual(reply.hasError(), false);
+
+    var command = new messages.ServerCommand();
+    command.setIndex(10);
+    reply = await client.send((frame) => frame.setCommand(command));
+    var root = messages.GetRootFolderResponse.deserializeBinary(reply.getResponse_asU8());
+    assert.strictEqual(root.getFolderid(), giganotes.getRootFolder().getFolderid());
+
+    client.socket.end();
+    await client.closed;
+  });
+
+  it('disconnects clients with a wrong token', async function() {
+    var client = new Client(paths.socketPath);
+    var reply = await client.authenticate('guessed');
+    assert.strictEqual(reply.getError().getCode(), messages.ErrorCode.AUTH_FAILED);
+    await client.closed;
+  });
+
+  it('refuses to start a second server in the same directory', function() {
+    assert.throws(() => giganotes.startServer(dir), /already running/);
+  });
+});